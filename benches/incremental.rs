@@ -0,0 +1,69 @@
+//! Measures the cost of an edit of an `IncrementalOverlayGraph` against the size of the graph.
+//!
+//! Run it with `cargo bench --bench incremental`.
+//!
+//! Every edit re-splits and re-fills only the segments around the edited path, but patching the built
+//! `OverlayGraph` shifts and renumbers the links and nodes behind the edited x range. So the edit time grows
+//! linearly with the graph, with a much smaller factor than building the graph again.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::core::overlay::{Overlay, ShapeType};
+
+const EDIT_COUNT: usize = 200;
+
+fn rect(x: i32, y: i32, w: i32, h: i32) -> IntPath {
+    vec![
+        IntPoint::new(x, y),
+        IntPoint::new(x, y + h),
+        IntPoint::new(x + w, y + h),
+        IntPoint::new(x + w, y),
+    ]
+}
+
+// a `size` x `size` grid of separate squares
+fn grid(size: i32) -> Vec<IntPath> {
+    let mut paths = Vec::with_capacity((size * size) as usize);
+    for i in 0..size {
+        for j in 0..size {
+            paths.push(rect(20 * i, 20 * j, 10, 10));
+        }
+    }
+    paths
+}
+
+fn main() {
+    println!("{:>8} {:>10} {:>14} {:>14}", "size", "edges", "edit, µs", "rebuild, µs");
+    for size in [16, 32, 64, 128, 256] {
+        let paths = grid(size);
+
+        let mut graph = Overlay::with_paths(&paths, &[]).into_incremental_graph(FillRule::NonZero);
+        black_box(graph.graph());
+
+        // a clip square which overlaps a few squares at the left side, so most of the graph is behind it
+        let clip = rect(5, 10 * size, 20, 20);
+
+        let start = Instant::now();
+        for _ in 0..EDIT_COUNT {
+            graph.add_path(&clip, ShapeType::Clip);
+            black_box(graph.graph());
+            graph.remove_path(&clip, ShapeType::Clip);
+            black_box(graph.graph());
+        }
+        let edit = start.elapsed().as_secs_f64() * 1_000_000.0 / (2 * EDIT_COUNT) as f64;
+
+        let clips = [clip];
+        let start = Instant::now();
+        let rebuild_count = (EDIT_COUNT / size as usize).max(1);
+        for _ in 0..rebuild_count {
+            black_box(Overlay::with_paths(&paths, &clips).into_graph(FillRule::NonZero));
+        }
+        let rebuild = start.elapsed().as_secs_f64() * 1_000_000.0 / rebuild_count as f64;
+
+        println!("{:>8} {:>10} {:>14.1} {:>14.1}", size, 4 * paths.len(), edit, rebuild);
+    }
+}
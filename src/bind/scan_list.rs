@@ -2,25 +2,26 @@ use i_float::point::IntPoint;
 use crate::util::Int;
 use crate::bind::segment::IdSegment;
 use crate::bind::solver::ScanHoleStore;
+use crate::overlay_point::OverlayPoint;
 
-pub(crate) struct ScanHoleList {
-    buffer: Vec<IdSegment>,
+pub(crate) struct ScanHoleList<P = IntPoint> {
+    buffer: Vec<IdSegment<P>>,
 }
 
-impl ScanHoleList {
+impl<P> ScanHoleList<P> {
     #[inline(always)]
     pub(crate) fn new(count: usize) -> Self {
         Self { buffer: Vec::with_capacity(count.log2_sqrt()) }
     }
 }
 
-impl ScanHoleStore for ScanHoleList {
+impl<P: OverlayPoint> ScanHoleStore<P> for ScanHoleList<P> {
     #[inline(always)]
-    fn insert(&mut self, segment: IdSegment, _stop: i32) {
+    fn insert(&mut self, segment: IdSegment<P>, _stop: P::Coord) {
         self.buffer.push(segment)
     }
 
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> usize {
+    fn find_under_and_nearest(&mut self, p: P) -> usize {
         if self.buffer.is_empty() {
             return 0
         }
@@ -30,7 +31,7 @@ impl ScanHoleStore for ScanHoleList {
         let mut n = self.buffer.len();
         while i < n {
             let item = unsafe { self.buffer.get_unchecked(i) };
-            if item.x_segment.b.x <= p.x {
+            if item.x_segment.b.x() <= p.x() {
                 let last = *unsafe { self.buffer.get_unchecked(n - 1) };
                 *unsafe { self.buffer.get_unchecked_mut(i) } = last;
                 n -= 1;
@@ -4,24 +4,25 @@ use i_tree::tree::Tree;
 use crate::bind::segment::IdSegment;
 use crate::bind::solver::ScanHoleStore;
 use crate::util::Int;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::x_segment::XSegment;
 
-pub(crate) struct ScanHoleTree {
-    tree: Tree<IdSegment>,
+pub(crate) struct ScanHoleTree<P = IntPoint> {
+    tree: Tree<IdSegment<P>>,
 }
 
-impl ScanHoleTree {
+impl<P: OverlayPoint> ScanHoleTree<P> {
 
     #[inline]
     pub(crate) fn new(count: usize) -> Self {
-        let x_segment = XSegment { a: IntPoint::ZERO, b: IntPoint::ZERO };
+        let x_segment = XSegment { a: P::ZERO, b: P::ZERO };
         let segment = IdSegment { id: usize::MAX, x_segment };
         Self { tree: Tree::new(segment, count.log2_sqrt()) }
     }
 }
 
-impl ScanHoleStore for ScanHoleTree {
-    fn insert(&mut self, segment: IdSegment, stop: i32) {
+impl<P: OverlayPoint> ScanHoleStore<P> for ScanHoleTree<P> {
+    fn insert(&mut self, segment: IdSegment<P>, stop: P::Coord) {
         let mut index = self.tree.root;
         let mut p_index = EMPTY_REF;
         let mut is_left = false;
@@ -29,7 +30,7 @@ impl ScanHoleStore for ScanHoleTree {
         while index != EMPTY_REF {
             let node = self.tree.node(index);
             p_index = index;
-            if node.value.x_segment.b.x <= stop {
+            if node.value.x_segment.b.x() <= stop {
                 let nd_parent = node.parent;
                 _ = self.tree.delete_index(index);
                 if nd_parent != EMPTY_REF {
@@ -71,12 +72,12 @@ impl ScanHoleStore for ScanHoleTree {
         }
     }
 
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> usize {
+    fn find_under_and_nearest(&mut self, p: P) -> usize {
         let mut index = self.tree.root;
         let mut result = EMPTY_REF;
         while index != EMPTY_REF {
             let node = self.tree.node(index);
-            if node.value.x_segment.b.x <= p.x {
+            if node.value.x_segment.b.x() <= p.x() {
                 let nd_parent = node.parent;
                 _ = self.tree.delete_index(index);
                 if nd_parent != EMPTY_REF {
@@ -1,19 +1,19 @@
 use std::cmp::Ordering;
 use i_float::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
-use i_shape::int::path::IntPath;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::x_segment::XSegment;
 use crate::vector::edge::VectorPath;
 
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct IdSegment {
+pub(crate) struct IdSegment<P = IntPoint> {
     pub(crate) id: usize,
-    pub(crate) x_segment: XSegment,
+    pub(crate) x_segment: XSegment<P>,
 }
 
-impl IdSegment {
+impl<P> IdSegment<P> {
     #[inline(always)]
-    pub(crate) fn new(id: usize, a: IntPoint, b: IntPoint) -> Self {
+    pub(crate) fn new(id: usize, a: P, b: P) -> Self {
         Self {
             id,
             x_segment: XSegment { a, b },
@@ -21,23 +21,23 @@ impl IdSegment {
     }
 }
 
-impl PartialEq<Self> for IdSegment {
+impl<P: PartialEq> PartialEq<Self> for IdSegment<P> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.x_segment == other.x_segment
     }
 }
 
-impl Eq for IdSegment {}
+impl<P: Eq> Eq for IdSegment<P> {}
 
-impl PartialOrd for IdSegment {
+impl<P: OverlayPoint> PartialOrd for IdSegment<P> {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for IdSegment {
+impl<P: OverlayPoint> Ord for IdSegment<P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
         if self.x_segment.is_under_segment(&other.x_segment) {
@@ -48,15 +48,15 @@ impl Ord for IdSegment {
     }
 }
 
-pub trait IdSegments {
-    fn append_id_segments(&self, buffer: &mut Vec<IdSegment>, id: usize, x_min: i32, x_max: i32);
+pub trait IdSegments<P: OverlayPoint = IntPoint> {
+    fn append_id_segments(&self, buffer: &mut Vec<IdSegment<P>>, id: usize, x_min: P::Coord, x_max: P::Coord);
 }
 
-impl IdSegments for IntPath {
-    fn append_id_segments(&self, buffer: &mut Vec<IdSegment>, id: usize, x_min: i32, x_max: i32) {
+impl<P: OverlayPoint> IdSegments<P> for [P] {
+    fn append_id_segments(&self, buffer: &mut Vec<IdSegment<P>>, id: usize, x_min: P::Coord, x_max: P::Coord) {
         let mut b = self[self.len() - 1];
         for &a in self.iter() {
-            if a.x < b.x && x_min < b.x && a.x <= x_max {
+            if a.x() < b.x() && x_min < b.x() && a.x() <= x_max {
                 buffer.push(IdSegment::new(id, a, b));
             }
             b = a
@@ -74,14 +74,14 @@ impl IdSegments for VectorPath {
     }
 }
 
-impl BinKey for IdSegment {
+impl<P: OverlayPoint> BinKey for IdSegment<P> {
     #[inline(always)]
     fn key(&self) -> i64 {
-        self.x_segment.a.x.into()
+        self.x_segment.a.x().into()
     }
 
     #[inline(always)]
     fn bin(&self, layout: &BinLayout) -> usize {
-        layout.index(self.x_segment.a.x.into())
+        layout.index(self.x_segment.a.x().into())
     }
 }
\ No newline at end of file
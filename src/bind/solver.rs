@@ -1,8 +1,8 @@
-use i_float::point::IntPoint;
 use crate::bind::segment::IdSegment;
 use crate::id_point::IdPoint;
 use crate::bind::scan_list::ScanHoleList;
 use crate::bind::scan_tree::ScanHoleTree;
+use crate::overlay_point::OverlayPoint;
 
 pub(crate) struct BindSolution {
     pub(crate) parent_for_child: Vec<usize>,
@@ -11,15 +11,15 @@ pub(crate) struct BindSolution {
 
 pub(crate) struct ShapeBinder;
 
-pub(crate) trait ScanHoleStore {
-    fn insert(&mut self, segment: IdSegment, stop: i32);
+pub(crate) trait ScanHoleStore<P: OverlayPoint> {
+    fn insert(&mut self, segment: IdSegment<P>, stop: P::Coord);
 
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> usize;
+    fn find_under_and_nearest(&mut self, p: P) -> usize;
 }
 
 impl ShapeBinder {
     #[inline]
-    pub(crate) fn bind(shape_count: usize, i_points: Vec<IdPoint>, segments: Vec<IdSegment>) -> BindSolution {
+    pub(crate) fn bind<P: OverlayPoint>(shape_count: usize, i_points: Vec<IdPoint<P>>, segments: Vec<IdSegment<P>>) -> BindSolution {
         if i_points.len() < 128 {
             let scan_list = ScanHoleList::new(segments.len());
             Self::private_solve(scan_list, shape_count, i_points, segments)
@@ -29,7 +29,7 @@ impl ShapeBinder {
         }
     }
 
-    fn private_solve<S: ScanHoleStore>(scan_store: S, shape_count: usize, i_points: Vec<IdPoint>, segments: Vec<IdSegment>) -> BindSolution {
+    fn private_solve<P: OverlayPoint, S: ScanHoleStore<P>>(scan_store: S, shape_count: usize, i_points: Vec<IdPoint<P>>, segments: Vec<IdSegment<P>>) -> BindSolution {
         let children_count = i_points.len();
         let mut scan_store = scan_store;
 
@@ -40,17 +40,17 @@ impl ShapeBinder {
         let mut j = 0;
 
         while i < i_points.len() {
-            let x = i_points[i].point.x;
+            let x = i_points[i].point.x();
 
-            while j < segments.len() && segments[j].x_segment.a.x <= x {
+            while j < segments.len() && segments[j].x_segment.a.x() <= x {
                 let id_segment = &segments[j];
-                if id_segment.x_segment.b.x > x {
+                if id_segment.x_segment.b.x() > x {
                     scan_store.insert(*id_segment, x);
                 }
                 j += 1
             }
 
-            while i < i_points.len() && i_points[i].point.x == x {
+            while i < i_points.len() && i_points[i].point.x() == x {
                 let p = i_points[i].point;
 
                 let parent_index = scan_store.find_under_and_nearest(p);
@@ -0,0 +1,39 @@
+//! This module measures the area of an overlay result directly from the links of the graph, without extracting any shapes.
+
+use crate::core::filter::Filter;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_rule::OverlayRule;
+
+impl OverlayGraph {
+    /// Calculates the area of the shapes which `extract_shapes` would return for the same overlay rule.
+    /// Every boundary link of the result adds the trapezoid under it if the result is below the link and subtracts it otherwise,
+    /// so contours are not traced and holes are not bound.
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    /// - Returns: The area of the result. It is summed exactly in integers and converted to `f64` only at the end.
+    pub fn area(&self, overlay_rule: OverlayRule) -> f64 {
+        self.area_x2(overlay_rule) as f64 * 0.5
+    }
+
+    /// The doubled area of the result, it is always an integer.
+    pub(crate) fn area_x2(&self, overlay_rule: OverlayRule) -> i128 {
+        let skip = self.links.filter(overlay_rule);
+
+        let mut area: i128 = 0;
+        for (link, &is_skip) in self.links.iter().zip(skip.iter()) {
+            if is_skip {
+                continue;
+            }
+
+            // links are directed from left to right
+            let trapezoid = link.trapezoid_x2();
+
+            if overlay_rule.is_fill_top(link.fill) {
+                area -= trapezoid;
+            } else {
+                area += trapezoid;
+            }
+        }
+
+        area
+    }
+}
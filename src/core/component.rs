@@ -0,0 +1,109 @@
+//! This module splits an overlay graph into independent clusters of connected links.
+//! Every cluster is a separate `OverlayGraph`, so clusters can be extracted one by one or in parallel.
+
+use i_float::rect::IntRect;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+
+impl OverlayGraph {
+    /// Splits the graph into independent clusters. A cluster is a set of links connected through the nodes,
+    /// together with all other clusters which can lie inside it, because a hole of a shape can be extracted only together with its outer boundary.
+    /// - Returns: A vector of `OverlayGraph`, ordered by the leftmost bottom point of every cluster.
+    ///
+    /// Extracting the shapes of all clusters by any overlay rule gives the same shapes as extracting them from the whole graph, every shape comes from exactly one cluster.
+    ///
+    /// Note: A cluster is nested into another one if its bounding box is inside the bounding box of the other, so some clusters which only look nested are merged too.
+    /// Polylines are not copied into the clusters.
+    pub fn components(&self) -> Vec<OverlayGraph> {
+        if self.links.is_empty() {
+            return Vec::new();
+        }
+
+        let mut sets = DisjointSet::new(self.nodes.len());
+        for link in self.links.iter() {
+            sets.union(link.a.id, link.b.id);
+        }
+
+        // bounding boxes of the connected parts
+        let mut rects: Vec<Option<IntRect>> = vec![None; self.nodes.len()];
+        for link in self.links.iter() {
+            let root = sets.find(link.a.id);
+            let rect = rects[root].get_or_insert_with(|| IntRect::new(link.a.point.x, link.a.point.x, link.a.point.y, link.a.point.y));
+            rect.add_point(&link.a.point);
+            rect.add_point(&link.b.point);
+        }
+
+        let mut parts: Vec<(usize, IntRect)> = rects.into_iter().enumerate()
+            .filter_map(|(root, rect)| rect.map(|rect| (root, rect)))
+            .collect();
+
+        // merge every part with all parts which contain it
+        parts.sort_unstable_by_key(|(_, rect)| (rect.min_x, -(rect.max_x as i64)));
+        let mut active: Vec<usize> = Vec::new();
+        for (i, (root, rect)) in parts.iter().enumerate() {
+            active.retain(|&j| parts[j].1.max_x >= rect.min_x);
+            for &j in active.iter() {
+                let other = &parts[j].1;
+                if other.max_x >= rect.max_x && other.min_y <= rect.min_y && other.max_y >= rect.max_y {
+                    sets.union(parts[j].0, *root);
+                }
+            }
+            active.push(i);
+        }
+
+        // links are sorted by `a`, so every cluster keeps this order and clusters are ordered by their first link
+        let mut cluster_index = vec![usize::MAX; self.nodes.len()];
+        let mut clusters: Vec<Vec<OverlayLink>> = Vec::new();
+        for link in self.links.iter() {
+            let root = sets.find(link.a.id);
+            let index = if cluster_index[root] == usize::MAX {
+                cluster_index[root] = clusters.len();
+                clusters.push(Vec::new());
+                clusters.len() - 1
+            } else {
+                cluster_index[root]
+            };
+
+            clusters[index].push(*link);
+        }
+
+        // every node keeps all its links in one cluster, so `with_links` can not fail
+        clusters.into_iter().map(|links| OverlayGraph::with_links(self.solver, links)).collect()
+    }
+}
+
+pub(crate) struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub(crate) fn new(count: usize) -> Self {
+        Self { parents: (0..count).collect() }
+    }
+
+    pub(crate) fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // compress the path
+        let mut i = index;
+        while self.parents[i] != root {
+            let next = self.parents[i];
+            self.parents[i] = root;
+            i = next;
+        }
+
+        root
+    }
+
+    pub(crate) fn union(&mut self, a: usize, b: usize) {
+        let ra = self.find(a);
+        let rb = self.find(b);
+        if ra != rb {
+            // keep the smaller root, so the root of a set does not depend on the order of unions
+            self.parents[ra.max(rb)] = ra.min(rb);
+        }
+    }
+}
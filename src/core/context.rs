@@ -0,0 +1,80 @@
+//! This module defines a context which keeps the buffers of the overlay pipeline between operations.
+//! It is useful for many small operations in a row, for example clipping of tiles.
+
+use i_shape::int::shape::IntShapes;
+
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::segm::end::End;
+use crate::segm::segment::{Segment, SegmentFill};
+
+/// Owns the segment, fill, link and node vectors of the overlay pipeline and the `visited` buffer of the shape extraction, so they are reused between operations.
+///
+/// Build a graph with `Overlay::build_graph`, which borrows both the overlay and the context, and extract shapes with `OverlayContext::extract_shapes`.
+/// An `Overlay` can be reused as well with `Overlay::clear`.
+///
+/// Note: Only these vectors are reused. The split and fill solvers, the crossing nodes of the graph and the hole binding still allocate their own memory for every operation.
+pub struct OverlayContext {
+    pub(crate) solver: Solver,
+    pub(crate) segments: Vec<Segment>,
+    pub(crate) fills: Vec<SegmentFill>,
+    pub(crate) end_bs: Vec<End>,
+    pub(crate) graph: OverlayGraph,
+    visited: Vec<bool>,
+}
+
+impl OverlayContext {
+    /// Creates an empty context.
+    /// - `solver`: Type of solver to use for every graph built in this context.
+    pub fn new(solver: Solver) -> Self {
+        Self {
+            solver,
+            segments: Vec::new(),
+            fills: Vec::new(),
+            end_bs: Vec::new(),
+            graph: OverlayGraph { solver, nodes: Vec::new(), links: Vec::new(), lines: Vec::new() },
+            visited: Vec::new(),
+        }
+    }
+
+    /// Returns the graph which was built last by `Overlay::build_graph`.
+    #[inline(always)]
+    pub fn graph(&self) -> &OverlayGraph {
+        &self.graph
+    }
+
+    /// Extracts shapes from the last built graph, see `OverlayGraph::extract_shapes`.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes(&mut self, overlay_rule: OverlayRule) -> IntShapes {
+        self.extract_shapes_min_area(overlay_rule, 0)
+    }
+
+    /// Extracts shapes from the last built graph, see `OverlayGraph::extract_shapes_min_area`.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_min_area(&mut self, overlay_rule: OverlayRule, min_area: i64) -> IntShapes {
+        let graph = &self.graph;
+        let visited = &mut self.visited;
+
+        visited.clear();
+        visited.extend(graph.links.iter().map(|link| overlay_rule.is_inside_top(link.fill) == overlay_rule.is_inside_bottom(link.fill)));
+
+        let link_indices = 0..visited.len();
+        graph.extract_shapes_from(link_indices, visited, |link_index| overlay_rule.is_fill_top(graph.link(link_index).fill), min_area)
+    }
+}
+
+impl Default for OverlayContext {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
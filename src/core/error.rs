@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+
+use i_float::point::IntPoint;
+
+/// Errors reported by the fallible API, such as `Overlay::try_into_graph` and `OverlayGraph::try_extract_shapes`.
+/// New variants can be added in future versions, so a `match` must have a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverlayError {
+    /// The point is out of the safe range `MIN_COORD..=MAX_COORD`, see `core::range`.
+    CoordinateOverflow(IntPoint),
+    /// The point with the coordinates `x` and `y` is out of the range `-I64Point::MAX_COORD..=I64Point::MAX_COORD`, see `I64Overlay::validate_range`.
+    I64CoordinateOverflow { x: i64, y: i64 },
+    /// A float input point has a `NaN` or infinite coordinate.
+    NonFiniteInput,
+    /// The graph is inconsistent, for example a node has only one link or a contour can not be closed.
+    TopologyFailure,
+    /// Polylines were added to the overlay, but the conversion only supports closed paths, see `Overlay::add_polyline`.
+    UnsupportedPolylines,
+}
+
+impl fmt::Display for OverlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverlayError::CoordinateOverflow(point) => write!(f, "coordinate overflow at ({}, {})", point.x, point.y),
+            OverlayError::I64CoordinateOverflow { x, y } => write!(f, "coordinate overflow at ({}, {})", x, y),
+            OverlayError::NonFiniteInput => write!(f, "input contains a non-finite coordinate"),
+            OverlayError::TopologyFailure => write!(f, "overlay graph topology failure"),
+            OverlayError::UnsupportedPolylines => write!(f, "polylines are not supported by this conversion"),
+        }
+    }
+}
+
+impl Error for OverlayError {}
@@ -1,13 +1,18 @@
+use std::collections::HashMap;
 use i_float::point::IntPoint;
 use i_shape::int::path::{IntPath, PointPathExtension};
 use i_shape::int::shape::{IntShape, IntShapes};
 use i_shape::int::simple::Simple;
-use crate::bind::segment::IdSegments;
-use crate::bind::solver::ShapeBinder;
+use crate::bind::segment::{IdSegment, IdSegments};
+use crate::bind::solver::{BindSolution, ShapeBinder};
 use crate::id_point::IdPoint;
+use crate::core::error::OverlayError;
+use crate::core::link_chain::LinkChains;
 use crate::core::overlay_graph::OverlayGraph;
 use crate::core::overlay_node::OverlayNode;
 use crate::core::solver::Solver;
+use crate::overlay_point::OverlayPoint;
+use crate::segm::segment::{CLIP_BOTTOM, CLIP_TOP, SegmentFill, SUBJ_BOTTOM, SUBJ_TOP};
 use crate::sort::SmartSort;
 
 use super::overlay_rule::OverlayRule;
@@ -24,11 +29,50 @@ impl OverlayGraph {
     /// - Each path `Vec<IntPoint>` is a sequence of points, forming a closed path.
     ///
     /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// Contours which touch at a single vertex are extracted as `VertexTouch::Merge` describes: separate outer boundaries stay separate,
+    /// but a hole touching its outer boundary or another hole is joined with it into one weakly simple path. Use `extract_shapes_with_touch` to split them.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
     #[inline(always)]
     pub fn extract_shapes(&self, overlay_rule: OverlayRule) -> IntShapes {
         self.extract_shapes_min_area(overlay_rule, 0)
     }
 
+    /// Extracts shapes like `extract_shapes`, but resolves contours which touch at a single vertex as requested.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - `vertex_touch`: How to extract contours which touch at a vertex.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// With `VertexTouch::Split` every path is strictly simple, as OGC validators like PostGIS or shapely expect.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
+    #[inline(always)]
+    pub fn extract_shapes_with_touch(&self, overlay_rule: OverlayRule, vertex_touch: VertexTouch) -> IntShapes {
+        self.extract_shapes_with_touch_min_area(overlay_rule, vertex_touch, 0)
+    }
+
+    /// Extracts shapes like `extract_shapes_with_touch`, but with an additional constraint on the minimum area of the shapes, see `extract_shapes_min_area`.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `vertex_touch`: How to extract contours which touch at a vertex.
+    /// - `min_area`: The minimum area threshold for paths to be included in the result. With `VertexTouch::Split` it applies to every split part,
+    ///   so a small loop which touches a large contour is removed and the large one is kept.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
+    pub fn extract_shapes_with_touch_min_area(&self, overlay_rule: OverlayRule, vertex_touch: VertexTouch, min_area: i64) -> IntShapes {
+        match vertex_touch {
+            VertexTouch::Merge => self.extract_shapes_min_area(overlay_rule, min_area),
+            VertexTouch::Split => {
+                // the area of a traced path is the sum of its loops, so the parts are only filtered after splitting
+                let shapes = self.extract_shapes_min_area(overlay_rule, 0);
+                shapes.into_iter().flat_map(|shape| self.split_touches(shape, min_area)).collect()
+            }
+        }
+    }
+
     /// Extracts shapes from the overlay graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes. This is useful for filtering out shapes that do not meet a certain size threshold, which can be beneficial for eliminating artifacts or noise from the output.
     /// - `overlay_rule`: The boolean operation rule to apply, determining how shapes are combined or subtracted.
     /// - `min_area`: The minimum area threshold for shapes to be included in the result. Shapes with an area smaller than this value will be excluded.
@@ -40,23 +84,328 @@ impl OverlayGraph {
     /// - Each path `Vec<IntPoint>` is a sequence of points, forming a closed path.
     ///
     /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
     pub fn extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: i64) -> IntShapes {
+        let visited = self.links.filter(overlay_rule);
+        self.extract_shapes_visited(visited, |link_index| overlay_rule.is_fill_top(self.link(link_index).fill), min_area)
+    }
+
+    /// Same as `extract_shapes`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of `IntShape` or `OverlayError::TopologyFailure` if the graph is inconsistent.
+    #[inline(always)]
+    pub fn try_extract_shapes(&self, overlay_rule: OverlayRule) -> Result<IntShapes, OverlayError> {
+        self.try_extract_shapes_min_area(overlay_rule, 0)
+    }
+
+    /// Same as `extract_shapes_min_area`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply, determining how shapes are combined or subtracted.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    pub fn try_extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: i64) -> Result<IntShapes, OverlayError> {
         let mut visited = self.links.filter(overlay_rule);
+        let link_indices = 0..visited.len();
+        self.try_extract_shapes_from(link_indices, &mut visited, |link_index| overlay_rule.is_fill_top(self.link(link_index).fill), min_area)
+    }
+
+    /// Extracts shapes for several overlay rules at once. It gives the same result as calling `extract_shapes` for every rule, but shares the work between the rules:
+    /// - the links are classified for all rules in a single pass and repeated rules are extracted once;
+    /// - the chains of links between crossing nodes are found once, and every contour is traced chain by chain;
+    /// - holes are bound to the links of the outer boundaries, which are already sorted, instead of sorting the boundaries for every rule.
+    ///
+    /// If multithreading is allowed by the solver, the rules are extracted in parallel.
+    /// - `overlay_rules`: The boolean operation rules to apply.
+    /// - Returns: A vector with one `IntShapes` per rule, in the same order as `overlay_rules`.
+    ///
+    /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes_multi(&self, overlay_rules: &[OverlayRule]) -> Vec<IntShapes> {
+        self.extract_shapes_multi_min_area(overlay_rules, 0)
+    }
+
+    /// Extracts shapes for several overlay rules at once similar to `extract_shapes_multi`, but with an additional constraint on the minimum area of the shapes.
+    /// - `overlay_rules`: The boolean operation rules to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_multi_min_area(&self, overlay_rules: &[OverlayRule], min_area: i64) -> Vec<IntShapes> {
+        let mut rules: Vec<OverlayRule> = Vec::with_capacity(overlay_rules.len());
+        let rule_indices: Vec<usize> = overlay_rules.iter().map(|rule| {
+            rules.iter().position(|r| r == rule).unwrap_or_else(|| {
+                rules.push(*rule);
+                rules.len() - 1
+            })
+        }).collect();
+
+        // for every possible fill: bit 0 is set if a link is not a boundary, bit 1 if its top side is inside
+        let tables: Vec<[u8; 16]> = rules.iter().map(|rule| {
+            let mut table = [0; 16];
+            for (fill, item) in table.iter_mut().enumerate() {
+                let fill = fill as SegmentFill;
+                let top = rule.is_inside_top(fill);
+                let bottom = rule.is_inside_bottom(fill);
+                *item = (top == bottom) as u8 | (top as u8) << 1;
+            }
+            table
+        }).collect();
+
+        let mut sides: Vec<Vec<u8>> = vec![Vec::with_capacity(self.links.len()); rules.len()];
+        for link in self.links.iter() {
+            for (side, table) in sides.iter_mut().zip(tables.iter()) {
+                side.push(table[link.fill as usize]);
+            }
+        }
+
+        let results = self.extract_sides(sides, min_area);
 
+        let mut results: Vec<Option<IntShapes>> = results.into_iter().map(Some).collect();
+        let mut output = Vec::with_capacity(overlay_rules.len());
+        for (i, &index) in rule_indices.iter().enumerate() {
+            let is_last = !rule_indices[i + 1..].contains(&index);
+            let shapes = if is_last {
+                results[index].take().unwrap()
+            } else {
+                results[index].clone().unwrap()
+            };
+            output.push(shapes);
+        }
+
+        output
+    }
+
+    fn extract_sides(&self, sides: Vec<Vec<u8>>, min_area: i64) -> Vec<IntShapes> {
+        let chains = LinkChains::new(self);
+
+        #[cfg(feature = "allow_multithreading")]
+        {
+            if self.solver.multithreading.is_some() && sides.len() > 1 {
+                use rayon::prelude::*;
+                return sides.into_par_iter()
+                    .map(|side| self.extract_side(&chains, side, min_area))
+                    .collect();
+            }
+        }
+
+        sides.into_iter().map(|side| self.extract_side(&chains, side, min_area)).collect()
+    }
+
+    /// Same as `extract_shapes_visited`, but traces contours chain by chain and binds holes to the links of the outer boundaries,
+    /// which are already sorted, so nothing is sorted again except the holes.
+    fn extract_side(&self, chains: &LinkChains, side: Vec<u8>, min_area: i64) -> IntShapes {
+        let mut visited: Vec<bool> = side.iter().map(|&item| item & 1 == 1).collect();
+        let is_fill_top = |link_index: usize| side[link_index] & 2 == 2;
+
+        let mut shapes = Vec::new();
+        let mut holes = Vec::new();
+        let mut chain_shapes = vec![usize::MAX; chains.count()];
+        let mut contour_chains = Vec::new();
+
+        for link_index in 0..visited.len() {
+            if visited[link_index] {
+                continue;
+            }
+
+            let left_top_link = self.find_left_top_link(link_index, &visited);
+            let link = self.link(left_top_link);
+            let is_hole = is_fill_top(left_top_link);
+            let node_id = if is_hole { link.b.id } else { link.a.id };
+
+            contour_chains.clear();
+            let mut path = chains.trace(self, left_top_link, node_id, &mut visited, &mut contour_chains)
+                .unwrap_or_else(|| panic!("{}", OverlayError::TopologyFailure));
+
+            if !path.validate(min_area) {
+                continue;
+            }
+
+            if is_hole {
+                holes.push(path);
+            } else {
+                for &chain_index in contour_chains.iter() {
+                    chain_shapes[chain_index] = shapes.len();
+                }
+                shapes.push(vec![path]);
+            }
+        }
+
+        if shapes.len() < 2 || holes.is_empty() {
+            shapes.join(&self.solver, holes);
+            return shapes;
+        }
+
+        let mut i_points: Vec<_> = holes.iter().enumerate()
+            .map(|(i, path)| IdPoint::new(i, path[0]))
+            .collect();
+
+        i_points.smart_sort_by(&self.solver, |a, b| a.point.x.cmp(&b.point.x));
+
+        let x_min = i_points[0].point.x;
+        let x_max = i_points[i_points.len() - 1].point.x;
+
+        // the same segments as `IdSegments` takes from the outer boundaries, split at the nodes and sorted by `a`
+        let mut segments = Vec::new();
+        for (link_index, link) in self.links.iter().enumerate() {
+            let shape_index = chain_shapes[chains.chain_index(link_index)];
+            let (a, b) = (link.a.point, link.b.point);
+            if shape_index != usize::MAX && is_fill_top(link_index) && a.x < b.x && x_min < b.x && a.x <= x_max {
+                segments.push(IdSegment::new(shape_index, a, b));
+            }
+        }
+
+        let solution = ShapeBinder::bind(shapes.len(), i_points, segments);
+
+        for (shape_index, &capacity) in solution.children_count_for_parent.iter().enumerate() {
+            shapes[shape_index].reserve_exact(capacity);
+        }
+
+        for (hole_index, hole) in holes.into_iter().enumerate() {
+            let shape_index = solution.parent_for_child[hole_index];
+            shapes[shape_index].push(hole);
+        }
+
+        shapes
+    }
+
+    /// Extracts shapes from the overlay graph using a custom rule instead of `OverlayRule`.
+    /// - `predicate`: Receives whether one side of a link is inside the subject and whether it is inside the clip, and returns `true` if that side is inside the result.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// The predicate is called for the top and the bottom side of every link separately, so a region is either inside or outside from all its sides and contours are always closed.
+    /// For example, `|subj, clip| subj && !clip` selects the regions which belong to the subject only, like `OverlayRule::Difference`.
+    /// The predicate must return `false` for `(false, false)`, otherwise the result is unbounded.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes_custom<P>(&self, predicate: P) -> IntShapes
+    where
+        P: Fn(bool, bool) -> bool,
+    {
+        self.extract_shapes_custom_min_area(predicate, 0)
+    }
+
+    /// Extracts shapes using a custom rule similar to `extract_shapes_custom`, but with an additional constraint on the minimum area of the shapes.
+    /// - `predicate`: Receives whether one side of a link is inside the subject and whether it is inside the clip, and returns `true` if that side is inside the result.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_custom_min_area<P>(&self, predicate: P, min_area: i64) -> IntShapes
+    where
+        P: Fn(bool, bool) -> bool,
+    {
+        let mut visited = Vec::with_capacity(self.links.len());
+        let mut fill_top = Vec::with_capacity(self.links.len());
+        for link in self.links.iter() {
+            let top = predicate(link.fill & SUBJ_TOP != 0, link.fill & CLIP_TOP != 0);
+            let bottom = predicate(link.fill & SUBJ_BOTTOM != 0, link.fill & CLIP_BOTTOM != 0);
+            visited.push(top == bottom);
+            fill_top.push(top);
+        }
+
+        self.extract_shapes_visited(visited, |link_index| fill_top[link_index], min_area)
+    }
+
+    /// Traces all not visited links into contours and binds holes to their shapes.
+    /// - `visited`: Links marked as visited are skipped, only boundary links must be left.
+    /// - `is_fill_top`: Tells whether the top side of a link is inside the result.
+    pub(crate) fn extract_shapes_visited<F>(&self, visited: Vec<bool>, is_fill_top: F, min_area: i64) -> IntShapes
+    where
+        F: Fn(usize) -> bool,
+    {
+        let mut visited = visited;
+        let link_indices = 0..visited.len();
+        self.extract_shapes_from(link_indices, &mut visited, is_fill_top, min_area)
+    }
+
+    /// Same as `extract_shapes_visited`, but only starts contours from the given links.
+    /// - `link_indices`: Sorted indices of all links which are not visited.
+    pub(crate) fn extract_shapes_from<I, F>(&self, link_indices: I, visited: &mut [bool], is_fill_top: F, min_area: i64) -> IntShapes
+    where
+        I: Iterator<Item = usize>,
+        F: Fn(usize) -> bool,
+    {
+        self.try_extract_shapes_from(link_indices, visited, is_fill_top, min_area)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `extract_shapes_from`, but also tells which shape every traced link belongs to.
+    /// - Returns: The shapes and the pairs `(link_index, shape_index)` for every link of the kept contours.
+    pub(crate) fn extract_shapes_with_links<I, F>(&self, link_indices: I, visited: &mut [bool], is_fill_top: F, min_area: i64) -> (IntShapes, Vec<(usize, usize)>)
+    where
+        I: Iterator<Item = usize>,
+        F: Fn(usize) -> bool,
+    {
+        let mut contour_links = ContourLinks::default();
+        let (mut shapes, holes) = self.trace_contours(link_indices, visited, is_fill_top, min_area, &mut contour_links)
+            .unwrap_or_else(|error| panic!("{}", error));
+
+        let parents = shapes.join_parents(&self.solver, holes);
+
+        let mut link_shapes = Vec::with_capacity(contour_links.links.len());
+        let mut start = 0;
+        let mut shell_index = 0;
+        let mut hole_index = 0;
+        for &(is_hole, end) in contour_links.contours.iter() {
+            let shape_index = if is_hole {
+                hole_index += 1;
+                parents[hole_index - 1]
+            } else {
+                shell_index += 1;
+                shell_index - 1
+            };
+
+            if shape_index != usize::MAX {
+                link_shapes.extend(contour_links.links[start..end].iter().map(|&link_index| (link_index, shape_index)));
+            }
+            start = end;
+        }
+
+        (shapes, link_shapes)
+    }
+}
+
+impl<P> OverlayGraph<P> {
+    /// Same as `extract_shapes_from`, but returns `OverlayError::TopologyFailure` if a contour can not be closed.
+    pub(crate) fn try_extract_shapes_from<I, F>(&self, link_indices: I, visited: &mut [bool], is_fill_top: F, min_area: P::Product) -> Result<Shapes<P>, OverlayError>
+    where
+        P: OverlayPoint,
+        Vec<P>: Validate<P::Product>,
+        I: Iterator<Item = usize>,
+        F: Fn(usize) -> bool,
+    {
+        let (mut shapes, holes) = self.trace_contours(link_indices, visited, is_fill_top, min_area, &mut ())?;
+        shapes.join(&self.solver, holes);
+
+        Ok(shapes)
+    }
+
+    /// Traces all not visited links into shells and holes, without binding them.
+    fn trace_contours<I, F, L>(&self, link_indices: I, visited: &mut [bool], is_fill_top: F, min_area: P::Product, links: &mut L) -> Result<(Shapes<P>, Vec<Vec<P>>), OverlayError>
+    where
+        P: OverlayPoint,
+        Vec<P>: Validate<P::Product>,
+        I: Iterator<Item = usize>,
+        F: Fn(usize) -> bool,
+        L: TracedLinks,
+    {
         let mut holes = Vec::new();
         let mut shapes = Vec::new();
 
-        let mut link_index = 0;
-        while link_index < visited.len() {
+        for link_index in link_indices {
             let &is_visited = unsafe { visited.get_unchecked(link_index) };
             if is_visited {
-                link_index += 1;
                 continue;
             }
 
-            let left_top_link = self.find_left_top_link(link_index, &visited);
+            let left_top_link = self.find_left_top_link(link_index, visited);
             let link = self.link(left_top_link);
-            let is_hole = overlay_rule.is_fill_top(link.fill);
+            let is_hole = is_fill_top(left_top_link);
 
             let start_data = if is_hole {
                 StartPathData {
@@ -74,33 +423,37 @@ impl OverlayGraph {
                 }
             };
 
-            let mut path = self.get_path(start_data, &mut visited);
+            let mut path = self.get_path(start_data, visited, links).ok_or(OverlayError::TopologyFailure)?;
 
             if path.validate(min_area) {
+                links.commit(is_hole);
                 if is_hole {
                     holes.push(path);
                 } else {
                     shapes.push(vec![path]);
                 }
+            } else {
+                links.discard();
             }
-
-            link_index += 1;
         }
 
-        shapes.join(&self.solver, holes);
-
-        shapes
+        Ok((shapes, holes))
     }
 
+    /// Returns `None` if the tour is broken, which is only possible for an inconsistent graph.
     #[inline]
-    fn get_path(&self, start_data: StartPathData, visited: &mut [bool]) -> IntPath {
+    fn get_path<L: TracedLinks>(&self, start_data: StartPathData<P>, visited: &mut [bool], links: &mut L) -> Option<Vec<P>>
+    where
+        P: OverlayPoint,
+    {
         let mut link_id = start_data.link_id;
         let mut node_id = start_data.node_id;
         let last_node_id = start_data.last_node_id;
 
         *unsafe { visited.get_unchecked_mut(link_id) } = true;
+        links.push(link_id);
 
-        let mut path = IntPath::new();
+        let mut path = Vec::new();
         path.push(start_data.begin);
 
         // Find a closed tour
@@ -108,10 +461,15 @@ impl OverlayGraph {
             let node = self.node(node_id);
             link_id = match node {
                 OverlayNode::Bridge(bridge) => {
-                    if bridge[0] == link_id { bridge[1] } else { bridge[0] }
+                    let next_id = if bridge[0] == link_id { bridge[1] } else { bridge[0] };
+                    let &is_visited = unsafe { visited.get_unchecked(next_id) };
+                    if is_visited {
+                        return None;
+                    }
+                    next_id
                 }
                 OverlayNode::Cross(indices) => {
-                    self.find_nearest_counter_wise_link_to(link_id, node_id, indices, visited)
+                    self.find_nearest_counter_wise_link_to(link_id, node_id, indices, visited)?
                 }
             };
 
@@ -125,27 +483,187 @@ impl OverlayGraph {
             };
 
             *unsafe { visited.get_unchecked_mut(link_id) } = true;
+            links.push(link_id);
+        }
+
+        Some(path)
+    }
+}
+
+impl OverlayGraph {
+    /// Splits every path of a shape into strictly simple paths and binds the holes again if the shape falls apart.
+    fn split_touches(&self, shape: IntShape, min_area: i64) -> IntShapes {
+        if !shape.iter().any(|path| self.has_touches(path)) {
+            let mut shape = shape;
+            if min_area > 0 {
+                if shape.first().is_some_and(|path| !is_large(path, min_area)) {
+                    return Vec::new();
+                }
+                shape.retain(|path| is_large(path, min_area));
+            }
+
+            return vec![shape];
+        }
+
+        let mut shapes = Vec::new();
+        let mut holes = Vec::new();
+        for path in shape.into_iter() {
+            for part in path.split_touches() {
+                if part.len() < 3 {
+                    continue;
+                }
+
+                if !is_large(&part, min_area) {
+                    continue;
+                }
+
+                let area = part.unsafe_area();
+
+                if area > 0 {
+                    shapes.push(vec![part]);
+                } else {
+                    holes.push(part);
+                }
+            }
+        }
+
+        shapes.join(&self.solver, holes);
+
+        shapes
+    }
+
+    /// A path can visit a point twice only at a node with more than two links, so only these points are compared.
+    fn has_touches(&self, path: &IntPath) -> bool {
+        let mut points: Vec<IntPoint> = path.iter().copied()
+            .filter(|&p| matches!(self.find_node(p).map(|node_id| self.node(node_id)), Some(OverlayNode::Cross(_))))
+            .collect();
+
+        points.sort_unstable();
+        points.windows(2).any(|w| w[0] == w[1])
+    }
+}
+
+#[inline]
+fn is_large(path: &IntPath, min_area: i64) -> bool {
+    min_area == 0 || path.unsafe_area().abs() >> 1 >= min_area
+}
+
+/// Defines how contours which touch each other at a single vertex are extracted.
+/// - `Merge`: Touching holes are joined with their outer boundary or with each other into one weakly simple path, which visits the common vertex twice. Separate outer boundaries which touch stay separate. This is what `extract_shapes` returns.
+/// - `Split`: Every path is split at the vertices which it visits more than once, so all paths are strictly simple and only touch each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VertexTouch {
+    #[default]
+    Merge,
+    Split,
+}
+
+trait SplitTouches {
+    fn split_touches(self) -> Vec<IntPath>;
+}
+
+impl SplitTouches for IntPath {
+    fn split_touches(self) -> Vec<IntPath> {
+        let mut counts: HashMap<IntPoint, usize> = HashMap::with_capacity(self.len());
+        for &p in self.iter() {
+            *counts.entry(p).or_default() += 1;
+        }
+
+        // cut a loop every time the path comes back to a point which is still on the stack
+        let mut parts = Vec::new();
+        let mut stack: IntPath = Vec::with_capacity(self.len());
+        let mut positions: HashMap<IntPoint, usize> = HashMap::with_capacity(self.len());
+        for p in self.into_iter() {
+            if let Some(&position) = positions.get(&p) {
+                for q in stack[position + 1..].iter() {
+                    positions.remove(q);
+                }
+                parts.push(stack.split_off(position));
+                stack.push(p);
+            } else {
+                positions.insert(p, stack.len());
+                stack.push(p);
+            }
         }
+        parts.push(stack);
 
-        path
+        // the first point of a hole is used to bind it, so it must not touch other paths
+        for part in parts.iter_mut() {
+            if let Some(start) = part.iter().position(|p| counts[p] == 1) {
+                part.rotate_left(start);
+            }
+        }
+
+        parts
     }
 }
 
-struct StartPathData {
-    begin: IntPoint,
+/// Receives the links of every traced contour.
+trait TracedLinks {
+    fn push(&mut self, link_index: usize);
+
+    /// Keeps the links pushed after the previous contour as a new contour.
+    fn commit(&mut self, is_hole: bool);
+
+    /// Forgets the links pushed after the previous contour.
+    fn discard(&mut self);
+}
+
+impl TracedLinks for () {
+    #[inline(always)]
+    fn push(&mut self, _link_index: usize) {}
+
+    #[inline(always)]
+    fn commit(&mut self, _is_hole: bool) {}
+
+    #[inline(always)]
+    fn discard(&mut self) {}
+}
+
+/// The links of all kept contours in a row, and for every contour if it is a hole and where its links end.
+#[derive(Default)]
+struct ContourLinks {
+    links: Vec<usize>,
+    contours: Vec<(bool, usize)>,
+}
+
+impl TracedLinks for ContourLinks {
+    #[inline(always)]
+    fn push(&mut self, link_index: usize) {
+        self.links.push(link_index);
+    }
+
+    #[inline]
+    fn commit(&mut self, is_hole: bool) {
+        self.contours.push((is_hole, self.links.len()));
+    }
+
+    #[inline]
+    fn discard(&mut self) {
+        let start = self.contours.last().map_or(0, |&(_, end)| end);
+        self.links.truncate(start);
+    }
+}
+
+/// The same as `IntShapes`, but for any point type.
+type Shapes<P> = Vec<Vec<Vec<P>>>;
+
+struct StartPathData<P> {
+    begin: P,
     node_id: usize,
     link_id: usize,
     last_node_id: usize,
 }
 
-trait JoinHoles {
-    fn join(&mut self, solver: &Solver, holes: Vec<IntPath>);
-    fn scan_join(&mut self, solver: &Solver, holes: Vec<IntPath>);
+trait JoinHoles<P> {
+    fn join(&mut self, solver: &Solver, holes: Vec<Vec<P>>);
+    fn join_parents(&mut self, solver: &Solver, holes: Vec<Vec<P>>) -> Vec<usize>;
+    fn scan_join(&mut self, solver: &Solver, holes: Vec<Vec<P>>);
 }
 
-impl JoinHoles for Vec<IntShape> {
+impl<P: OverlayPoint> JoinHoles<P> for Shapes<P> {
     #[inline]
-    fn join(&mut self, solver: &Solver, holes: Vec<IntPath>) {
+    fn join(&mut self, solver: &Solver, holes: Vec<Vec<P>>) {
         if self.is_empty() || holes.is_empty() {
             return;
         }
@@ -159,25 +677,29 @@ impl JoinHoles for Vec<IntShape> {
         }
     }
 
-    fn scan_join(&mut self, solver: &Solver, holes: Vec<IntPath>) {
-        let mut i_points: Vec<_> = holes.iter().enumerate()
-            .map(|(i, path)| IdPoint::new(i, *path.first().unwrap()))
-            .collect();
-
-        i_points.smart_sort_by(solver, |a, b| a.point.x.cmp(&b.point.x));
+    /// Same as `join`, but returns the index of the shape for every hole, or `usize::MAX` if there are no shapes.
+    fn join_parents(&mut self, solver: &Solver, holes: Vec<Vec<P>>) -> Vec<usize> {
+        if self.is_empty() {
+            return vec![usize::MAX; holes.len()];
+        }
 
-        let x_min = i_points[0].point.x;
-        let x_max = i_points[i_points.len() - 1].point.x;
+        if self.len() == 1 || holes.is_empty() {
+            let parents = vec![0; holes.len()];
+            self.join(solver, holes);
+            return parents;
+        }
 
-        let capacity = self.iter().fold(0, |s, it| s + it[0].len()) / 2;
-        let mut segments = Vec::with_capacity(capacity);
-        for (i, shape) in self.iter().enumerate() {
-            shape[0].append_id_segments(&mut segments, i, x_min, x_max);
+        let solution = bind_holes(solver, self, &holes);
+        for (hole_index, hole) in holes.into_iter().enumerate() {
+            let shape_index = solution.parent_for_child[hole_index];
+            self[shape_index].push(hole);
         }
 
-        segments.smart_sort_by(solver, |a, b| a.x_segment.a.x.cmp(&b.x_segment.a.x));
+        solution.parent_for_child
+    }
 
-        let solution = ShapeBinder::bind(self.len(), i_points, segments);
+    fn scan_join(&mut self, solver: &Solver, holes: Vec<Vec<P>>) {
+        let solution = bind_holes(solver, self, &holes);
 
         for (shape_index, &capacity) in solution.children_count_for_parent.iter().enumerate() {
             self[shape_index].reserve_exact(capacity);
@@ -190,8 +712,31 @@ impl JoinHoles for Vec<IntShape> {
     }
 }
 
-trait Validate {
-    fn validate(&mut self, min_area: i64) -> bool;
+/// Finds the shape which contains every hole. The holes must not be empty.
+pub(crate) fn bind_holes<P: OverlayPoint>(solver: &Solver, shapes: &[Vec<Vec<P>>], holes: &[Vec<P>]) -> BindSolution {
+    let mut i_points: Vec<_> = holes.iter().enumerate()
+        .map(|(i, path)| IdPoint::new(i, *path.first().unwrap()))
+        .collect();
+
+    i_points.smart_sort_by(solver, |a, b| a.point.x().cmp(&b.point.x()));
+
+    let x_min = i_points[0].point.x();
+    let x_max = i_points[i_points.len() - 1].point.x();
+
+    let capacity = shapes.iter().fold(0, |s, it| s + it[0].len()) / 2;
+    let mut segments = Vec::with_capacity(capacity);
+    for (i, shape) in shapes.iter().enumerate() {
+        shape[0].as_slice().append_id_segments(&mut segments, i, x_min, x_max);
+    }
+
+    segments.smart_sort_by(solver, |a, b| a.x_segment.a.x().cmp(&b.x_segment.a.x()));
+
+    ShapeBinder::bind(shapes.len(), i_points, segments)
+}
+
+/// `A` is the type of the area of a path.
+pub(crate) trait Validate<A = i64> {
+    fn validate(&mut self, min_area: A) -> bool;
 }
 
 impl Validate for IntPath {
@@ -213,6 +758,6 @@ impl Validate for IntPath {
         let area = self.unsafe_area();
         let abs_area = area.abs() >> 1;
 
-        abs_area < min_area
+        abs_area >= min_area
     }
 }
\ No newline at end of file
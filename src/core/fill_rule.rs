@@ -1,8 +1,63 @@
+pub use crate::segm::shape_count::ShapeCount;
+
 /// Represents the rule used to determine the "bind" of a shape, affecting how shapes are filled. For a visual description, see [Fill Rules](https://ishape-rust.github.io/iShape-js/overlay/filling_rules/filling_rules.html).
 /// - `EvenOdd`: A point is part of a bind if a line from that point to infinity crosses an odd number of shape edges.
 /// - `NonZero`: A point is part of a bind if the number of left-to-right crossings differs from right-to-left crossings.
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// - `Positive`: A point is part of a bind if its winding number is greater than zero, so only counterclockwise paths fill the plane.
+/// - `Negative`: A point is part of a bind if its winding number is less than zero, so only clockwise paths fill the plane.
+/// - `Custom`: A point is part of a bind if a `CustomFill` function says so for its winding numbers.
+#[derive(Debug, Clone, Copy)]
 pub enum FillRule {
     EvenOdd,
-    NonZero
-}
\ No newline at end of file
+    NonZero,
+    Positive,
+    Negative,
+    Custom(CustomFill),
+}
+
+/// A user-defined fill rule. It receives the winding numbers of the subject and clip shapes at a point
+/// and returns whether the point is inside the subject and whether it is inside the clip, as `(subj, clip)`.
+///
+/// The function is called for both sides of every segment separately, so it must return the same answer for the same winding numbers.
+/// It must return `(false, false)` for zero winding numbers, because the plane outside all paths has them. Otherwise the outside is filled and the result is unbounded.
+/// Overlays which apply the fill rule to every input alone, like `SourceOverlay`, pass the winding number of the input as `subj` with a zero `clip`.
+///
+/// For example, the regions covered by at least 3 subject paths:
+/// ```
+/// use i_overlay::core::fill_rule::{FillRule, ShapeCount};
+///
+/// fn at_least_three(count: ShapeCount) -> (bool, bool) {
+///     (count.subj().abs() >= 3, count.clip() != 0)
+/// }
+///
+/// let fill_rule = FillRule::Custom(at_least_three);
+/// ```
+pub type CustomFill = fn(count: ShapeCount) -> (bool, bool);
+
+/// Built-in rules are equal to themselves. A `Custom` rule is not equal to any rule, even to itself,
+/// because Rust does not guarantee that a function has a single address or that different functions have different addresses.
+impl PartialEq for FillRule {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (FillRule::EvenOdd, FillRule::EvenOdd)
+                | (FillRule::NonZero, FillRule::NonZero)
+                | (FillRule::Positive, FillRule::Positive)
+                | (FillRule::Negative, FillRule::Negative)
+        )
+    }
+}
+
+impl FillRule {
+    /// Tells if a single winding number is filled. A `Custom` rule receives it as the subject winding number with a zero clip winding number.
+    #[inline(always)]
+    pub(crate) fn is_fill(&self, count: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => count & 1 == 1,
+            FillRule::NonZero => count != 0,
+            FillRule::Positive => count > 0,
+            FillRule::Negative => count < 0,
+            FillRule::Custom(fill) => fill(ShapeCount::new(count, 0)).0,
+        }
+    }
+}
@@ -6,7 +6,7 @@ pub(crate) trait Filter {
     fn filter(&self, fill_rule: OverlayRule) -> Vec<bool>;
 }
 
-impl Filter for Vec<OverlayLink> {
+impl<P> Filter for Vec<OverlayLink<P>> {
     #[inline(always)]
     fn filter(&self, overlay_rule: OverlayRule) -> Vec<bool> {
         match overlay_rule {
@@ -21,7 +21,7 @@ impl Filter for Vec<OverlayLink> {
     }
 }
 
-fn filter_subject(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_subject<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -32,7 +32,7 @@ fn filter_subject(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_clip(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_clip<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -43,7 +43,7 @@ fn filter_clip(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_intersect(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_intersect<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -56,7 +56,7 @@ fn filter_intersect(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_union(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_union<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -69,7 +69,7 @@ fn filter_union(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_difference(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_difference<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -84,7 +84,7 @@ fn filter_difference(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_inverse_difference(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_inverse_difference<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
@@ -99,7 +99,7 @@ fn filter_inverse_difference(links: &[OverlayLink]) -> Vec<bool> {
     }).collect()
 }
 
-fn filter_xor(links: &[OverlayLink]) -> Vec<bool> {
+fn filter_xor<P>(links: &[OverlayLink<P>]) -> Vec<bool> {
     links.iter().map(|link| {
         let fill = link.fill;
 
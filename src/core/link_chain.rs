@@ -0,0 +1,199 @@
+//! Chains of links between crossing nodes. A node inside a chain joins exactly two links, so all links
+//! of a chain separate the same two regions and are a boundary for the same overlay rules. The chains
+//! are found once and then every contour is traced chain by chain instead of link by link.
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_node::OverlayNode;
+
+/// A range of `LinkChains::links`, ordered from `start_node` to `end_node`.
+/// A closed chain has no crossing nodes at all and starts and ends at the same bridge node.
+struct LinkChain {
+    start: usize,
+    end: usize,
+    start_node: usize,
+    end_node: usize,
+    end_point: IntPoint,
+    is_closed: bool,
+}
+
+pub(crate) struct LinkChains {
+    chains: Vec<LinkChain>,
+    /// The links of all chains in a row.
+    links: Vec<usize>,
+    /// The point where every item of `links` starts in the direction of its chain.
+    points: Vec<IntPoint>,
+    /// The chain of every link of the graph and its position in `links`.
+    positions: Vec<(usize, usize)>,
+}
+
+impl LinkChains {
+    pub(crate) fn new(graph: &OverlayGraph) -> Self {
+        let count = graph.links.len();
+        let mut chains = Self {
+            chains: Vec::new(),
+            links: Vec::with_capacity(count),
+            points: Vec::with_capacity(count),
+            positions: vec![(usize::MAX, 0); count],
+        };
+
+        for (node_id, node) in graph.nodes.iter().enumerate() {
+            if let OverlayNode::Cross(indices) = node {
+                for &link_index in indices.iter() {
+                    if chains.positions[link_index].0 == usize::MAX {
+                        chains.add_chain(graph, node_id, link_index);
+                    }
+                }
+            }
+        }
+
+        // the rest are contours without crossing nodes
+        for link_index in 0..count {
+            if chains.positions[link_index].0 == usize::MAX {
+                chains.add_chain(graph, graph.link(link_index).a.id, link_index);
+            }
+        }
+
+        chains
+    }
+
+    #[inline(always)]
+    pub(crate) fn count(&self) -> usize {
+        self.chains.len()
+    }
+
+    #[inline(always)]
+    pub(crate) fn chain_index(&self, link_index: usize) -> usize {
+        self.positions[link_index].0
+    }
+
+    fn add_chain(&mut self, graph: &OverlayGraph, start_node: usize, link_index: usize) {
+        let chain_index = self.chains.len();
+        let start = self.links.len();
+        let mut link_index = link_index;
+        let mut node_id = start_node;
+
+        let end_point = loop {
+            let link = graph.link(link_index);
+            self.positions[link_index] = (chain_index, self.links.len());
+            self.links.push(link_index);
+
+            let (point, next) = if link.a.id == node_id { (link.a, link.b) } else { (link.b, link.a) };
+            self.points.push(point.point);
+            node_id = next.id;
+
+            match graph.node(node_id) {
+                OverlayNode::Bridge(bridge) if node_id != start_node => {
+                    link_index = if bridge[0] == link_index { bridge[1] } else { bridge[0] };
+                }
+                _ => break next.point,
+            }
+        };
+
+        let is_closed = matches!(graph.node(start_node), OverlayNode::Bridge(_));
+
+        self.chains.push(LinkChain { start, end: self.links.len(), start_node, end_node: node_id, end_point, is_closed });
+    }
+
+    /// Traces the contour which starts with `link_index` going out of `node_id`, the same way as `OverlayGraph` traces it link by link.
+    /// The index of every chain of the contour is pushed to `contour_chains`.
+    /// Returns `None` if the tour is broken, which is only possible for an inconsistent graph.
+    pub(crate) fn trace(
+        &self,
+        graph: &OverlayGraph,
+        link_index: usize,
+        node_id: usize,
+        visited: &mut [bool],
+        contour_chains: &mut Vec<usize>,
+    ) -> Option<IntPath> {
+        let (start_chain, position) = self.positions[link_index];
+        let chain = &self.chains[start_chain];
+        let is_forward = self.is_forward(graph, position, node_id);
+
+        // the position of the first node of the contour inside its chain
+        let first = if is_forward { position } else { position + 1 };
+
+        let mut path = IntPath::new();
+        contour_chains.push(start_chain);
+
+        if chain.is_closed {
+            let end = if is_forward { chain.end } else { chain.start };
+            self.walk(chain, first, end, visited, &mut path);
+            let start = if is_forward { chain.start } else { chain.end };
+            self.walk(chain, start, first, visited, &mut path);
+            return Some(path);
+        }
+
+        let last_node_id = node_id;
+        let (mut last_link, mut node_id) = if is_forward {
+            (self.walk(chain, first, chain.end, visited, &mut path), chain.end_node)
+        } else {
+            (self.walk(chain, first, chain.start, visited, &mut path), chain.start_node)
+        };
+
+        // if the first node is a bridge, the contour ends when it comes back into the first chain
+        let is_first_bridge = first != chain.start && first != chain.end;
+
+        while is_first_bridge || node_id != last_node_id {
+            let indices = match graph.node(node_id) {
+                OverlayNode::Cross(indices) => indices,
+                OverlayNode::Bridge(_) => return None,
+            };
+
+            let next_link = graph.find_nearest_counter_wise_link_to(last_link, node_id, indices, visited)?;
+            let (chain_index, position) = self.positions[next_link];
+            let chain = &self.chains[chain_index];
+            let is_forward = self.is_forward(graph, position, node_id);
+            contour_chains.push(chain_index);
+
+            if chain_index == start_chain {
+                let begin = if is_forward { position } else { position + 1 };
+                self.walk(chain, begin, first, visited, &mut path);
+                return Some(path);
+            }
+
+            if is_forward {
+                last_link = self.walk(chain, chain.start, chain.end, visited, &mut path);
+                node_id = chain.end_node;
+            } else {
+                last_link = self.walk(chain, chain.end, chain.start, visited, &mut path);
+                node_id = chain.start_node;
+            }
+        }
+
+        Some(path)
+    }
+
+    /// Tells whether the link at `position` goes out of `node_id` in the direction of its chain.
+    #[inline(always)]
+    fn is_forward(&self, graph: &OverlayGraph, position: usize, node_id: usize) -> bool {
+        let link = graph.link(self.links[position]);
+        let point = if link.a.id == node_id { link.a.point } else { link.b.point };
+        self.points[position] == point
+    }
+
+    /// Walks a chain from the node at position `from` to the node at position `to`, pushing every node except the last one.
+    /// Returns the last walked link.
+    fn walk(&self, chain: &LinkChain, from: usize, to: usize, visited: &mut [bool], path: &mut IntPath) -> usize {
+        if from < to {
+            path.extend_from_slice(&self.points[from..to]);
+            for &link_index in self.links[from..to].iter() {
+                visited[link_index] = true;
+            }
+            self.links[to - 1]
+        } else if from > to {
+            for position in (to + 1..=from).rev() {
+                let point = if position == chain.end { chain.end_point } else { self.points[position] };
+                path.push(point);
+            }
+            for &link_index in self.links[to..from].iter() {
+                visited[link_index] = true;
+            }
+            self.links[to]
+        } else {
+            usize::MAX
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! This module compares two shape sets by their areas, like the intersection over union used to score detections.
+//! All areas are measured in a single pass over the links of one overlay graph.
+
+use i_shape::int::shape::IntShape;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::Overlay;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::segm::segment::{CLIP_BOTTOM, CLIP_TOP, NONE, SUBJ_BOTTOM, SUBJ_TOP};
+
+/// The areas of the subject, the clip, their intersection and their union.
+/// Shapes are taken after applying the fill rule, so common edges and touching shapes are resolved by the overlay.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AreaMetrics {
+    pub subj_area: f64,
+    pub clip_area: f64,
+    pub intersect_area: f64,
+    pub union_area: f64,
+}
+
+impl AreaMetrics {
+    /// The area covered by exactly one of the shape sets, the same as the area of `OverlayRule::Xor`.
+    #[inline]
+    pub fn symmetric_difference_area(&self) -> f64 {
+        self.union_area - self.intersect_area
+    }
+
+    /// The intersection over union (Jaccard index), from `0.0` for disjoint shapes to `1.0` for equal ones.
+    /// Two empty shape sets are equal, so the result is `1.0`.
+    #[inline]
+    pub fn iou(&self) -> f64 {
+        if self.union_area == 0.0 {
+            1.0
+        } else {
+            self.intersect_area / self.union_area
+        }
+    }
+
+    /// The Dice coefficient, the doubled intersection area over the sum of both areas, from `0.0` to `1.0`.
+    /// Two empty shape sets are equal, so the result is `1.0`.
+    #[inline]
+    pub fn dice(&self) -> f64 {
+        let sum = self.subj_area + self.clip_area;
+        if sum == 0.0 {
+            1.0
+        } else {
+            2.0 * self.intersect_area / sum
+        }
+    }
+
+    #[inline]
+    pub(crate) fn scaled(self, scale: f64) -> Self {
+        let scale = scale * scale;
+        Self {
+            subj_area: self.subj_area * scale,
+            clip_area: self.clip_area * scale,
+            intersect_area: self.intersect_area * scale,
+            union_area: self.union_area * scale,
+        }
+    }
+}
+
+pub trait Metrics {
+    /// Measures the areas of two shape sets, their intersection and their union by a single overlay.
+    /// - `other`: The shapes to compare with, they are the clip of the overlay.
+    /// - `fill_rule`: Fill rule to determine filled areas of both shape sets.
+    /// - Returns: `AreaMetrics` of the shapes, the shapes of `self` are the subject.
+    fn metrics(&self, other: &Self, fill_rule: FillRule) -> AreaMetrics;
+}
+
+impl Metrics for [IntShape] {
+    #[inline]
+    fn metrics(&self, other: &Self, fill_rule: FillRule) -> AreaMetrics {
+        Overlay::with_shapes(self, other).into_graph(fill_rule).metrics()
+    }
+}
+
+impl OverlayGraph {
+    /// Measures the areas of the subject, the clip, their intersection and their union at once, see `OverlayGraph::area`.
+    /// - Returns: `AreaMetrics` of the graph.
+    pub fn metrics(&self) -> AreaMetrics {
+        let mut subj: i128 = 0;
+        let mut clip: i128 = 0;
+        let mut intersect: i128 = 0;
+        let mut union: i128 = 0;
+
+        for link in self.links.iter() {
+            let fill = link.fill;
+            let subj_top = fill & SUBJ_TOP != NONE;
+            let subj_bottom = fill & SUBJ_BOTTOM != NONE;
+            let clip_top = fill & CLIP_TOP != NONE;
+            let clip_bottom = fill & CLIP_BOTTOM != NONE;
+
+            // a result adds the trapezoid under the link if it is below the link and subtracts it if it is above
+            let trapezoid = link.trapezoid_x2();
+            let sign = |top: bool, bottom: bool| bottom as i128 - top as i128;
+
+            subj += sign(subj_top, subj_bottom) * trapezoid;
+            clip += sign(clip_top, clip_bottom) * trapezoid;
+            intersect += sign(subj_top && clip_top, subj_bottom && clip_bottom) * trapezoid;
+            union += sign(subj_top || clip_top, subj_bottom || clip_bottom) * trapezoid;
+        }
+
+        AreaMetrics {
+            subj_area: subj as f64 * 0.5,
+            clip_area: clip as f64 * 0.5,
+            intersect_area: intersect as f64 * 0.5,
+            union_area: union as f64 * 0.5,
+        }
+    }
+}
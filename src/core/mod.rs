@@ -5,6 +5,17 @@ pub mod overlay_rule;
 pub mod extract;
 pub mod fill_rule;
 pub mod simplify;
+pub mod error;
+pub mod range;
+pub mod context;
+pub mod union_all;
+pub mod component;
+pub mod query;
+pub mod predicate;
+pub mod area;
+pub mod metrics;
+pub mod shared_boundary;
 pub(crate) mod overlay_link;
 pub(crate) mod overlay_node;
 pub(crate) mod filter;
+pub(crate) mod link_chain;
@@ -7,15 +7,21 @@ use i_shape::int::path::IntPath;
 use i_shape::int::shape::{IntShape, PointsCount};
 use i_shape::int::simple::Simple;
 
+use crate::core::context::OverlayContext;
+use crate::core::error::OverlayError;
 use crate::core::fill_rule::FillRule;
+use crate::core::range::{is_in_range, RangeTransform};
 use crate::core::overlay_rule::OverlayRule;
 use crate::segm::shape_count::ShapeCount;
 use crate::segm::segment::{CLIP_BOTH, NONE, SegmentFill, ShapeEdgesMerge, SUBJ_BOTH};
 
 use crate::core::solver::Solver;
+use crate::coverage::graph::CoverageOverlayGraph;
 use crate::fill::solver::FillSolver;
+use crate::incremental::graph::IncrementalOverlayGraph;
 use crate::segm::segment::Segment;
 use crate::segm::x_segment::XSegment;
+use crate::line::count::LineCount;
 use crate::sort::SmartSort;
 use crate::split::solver::SplitSolver;
 use crate::vector::edge::{VectorEdge, VectorShape};
@@ -26,7 +32,7 @@ use super::overlay_graph::OverlayGraph;
 /// Note: All operations except for `Difference` are commutative, meaning the order of `Subject` and `Clip` shapes does not impact the outcome.
 /// - `Subject`: The primary shape(s) for operations. Acts as the base layer in the operation.
 /// - `Clip`: The modifying shape(s) that are applied to the `Subject`. Determines how the `Subject` is altered or intersected.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ShapeType {
     Subject,
     Clip,
@@ -36,6 +42,7 @@ pub enum ShapeType {
 #[derive(Clone)]
 pub struct Overlay {
     edges: Vec<Segment>,
+    lines: Vec<Segment<LineCount>>,
 }
 
 impl Overlay {
@@ -45,6 +52,7 @@ impl Overlay {
     pub fn new(capacity: usize) -> Self {
         Self {
             edges: Vec::with_capacity(capacity),
+            lines: Vec::new(),
         }
     }
 
@@ -100,6 +108,30 @@ impl Overlay {
         }
     }
 
+    /// Adds an open polyline to the overlay. Unlike paths, a polyline is not closed from its last point to the first and does not fill any area.
+    /// It is split by the edges of the added shapes, so its pieces can be extracted with `OverlayGraph::extract_lines`.
+    /// - `path`: A reference to a `IntPath` instance, a sequence of at least two points.
+    ///
+    /// Polyline edges which overlap each other are merged into one piece, so such a piece is extracted once, in the direction of most of its edges.
+    ///
+    /// Note: Polylines are only used by `into_graph`, `into_graph_with_solver` and their `try_` variants. Other conversions panic or return `OverlayError::UnsupportedPolylines` for an overlay with polylines.
+    pub fn add_polyline(&mut self, path: &[IntPoint]) {
+        for window in path.windows(2) {
+            let (p0, p1) = (window[0], window[1]);
+            if p0 != p1 {
+                self.lines.push(Segment::create_and_validate(p0, p1, LineCount::with_line()));
+            }
+        }
+    }
+
+    /// Adds multiple open polylines to the overlay.
+    /// - `paths`: An array of `IntPath` instances to be added to the overlay as polylines.
+    pub fn add_polylines(&mut self, paths: &[IntPath]) {
+        for path in paths.iter() {
+            self.add_polyline(path);
+        }
+    }
+
     /// Convert into segments from the added paths or shapes according to the specified fill rule.
     /// - `fill_rule`: The fill rule to use when determining the inside of shapes.
     /// - `solver`: Type of solver to use.
@@ -114,11 +146,63 @@ impl Overlay {
         (segments, fills)
     }
 
+    /// Removes all added paths, keeping the allocated memory, so the overlay can be reused for the next operation.
+    pub fn clear(&mut self) {
+        self.edges.clear();
+        self.lines.clear();
+    }
+
+    /// Builds the `OverlayGraph` in the buffers of the context without consuming the overlay. Unlike `into_graph`, the segments, fills, links and nodes
+    /// are written into the vectors of the context, so they are reallocated only when they grow.
+    /// - `context`: The context which owns the buffers and the resulting graph.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - Returns: The graph, it stays in the context until the next call, `OverlayError::UnsupportedPolylines` if polylines were added,
+    ///   or `OverlayError::TopologyFailure` if the graph can not be built. In case of an error the graph of the context is left empty.
+    pub fn build_graph<'a>(&self, context: &'a mut OverlayContext, fill_rule: FillRule) -> Result<&'a OverlayGraph, OverlayError> {
+        if !self.lines.is_empty() {
+            context.graph.links.clear();
+            context.graph.nodes.clear();
+            return Err(OverlayError::UnsupportedPolylines);
+        }
+
+        let solver = context.solver;
+        let segments = &mut context.segments;
+        let fills = &mut context.fills;
+
+        segments.clear();
+        segments.extend_from_slice(&self.edges);
+        fills.clear();
+
+        if !segments.is_empty() {
+            segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+            segments.merge_if_needed();
+
+            SplitSolver::new(solver).split(segments);
+
+            FillSolver::fill_into(&fill_rule, &solver, segments, fills);
+
+            clean_if_needed(segments, fills);
+        }
+
+        if let Err(error) = context.graph.rebuild(solver, segments, fills, &mut context.end_bs) {
+            context.graph.links.clear();
+            context.graph.nodes.clear();
+            return Err(error);
+        }
+
+        Ok(&context.graph)
+    }
+
     /// Convert into vector shapes from the added paths or shapes, applying the specified fill and overlay rules. This method is particularly useful for development purposes and for creating visualizations in educational demos, where understanding the impact of different rules on the final geometry is crucial.
     /// - `fill_rule`: The fill rule to use for the shapes.
     /// - `overlay_rule`: The overlay rule to apply.
     /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If polylines were added, or the graph can not be built or a contour can not be traced, which is only possible for an inconsistent graph.
     pub fn into_shape_vectors(self, fill_rule: FillRule, overlay_rule: OverlayRule, solver: Solver) -> Vec<VectorShape> {
+        self.assert_no_polylines();
         if self.edges.is_empty() {
             return Vec::new();
         }
@@ -130,7 +214,11 @@ impl Overlay {
     /// Convert into vectors from the added paths or shapes, applying the specified fill rule. This method is particularly useful for development purposes and for creating visualizations in educational demos, where understanding the impact of different rules on the final geometry is crucial.
     /// - `fill_rule`: The fill rule to use for the shapes.
     /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If polylines were added, or the graph can not be built, see `try_into_graph_with_solver`.
     pub fn into_separate_vectors(self, fill_rule: FillRule, solver: Solver) -> Vec<VectorEdge> {
+        self.assert_no_polylines();
         if self.edges.is_empty() {
             return Vec::new();
         }
@@ -140,15 +228,152 @@ impl Overlay {
 
     /// Convert into `OverlayGraph` from the added paths or shapes using the specified fill rule. This graph is the foundation for executing boolean operations, allowing for the analysis and manipulation of the geometric data. The `OverlayGraph` created by this method represents a preprocessed state of the input shapes, optimized for the application of boolean operations based on the provided fill rule.
     /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes, influencing how the resulting graph represents intersections and unions.
+    ///
+    /// # Panics
+    /// If the graph can not be built, see `try_into_graph`.
     pub fn into_graph(self, fill_rule: FillRule) -> OverlayGraph {
-        OverlayGraph::new(Default::default(), self.into_segments(fill_rule, Default::default()))
+        self.into_graph_with_solver(fill_rule, Default::default())
     }
 
     /// Convert into `OverlayGraph` from the added paths or shapes using the specified fill rule. This graph is the foundation for executing boolean operations, allowing for the analysis and manipulation of the geometric data. The `OverlayGraph` created by this method represents a preprocessed state of the input shapes, optimized for the application of boolean operations based on the provided fill rule.
     /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes, influencing how the resulting graph represents intersections and unions.
     /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If the graph can not be built, see `try_into_graph_with_solver`.
     pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> OverlayGraph {
-        OverlayGraph::new(solver, self.into_segments(fill_rule, solver))
+        if self.lines.is_empty() {
+            return OverlayGraph::new(solver, self.into_segments(fill_rule, solver));
+        }
+
+        let mut segments = self.lines;
+        segments.reserve(self.edges.len());
+        segments.extend(self.edges.into_iter().map(|edge| Segment { x_segment: edge.x_segment, count: LineCount::with_shape(edge.count) }));
+
+        OverlayGraph::with_lines(solver, fill_rule, segments)
+    }
+
+    /// Same as `into_graph`, but returns an error instead of panicking or overflowing.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - Returns: `OverlayError::CoordinateOverflow` if a point is out of the safe range, or `OverlayError::TopologyFailure` if the graph can not be built.
+    pub fn try_into_graph(self, fill_rule: FillRule) -> Result<OverlayGraph, OverlayError> {
+        self.try_into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Same as `into_graph_with_solver`, but returns an error instead of panicking or overflowing.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    /// - Returns: `OverlayError::CoordinateOverflow` if a point is out of the safe range, or `OverlayError::TopologyFailure` if the graph can not be built.
+    pub fn try_into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> Result<OverlayGraph, OverlayError> {
+        self.validate_range()?;
+
+        if self.lines.is_empty() {
+            return OverlayGraph::try_new(solver, self.into_segments(fill_rule, solver));
+        }
+
+        let mut segments = self.lines;
+        segments.reserve(self.edges.len());
+        segments.extend(self.edges.into_iter().map(|edge| Segment { x_segment: edge.x_segment, count: LineCount::with_shape(edge.count) }));
+
+        OverlayGraph::try_with_lines(solver, fill_rule, segments)
+    }
+
+    /// Checks that all added points are in the safe range `MIN_COORD..=MAX_COORD`, see `core::range`.
+    /// Points out of this range can overflow the calculation of cross points.
+    /// - Returns: `OverlayError::CoordinateOverflow` with the first point out of the range.
+    pub fn validate_range(&self) -> Result<(), OverlayError> {
+        for x_segment in self.x_segments() {
+            for p in [x_segment.a, x_segment.b] {
+                if !is_in_range(p) {
+                    return Err(OverlayError::CoordinateOverflow(p));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves and scales down all added points, so they are in the safe range `MIN_COORD..=MAX_COORD`.
+    /// Nothing is changed if the points are already in the range. The input is moved to the center of the range first,
+    /// and scaled by a power of two only if its span is still too wide, so small details may collapse.
+    /// - Returns: The applied transform, use `RangeTransform::restore_shapes` to map the result back.
+    pub fn fit_range(&mut self) -> RangeTransform {
+        let mut x_segments = self.x_segments();
+        let first = match x_segments.next() {
+            Some(x_segment) => x_segment.a,
+            None => return RangeTransform::IDENTITY,
+        };
+
+        let (mut min, mut max) = (first, first);
+        for x_segment in x_segments {
+            for p in [x_segment.a, x_segment.b] {
+                min = IntPoint::new(min.x.min(p.x), min.y.min(p.y));
+                max = IntPoint::new(max.x.max(p.x), max.y.max(p.y));
+            }
+        }
+
+        let transform = RangeTransform::with_bounds(min, max);
+        if transform.is_identity() {
+            return transform;
+        }
+
+        self.edges = self.edges.iter().filter_map(|edge| edge.transform(&transform)).collect();
+        self.lines = self.lines.iter().filter_map(|line| line.transform(&transform)).collect();
+
+        transform
+    }
+
+    #[inline]
+    fn x_segments(&self) -> impl Iterator<Item = &XSegment> {
+        self.edges.iter().map(|edge| &edge.x_segment)
+            .chain(self.lines.iter().map(|line| &line.x_segment))
+    }
+
+    /// Convert into `CoverageOverlayGraph` from the added subject paths or shapes. Instead of a fill rule, this graph keeps the subject winding number of every region, so regions can be extracted by the number of paths covering them. Clip paths are ignored.
+    ///
+    /// # Panics
+    /// If polylines were added, or a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_coverage_graph(self) -> CoverageOverlayGraph {
+        self.into_coverage_graph_with_solver(Default::default())
+    }
+
+    /// Convert into `CoverageOverlayGraph` from the added subject paths or shapes.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If polylines were added, or a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_coverage_graph_with_solver(self, solver: Solver) -> CoverageOverlayGraph {
+        self.assert_no_polylines();
+        let segments = self.edges.into_iter().filter(|edge| edge.count.subj != 0).collect();
+        CoverageOverlayGraph::build(solver, segments)
+    }
+
+    /// Convert into `IncrementalOverlayGraph` from the added paths or shapes using the specified fill rule. Unlike `OverlayGraph`, paths can be added to and removed from this graph later, and only the region around the edited path is processed again.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    ///
+    /// # Panics
+    /// If polylines were added, or a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_incremental_graph(self, fill_rule: FillRule) -> IncrementalOverlayGraph {
+        self.into_incremental_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `IncrementalOverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use, for the initial build and for every edit.
+    ///
+    /// # Panics
+    /// If polylines were added, or a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_incremental_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> IncrementalOverlayGraph {
+        self.assert_no_polylines();
+        IncrementalOverlayGraph::build(solver, fill_rule, self.edges)
+    }
+
+    /// Panics for the conversions which only support closed paths, so the added polylines are not thrown away silently.
+    #[inline]
+    fn assert_no_polylines(&self) {
+        if !self.lines.is_empty() {
+            panic!("{}", OverlayError::UnsupportedPolylines);
+        }
     }
 
     fn prepare_segments_and_fills(self, fill_rule: FillRule, solver: Solver) -> (Vec<Segment>, Vec<SegmentFill>) {
@@ -159,14 +384,13 @@ impl Overlay {
 
         SplitSolver::new(solver).split(&mut segments);
 
-        let is_list = solver.is_list_fill(&segments);
-        let fills = FillSolver::fill(fill_rule, is_list, &segments);
+        let fills = FillSolver::fill(&fill_rule, &solver, &segments);
 
         (segments, fills)
     }
 }
 
-trait BuildEdges {
+pub(crate) trait BuildEdges {
     fn append_edges(&mut self, path: &[IntPoint], shape_type: ShapeType);
 
     fn append_private_edges(&mut self, path: &[IntPoint], shape_type: ShapeType);
@@ -215,7 +439,7 @@ impl BuildEdges for Vec<Segment> {
     }
 }
 
-trait Fill {
+pub(crate) trait Fill {
     fn is_empty(&self) -> bool;
 }
 
@@ -227,7 +451,7 @@ impl Fill for SegmentFill {
 }
 
 #[inline(always)]
-fn clean_if_needed(segments: &mut Vec<Segment>, fills: &mut Vec<SegmentFill>) {
+pub(crate) fn clean_if_needed(segments: &mut Vec<Segment>, fills: &mut Vec<SegmentFill>) {
     if let Some(first_empty_index) = fills.iter().position(|fill| fill.is_empty()) {
         clean(segments, fills, first_empty_index);
     }
@@ -3,10 +3,12 @@
 //! based on the overlay rule applied.
 
 use i_float::point::IntPoint;
-use i_float::triangle::Triangle;
 
+use crate::core::error::OverlayError;
 use crate::core::solver::Solver;
 use crate::id_point::IdPoint;
+use crate::line::graph::OverlayLine;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::end::End;
 use crate::segm::segment::{Segment, SegmentFill};
 use crate::sort::SmartSort;
@@ -20,23 +22,27 @@ use super::{overlay_link::OverlayLink, overlay_node::OverlayNode};
 ///
 /// Use `OverlayGraph` to perform boolean operations on the geometric shapes you've added to an `Overlay`, after it has processed the shapes according to the specified fill and overlay rules.
 /// [More information](https://ishape-rust.github.io/iShape-js/overlay/overlay_graph/overlay_graph.html) about Overlay Graph.
-pub struct OverlayGraph {
+pub struct OverlayGraph<P = IntPoint> {
     pub(crate) solver: Solver,
     pub(crate) nodes: Vec<OverlayNode>,
-    pub(crate) links: Vec<OverlayLink>,
+    pub(crate) links: Vec<OverlayLink<P>>,
+    pub(crate) lines: Vec<OverlayLine>,
 }
 
 impl OverlayGraph {
-    pub(super) fn new(solver: Solver, bundle: (Vec<Segment>, Vec<SegmentFill>)) -> Self {
+    pub(crate) fn new(solver: Solver, bundle: (Vec<Segment>, Vec<SegmentFill>)) -> Self {
+        Self::with_links(solver, Self::bundle_links(bundle))
+    }
+
+    pub(super) fn try_new(solver: Solver, bundle: (Vec<Segment>, Vec<SegmentFill>)) -> Result<Self, OverlayError> {
+        Self::try_with_links(solver, Self::bundle_links(bundle))
+    }
+
+    fn bundle_links(bundle: (Vec<Segment>, Vec<SegmentFill>)) -> Vec<OverlayLink> {
         let segments = bundle.0;
         let fills = bundle.1;
 
-        if segments.is_empty() {
-            return Self { solver: Default::default(), nodes: vec![], links: vec![] };
-        }
-
-        let n = segments.len();
-        let mut links: Vec<OverlayLink> = segments
+        segments
             .into_iter().enumerate()
             .map(|(index, segment)| {
                 let fill = *unsafe { fills.get_unchecked(index) };
@@ -45,15 +51,67 @@ impl OverlayGraph {
                     IdPoint { id: 0, point: segment.x_segment.b },
                     fill,
                 )
-            }).collect();
+            }).collect()
+    }
 
-        let mut end_bs: Vec<End> = links.iter().enumerate()
-            .map(|(i, link)| End { index: i, point: link.b.point })
-            .collect();
+    /// Replaces the content of the graph with new segments, keeping the allocated memory.
+    /// - `end_bs`: A buffer for sorting the ends of the links.
+    pub(crate) fn rebuild(&mut self, solver: Solver, segments: &[Segment], fills: &[SegmentFill], end_bs: &mut Vec<End>) -> Result<(), OverlayError> {
+        self.solver = solver;
+        self.lines.clear();
+        self.links.clear();
+        self.links.extend(segments.iter().zip(fills.iter()).map(|(segment, &fill)| {
+            OverlayLink::new(
+                IdPoint { id: 0, point: segment.x_segment.a },
+                IdPoint { id: 0, point: segment.x_segment.b },
+                fill,
+            )
+        }));
+
+        self.build_nodes(end_bs)
+    }
+}
 
-        end_bs.smart_sort_by(&solver, |a, b| a.point.cmp(&b.point));
+// the bounds are on the methods, so the crate-private trait does not leak into the public type
+impl<P> OverlayGraph<P> {
+    /// Builds the nodes for links which are already sorted by `a`.
+    /// Panics if a node has less than two links, see `try_with_links`.
+    pub(crate) fn with_links(solver: Solver, links: Vec<OverlayLink<P>>) -> Self
+    where
+        P: OverlayPoint,
+    {
+        Self::try_with_links(solver, links).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        let mut nodes: Vec<OverlayNode> = Vec::with_capacity(n);
+    /// Same as `with_links`, but returns `OverlayError::TopologyFailure` if a node has less than two links.
+    pub(crate) fn try_with_links(solver: Solver, links: Vec<OverlayLink<P>>) -> Result<Self, OverlayError>
+    where
+        P: OverlayPoint,
+    {
+        let mut graph = Self { solver, nodes: Vec::new(), links, lines: Vec::new() };
+        graph.build_nodes(&mut Vec::new())?;
+        Ok(graph)
+    }
+
+    fn build_nodes(&mut self, end_bs: &mut Vec<End<P>>) -> Result<(), OverlayError>
+    where
+        P: OverlayPoint,
+    {
+        self.nodes.clear();
+        if self.links.is_empty() {
+            return Ok(());
+        }
+
+        let n = self.links.len();
+        let links = &mut self.links;
+
+        end_bs.clear();
+        end_bs.extend(links.iter().enumerate().map(|(i, link)| End { index: i, point: link.b.point }));
+
+        end_bs.smart_sort_by(&self.solver, |a, b| a.point.cmp(&b.point));
+
+        let nodes = &mut self.nodes;
+        nodes.reserve(n);
 
         let mut ai = 0;
         let mut bi = 0;
@@ -101,8 +159,9 @@ impl OverlayGraph {
                 }
             }
 
-            debug_assert!(indices.len() > 1, "indices: {}", indices.len());
-            // nodes.push(OverlayNode { indices });
+            if indices.len() < 2 {
+                return Err(OverlayError::TopologyFailure);
+            }
 
             nodes.push(OverlayNode::new(indices.as_slice()));
             indices.clear();
@@ -110,7 +169,7 @@ impl OverlayGraph {
 
         debug_assert!(nodes.len() <= n);
 
-        Self { solver, nodes, links }
+        Ok(())
     }
 
     pub(crate) fn find_nearest_counter_wise_link_to(
@@ -119,54 +178,77 @@ impl OverlayGraph {
         node_id: usize,
         indices: &[usize],
         visited: &[bool],
-    ) -> usize {
+    ) -> Option<usize>
+    where
+        P: OverlayPoint,
+    {
+        self.find_nearest_counter_wise_link_by(target_index, node_id, indices, |link_index| {
+            let &is_visited = unsafe { visited.get_unchecked(link_index) };
+            !is_visited
+        })
+    }
+
+    /// Same as `find_nearest_counter_wise_link_to`, but the candidate links are selected by `is_available`.
+    /// Returns `None` if no link is available.
+    pub(crate) fn find_nearest_counter_wise_link_by<F>(
+        &self,
+        target_index: usize,
+        node_id: usize,
+        indices: &[usize],
+        is_available: F,
+    ) -> Option<usize>
+    where
+        P: OverlayPoint,
+        F: Fn(usize) -> bool,
+    {
         let target = self.link(target_index);
         let (c, a) = if target.a.id == node_id {
             (target.a.point, target.b.point)
         } else { (target.b.point, target.a.point) };
 
-        let (mut it_index, mut best_index) = indices.first_not_visited(visited);
+        let (mut it_index, mut best_index) = indices.first_available(&is_available)?;
 
-        let mut link_index = indices.next_link(&mut it_index, visited);
+        let mut link_index = indices.next_available(&mut it_index, &is_available);
 
         if link_index >= self.links.len() {
             // no more links
-            return best_index;
+            return Some(best_index);
         }
 
-        let va = a.subtract(c);
-        let b = self.link(best_index).other(node_id).point;
-        let mut vb = b.subtract(c);
-        let mut more_180 = va.cross_product(vb) <= 0;
+        let zero = P::Product::default();
+        let mut b = self.link(best_index).other(node_id).point;
+        let mut more_180 = P::cross_product(c, a, b) <= zero;
 
         while link_index < self.links.len() {
             let link = &self.links[link_index];
             let p = link.other(node_id).point;
-            let vp = p.subtract(c);
-            let new_more_180 = va.cross_product(vp) <= 0;
+            let new_more_180 = P::cross_product(c, a, p) <= zero;
 
             if new_more_180 == more_180 {
                 // both more 180 or both less 180
-                let is_clock_wise = vp.cross_product(vb) > 0;
+                let is_clock_wise = P::cross_product(c, p, b) > zero;
                 if is_clock_wise {
                     best_index = link_index;
-                    vb = vp;
+                    b = p;
                 }
             } else if more_180 {
                 // new less 180
                 more_180 = false;
                 best_index = link_index;
-                vb = vp;
+                b = p;
             }
 
-            link_index = indices.next_link(&mut it_index, visited);
+            link_index = indices.next_available(&mut it_index, &is_available);
         }
 
-        best_index
+        Some(best_index)
     }
 
     #[inline]
-    pub(crate) fn find_left_top_link(&self, link_index: usize, visited: &[bool]) -> usize {
+    pub(crate) fn find_left_top_link(&self, link_index: usize, visited: &[bool]) -> usize
+    where
+        P: OverlayPoint,
+    {
         let top = self.link(link_index);
         debug_assert!(top.is_direct());
 
@@ -183,7 +265,10 @@ impl OverlayGraph {
     }
 
     #[inline(always)]
-    fn find_left_top_link_on_indices(&self, link: &OverlayLink, link_index: usize, indices: &[usize], visited: &[bool]) -> usize {
+    fn find_left_top_link_on_indices(&self, link: &OverlayLink<P>, link_index: usize, indices: &[usize], visited: &[bool]) -> usize
+    where
+        P: OverlayPoint,
+    {
         let mut top_index = link_index;
         let mut top = link;
 
@@ -194,7 +279,7 @@ impl OverlayGraph {
                 continue;
             }
             let link = self.link(i);
-            if !link.is_direct() || Triangle::is_clockwise_point(top.a.point, top.b.point, link.b.point) {
+            if !link.is_direct() || P::is_clockwise(top.a.point, top.b.point, link.b.point) {
                 continue;
             }
 
@@ -211,10 +296,13 @@ impl OverlayGraph {
     }
 
     #[inline(always)]
-    fn find_left_top_link_on_bridge(&self, bridge: &[usize; 2]) -> usize {
+    fn find_left_top_link_on_bridge(&self, bridge: &[usize; 2]) -> usize
+    where
+        P: OverlayPoint,
+    {
         let l0 = self.link(bridge[0]);
         let l1 = self.link(bridge[1]);
-        if Triangle::is_clockwise_point(l0.a.point, l0.b.point, l1.b.point) {
+        if P::is_clockwise(l0.a.point, l0.b.point, l1.b.point) {
             bridge[0]
         } else {
             bridge[1]
@@ -222,7 +310,7 @@ impl OverlayGraph {
     }
 
     #[inline(always)]
-    pub(crate) fn link(&self, index: usize) -> &OverlayLink {
+    pub(crate) fn link(&self, index: usize) -> &OverlayLink<P> {
         unsafe { self.links.get_unchecked(index) }
     }
 
@@ -230,35 +318,79 @@ impl OverlayGraph {
     pub(crate) fn node(&self, index: usize) -> &OverlayNode {
         unsafe { self.nodes.get_unchecked(index) }
     }
+
+    /// Returns the id of the node at `point`, if there is one.
+    pub(crate) fn find_node(&self, point: P) -> Option<usize>
+    where
+        P: OverlayPoint,
+    {
+        let node_id = self.node_partition(|p| p < point);
+        if node_id < self.nodes.len() && self.node_point(node_id) == point {
+            Some(node_id)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of nodes whose point satisfies `predicate`, the nodes are sorted by their points and must be partitioned by it.
+    pub(crate) fn node_partition<F: Fn(P) -> bool>(&self, predicate: F) -> usize
+    where
+        P: OverlayPoint,
+    {
+        let mut lo = 0;
+        let mut hi = self.nodes.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if predicate(self.node_point(mid)) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        lo
+    }
+
+    #[inline]
+    fn node_point(&self, node_id: usize) -> P
+    where
+        P: OverlayPoint,
+    {
+        let link_index = match self.node(node_id) {
+            OverlayNode::Bridge(bridge) => bridge[0],
+            OverlayNode::Cross(indices) => indices[0],
+        };
+        let link = self.link(link_index);
+        if link.a.id == node_id { link.a.point } else { link.b.point }
+    }
 }
 
 trait OverlayNodeIndices {
-    fn first_not_visited(&self, visited: &[bool]) -> (usize, usize);
-    fn next_link(&self, it_index: &mut usize, visited: &[bool]) -> usize;
+    fn first_available<F: Fn(usize) -> bool>(&self, is_available: &F) -> Option<(usize, usize)>;
+    fn next_available<F: Fn(usize) -> bool>(&self, it_index: &mut usize, is_available: &F) -> usize;
 }
 
 impl OverlayNodeIndices for [usize] {
     #[inline(always)]
-    fn first_not_visited(&self, visited: &[bool]) -> (usize, usize) {
+    fn first_available<F: Fn(usize) -> bool>(&self, is_available: &F) -> Option<(usize, usize)> {
         let mut it_index = 0;
         while it_index < self.len() {
             let link_index = self[it_index];
             it_index += 1;
-            let &is_visited = unsafe { visited.get_unchecked(link_index) };
-            if !is_visited {
-                return (it_index, link_index);
+            if is_available(link_index) {
+                return Some((it_index, link_index));
             }
         }
-        unreachable!("The loop should always return");
+
+        None
     }
 
     #[inline(always)]
-    fn next_link(&self, it_index: &mut usize, visited: &[bool]) -> usize {
+    fn next_available<F: Fn(usize) -> bool>(&self, it_index: &mut usize, is_available: &F) -> usize {
         while *it_index < self.len() {
             let link_index = self[*it_index];
             *it_index += 1;
-            let &is_visited = unsafe { visited.get_unchecked(link_index) };
-            if !is_visited {
+            if is_available(link_index) {
                 return link_index
             }
         }
@@ -267,13 +399,13 @@ impl OverlayNodeIndices for [usize] {
     }
 }
 
-trait Size {
-    fn size(&self, point: IntPoint, index: usize) -> usize;
+trait Size<P> {
+    fn size(&self, point: P, index: usize) -> usize;
 }
 
-impl Size for Vec<OverlayLink> {
+impl<P: OverlayPoint> Size<P> for Vec<OverlayLink<P>> {
     #[inline]
-    fn size(&self, point: IntPoint, index: usize) -> usize {
+    fn size(&self, point: P, index: usize) -> usize {
         let mut i = index + 1;
         while i < self.len() && self[i].a.point == point {
             i += 1;
@@ -283,9 +415,9 @@ impl Size for Vec<OverlayLink> {
     }
 }
 
-impl Size for Vec<End> {
+impl<P: OverlayPoint> Size<P> for Vec<End<P>> {
     #[inline]
-    fn size(&self, point: IntPoint, index: usize) -> usize {
+    fn size(&self, point: P, index: usize) -> usize {
         let mut i = index + 1;
         while i < self.len() && self[i].point == point {
             i += 1;
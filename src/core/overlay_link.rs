@@ -1,21 +1,22 @@
+use i_float::point::IntPoint;
 use crate::segm::segment::SegmentFill;
 use crate::id_point::IdPoint;
 
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct OverlayLink {
-    pub(crate) a: IdPoint,
-    pub(crate) b: IdPoint,
+pub(crate) struct OverlayLink<P = IntPoint> {
+    pub(crate) a: IdPoint<P>,
+    pub(crate) b: IdPoint<P>,
     pub(crate) fill: SegmentFill,
 }
 
-impl OverlayLink {
+impl<P: Copy + Ord> OverlayLink<P> {
     #[inline(always)]
-    pub(super) fn new(a: IdPoint, b: IdPoint, fill: SegmentFill) -> OverlayLink {
-        OverlayLink { a, b, fill }
+    pub(crate) fn new(a: IdPoint<P>, b: IdPoint<P>, fill: SegmentFill) -> Self {
+        Self { a, b, fill }
     }
 
     #[inline(always)]
-    pub(crate) fn other(&self, node_id: usize) -> IdPoint {
+    pub(crate) fn other(&self, node_id: usize) -> IdPoint<P> {
         if self.a.id == node_id { self.b } else { self.a }
     }
 
@@ -23,4 +24,13 @@ impl OverlayLink {
     pub(crate) fn is_direct(&self) -> bool {
         self.a.point < self.b.point
     }
+}
+
+impl OverlayLink {
+    /// The doubled signed area of the trapezoid between the link and the x axis.
+    #[inline(always)]
+    pub(crate) fn trapezoid_x2(&self) -> i128 {
+        let (a, b) = (self.a.point, self.b.point);
+        (b.x as i64 - a.x as i64) as i128 * (a.y as i64 + b.y as i64) as i128
+    }
 }
\ No newline at end of file
@@ -6,7 +6,7 @@ pub(crate) enum OverlayNode {
 
 impl OverlayNode {
     #[inline]
-    pub(super) fn new(indices: &[usize]) -> Self {
+    pub(crate) fn new(indices: &[usize]) -> Self {
         if indices.len() > 2 {
             Self::Cross(indices.to_vec())
         } else {
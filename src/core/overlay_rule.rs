@@ -1,4 +1,4 @@
-use crate::segm::segment::{BOTH_BOTTOM, BOTH_TOP, CLIP_TOP, NONE, SegmentFill, SUBJ_TOP};
+use crate::segm::segment::{BOTH_BOTTOM, BOTH_TOP, CLIP_BOTTOM, CLIP_TOP, NONE, SegmentFill, SUBJ_BOTTOM, SUBJ_TOP};
 
 /// Defines the types of overlay/boolean operations that can be applied to shapes. For a visual description, see [Overlay Rules](https://ishape-rust.github.io/iShape-js/overlay/overlay_rules/overlay_rules.html).
 /// - `Subject`: Processes the subject shape, useful for resolving self-intersections and degenerate cases within the subject itself.
@@ -36,4 +36,30 @@ impl OverlayRule {
             }
         }
     }
+
+    /// Tells whether a region covered by the subject and/or the clip is part of the result.
+    #[inline]
+    pub(crate) fn is_fill_side(&self, subj: bool, clip: bool) -> bool {
+        match self {
+            OverlayRule::Subject => subj,
+            OverlayRule::Clip => clip,
+            OverlayRule::Intersect => subj && clip,
+            OverlayRule::Union => subj || clip,
+            OverlayRule::Difference => subj && !clip,
+            OverlayRule::InverseDifference => !subj && clip,
+            OverlayRule::Xor => subj != clip,
+        }
+    }
+
+    /// Tells whether the top side of a link is part of the result. Unlike `is_fill_top`, it is valid for any link, not only for the boundary ones.
+    #[inline]
+    pub(crate) fn is_inside_top(&self, fill: SegmentFill) -> bool {
+        self.is_fill_side(fill & SUBJ_TOP == SUBJ_TOP, fill & CLIP_TOP == CLIP_TOP)
+    }
+
+    /// Tells whether the bottom side of a link is part of the result. It is valid for any link.
+    #[inline]
+    pub(crate) fn is_inside_bottom(&self, fill: SegmentFill) -> bool {
+        self.is_fill_side(fill & SUBJ_BOTTOM == SUBJ_BOTTOM, fill & CLIP_BOTTOM == CLIP_BOTTOM)
+    }
 }
\ No newline at end of file
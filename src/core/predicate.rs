@@ -0,0 +1,270 @@
+//! This module evaluates spatial relations between the subject and the clip shapes of an overlay graph.
+//! The relation is read from the fills of the links and from the nodes, so no result shapes are built.
+
+use std::fmt;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_node::OverlayNode;
+use crate::segm::segment::{CLIP_BOTTOM, CLIP_TOP, NONE, SegmentFill, SUBJ_BOTTOM, SUBJ_TOP};
+
+/// The dimension of an intersection of two point sets.
+/// - `Empty`: The sets do not intersect, `F` in the DE-9IM notation.
+/// - `Point`: The sets intersect only at isolated points, `0`.
+/// - `Line`: The sets intersect along lines, `1`.
+/// - `Area`: The sets share an area, `2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dimension {
+    Empty,
+    Point,
+    Line,
+    Area,
+}
+
+/// A part of the plane relative to a set of shapes.
+/// - `Interior`: Inside the shapes, not on their boundary.
+/// - `Boundary`: On the boundary of the shapes.
+/// - `Exterior`: Outside the shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+/// The DE-9IM intersection matrix of the subject and the clip shapes. Rows are the locations relative to the subject,
+/// columns are the locations relative to the clip, and every cell is the dimension of the intersection of these parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntersectionMatrix {
+    cells: [Dimension; 9],
+}
+
+/// Defines the spatial relations which can be checked between the subject and the clip shapes.
+/// - `Intersects`: The shapes have at least one common point.
+/// - `Disjoint`: The shapes have no common points.
+/// - `Touches`: The shapes have common points, but only on their boundaries.
+/// - `Within`: The subject lies inside the clip and their interiors intersect.
+/// - `Contains`: The clip lies inside the subject and their interiors intersect.
+/// - `CoveredBy`: Every point of the subject is a point of the clip.
+/// - `Covers`: Every point of the clip is a point of the subject.
+/// - `Equals`: The shapes cover the same area.
+/// - `Overlaps`: The shapes share an area, and each of them has an area outside the other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Predicate {
+    Intersects,
+    Disjoint,
+    Touches,
+    Within,
+    Contains,
+    CoveredBy,
+    Covers,
+    Equals,
+    Overlaps,
+}
+
+impl IntersectionMatrix {
+    // the largest dimensions which two areas can give, the exterior always intersects the exterior
+    const FULL: IntersectionMatrix = IntersectionMatrix {
+        cells: [
+            Dimension::Area, Dimension::Line, Dimension::Area,
+            Dimension::Line, Dimension::Line, Dimension::Line,
+            Dimension::Area, Dimension::Line, Dimension::Area,
+        ]
+    };
+
+    fn new() -> Self {
+        let mut matrix = Self { cells: [Dimension::Empty; 9] };
+        matrix.cells[8] = Dimension::Area;
+        matrix
+    }
+
+    /// Returns the dimension of the intersection of a part of the subject with a part of the clip.
+    /// - `subj`: The location relative to the subject.
+    /// - `clip`: The location relative to the clip.
+    #[inline]
+    pub fn get(&self, subj: Location, clip: Location) -> Dimension {
+        self.cells[Self::index(subj, clip)]
+    }
+
+    /// Checks the matrix against a DE-9IM pattern of nine characters, like `T*F**F***`.
+    /// `T` matches any non-empty intersection, `F` an empty one, `0`, `1` and `2` an exact dimension and `*` anything.
+    /// - Returns: `false` if the pattern does not match or is not a valid pattern.
+    pub fn matches(&self, pattern: &str) -> bool {
+        if pattern.len() != 9 {
+            return false;
+        }
+
+        pattern.chars().zip(self.cells.iter()).all(|(ch, &dim)| match ch {
+            'T' | 't' => dim != Dimension::Empty,
+            'F' | 'f' => dim == Dimension::Empty,
+            '0' => dim == Dimension::Point,
+            '1' => dim == Dimension::Line,
+            '2' => dim == Dimension::Area,
+            '*' => true,
+            _ => false,
+        })
+    }
+
+    /// Tells whether the relation described by the matrix satisfies a predicate.
+    pub fn is(&self, predicate: Predicate) -> bool {
+        match predicate {
+            Predicate::Intersects => !self.matches("FF*FF****"),
+            Predicate::Disjoint => self.matches("FF*FF****"),
+            Predicate::Touches => self.matches("FT*******") || self.matches("F**T*****") || self.matches("F***T****"),
+            Predicate::Within => self.matches("T*F**F***"),
+            Predicate::Contains => self.matches("T*****FF*"),
+            Predicate::CoveredBy => self.matches("T*F**F***") || self.matches("*TF**F***")
+                || self.matches("**FT*F***") || self.matches("**F*TF***"),
+            Predicate::Covers => self.matches("T*****FF*") || self.matches("*T****FF*")
+                || self.matches("***T**FF*") || self.matches("****T*FF*"),
+            Predicate::Equals => self.matches("T*F**FFF*"),
+            Predicate::Overlaps => self.matches("T*T***T**"),
+        }
+    }
+
+    #[inline(always)]
+    fn index(subj: Location, clip: Location) -> usize {
+        3 * subj as usize + clip as usize
+    }
+
+    #[inline(always)]
+    fn set(&mut self, subj: Location, clip: Location, dim: Dimension) {
+        let cell = &mut self.cells[Self::index(subj, clip)];
+        *cell = (*cell).max(dim);
+    }
+
+    #[inline(always)]
+    fn is_empty(&self, subj: Location, clip: Location) -> bool {
+        self.get(subj, clip) == Dimension::Empty
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for dim in self.cells.iter() {
+            let ch = match dim {
+                Dimension::Empty => 'F',
+                Dimension::Point => '0',
+                Dimension::Line => '1',
+                Dimension::Area => '2',
+            };
+            write!(f, "{}", ch)?;
+        }
+        Ok(())
+    }
+}
+
+impl Predicate {
+    /// Tells whether more intersections can not change the result anymore. Dimensions in the matrix only grow during the scan.
+    fn is_decided(&self, matrix: &IntersectionMatrix) -> bool {
+        use Location::*;
+        match self {
+            Predicate::Intersects | Predicate::Disjoint => {
+                !matrix.is_empty(Interior, Interior) || !matrix.is_empty(Interior, Boundary)
+                    || !matrix.is_empty(Boundary, Interior) || !matrix.is_empty(Boundary, Boundary)
+            }
+            Predicate::Touches => !matrix.is_empty(Interior, Interior),
+            Predicate::Within | Predicate::CoveredBy => {
+                !matrix.is_empty(Interior, Exterior) || !matrix.is_empty(Boundary, Exterior)
+            }
+            Predicate::Contains | Predicate::Covers => {
+                !matrix.is_empty(Exterior, Interior) || !matrix.is_empty(Exterior, Boundary)
+            }
+            Predicate::Equals => {
+                !matrix.is_empty(Interior, Exterior) || !matrix.is_empty(Boundary, Exterior)
+                    || !matrix.is_empty(Exterior, Interior) || !matrix.is_empty(Exterior, Boundary)
+            }
+            Predicate::Overlaps => {
+                !matrix.is_empty(Interior, Interior) && !matrix.is_empty(Interior, Exterior) && !matrix.is_empty(Exterior, Interior)
+            }
+        }
+    }
+}
+
+impl OverlayGraph {
+    /// Computes the DE-9IM intersection matrix of the subject and the clip shapes.
+    /// Shapes are taken after applying the fill rule of the graph, and the subject is the first geometry.
+    /// - Returns: `IntersectionMatrix` which can be printed as a DE-9IM string, like `212101212`.
+    pub fn relate(&self) -> IntersectionMatrix {
+        self.scan(|matrix| *matrix == IntersectionMatrix::FULL)
+    }
+
+    /// Tells whether the subject and the clip shapes satisfy a spatial predicate.
+    /// The scan stops as soon as the result is known, so it is usually faster than `relate`.
+    /// - `predicate`: The spatial relation to check.
+    pub fn satisfies(&self, predicate: Predicate) -> bool {
+        self.scan(|matrix| predicate.is_decided(matrix)).is(predicate)
+    }
+
+    fn scan<F: Fn(&IntersectionMatrix) -> bool>(&self, is_done: F) -> IntersectionMatrix {
+        let mut matrix = IntersectionMatrix::new();
+
+        // every link is a line between two areas
+        for link in self.links.iter() {
+            let fill = link.fill;
+            let subj_top = fill & SUBJ_TOP != NONE;
+            let subj_bottom = fill & SUBJ_BOTTOM != NONE;
+            let clip_top = fill & CLIP_TOP != NONE;
+            let clip_bottom = fill & CLIP_BOTTOM != NONE;
+
+            matrix.set(subj_top.into(), clip_top.into(), Dimension::Area);
+            matrix.set(subj_bottom.into(), clip_bottom.into(), Dimension::Area);
+            matrix.set(Location::subj(fill), Location::clip(fill), Dimension::Line);
+
+            if is_done(&matrix) {
+                return matrix;
+            }
+        }
+
+        // boundaries can also meet at single points
+        for node in self.nodes.iter() {
+            let indices: &[usize] = match node {
+                OverlayNode::Bridge(bridge) => bridge,
+                OverlayNode::Cross(indices) => indices,
+            };
+
+            let subj = Location::node(indices.iter().map(|&i| Location::subj(self.links[i].fill)));
+            let clip = Location::node(indices.iter().map(|&i| Location::clip(self.links[i].fill)));
+            matrix.set(subj, clip, Dimension::Point);
+
+            if is_done(&matrix) {
+                return matrix;
+            }
+        }
+
+        matrix
+    }
+}
+
+impl From<bool> for Location {
+    #[inline(always)]
+    fn from(is_inside: bool) -> Self {
+        if is_inside { Location::Interior } else { Location::Exterior }
+    }
+}
+
+impl Location {
+    #[inline(always)]
+    fn subj(fill: SegmentFill) -> Self {
+        Self::side(fill & SUBJ_TOP != NONE, fill & SUBJ_BOTTOM != NONE)
+    }
+
+    #[inline(always)]
+    fn clip(fill: SegmentFill) -> Self {
+        Self::side(fill & CLIP_TOP != NONE, fill & CLIP_BOTTOM != NONE)
+    }
+
+    #[inline(always)]
+    fn side(top: bool, bottom: bool) -> Self {
+        if top != bottom { Location::Boundary } else { top.into() }
+    }
+
+    // a node is on the boundary if any of its links is, otherwise all links have the same side
+    #[inline]
+    fn node<I: Iterator<Item = Location>>(mut locations: I) -> Self {
+        let first = locations.next().unwrap_or(Location::Exterior);
+        if first == Location::Boundary || locations.any(|location| location == Location::Boundary) {
+            Location::Boundary
+        } else {
+            first
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! This module answers point queries on an overlay graph: which shapes cover a point and whether it belongs to the result
+//! of an overlay rule, without extracting any shapes.
+
+use i_float::point::IntPoint;
+use i_float::triangle::Triangle;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_node::OverlayNode;
+use crate::core::overlay_rule::OverlayRule;
+use crate::fill::count_segment::CountSegment;
+use crate::fill::solver_list::ScanFillList;
+use crate::segm::segment::{CLIP_BOTH, CLIP_TOP, NONE, SegmentFill, SUBJ_BOTH, SUBJ_TOP};
+use crate::segm::x_segment::XSegment;
+
+/// The fill of the subject and clip shapes at a point.
+///
+/// The graph keeps only the fill of both sides of every link and not the winding numbers, so the fill is given after applying the fill rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointFill {
+    /// The point is inside the subject or on its boundary.
+    pub subj: bool,
+    /// The point is inside the clip or on its boundary.
+    pub clip: bool,
+    /// The point lies on a link of the graph, which is a boundary of the subject or of the clip.
+    pub is_boundary: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Location<T> {
+    // the point lies on links, `T` is the fold of the fills of all of them
+    Boundary(T),
+    // the fill of the region which contains the point
+    Inside(SegmentFill),
+}
+
+impl OverlayGraph {
+    /// Finds the fill of the subject and clip shapes at a point. A point on a boundary counts as inside, shapes are treated as closed.
+    /// - `point`: The queried point.
+    /// - Returns: `PointFill` with the membership of the point in the subject and in the clip.
+    ///
+    /// Note: Every call walks the links which start to the left of the point. For many points use `fill_at_points`, which sweeps the links once.
+    pub fn fill_at(&self, point: IntPoint) -> PointFill {
+        point_fill(self.locate(point, NONE, join_fill))
+    }
+
+    /// Tells whether a point belongs to the result of an overlay rule, the same shapes which `extract_shapes` would return. A point on the boundary of the result counts as inside.
+    /// - `point`: The queried point.
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    ///
+    /// Note: Every call walks the links which start to the left of the point. For many points use `contains_points`, which sweeps the links once.
+    pub fn contains(&self, point: IntPoint, overlay_rule: OverlayRule) -> bool {
+        is_contained(self.locate(point, false, |is_inside, fill| is_inside || is_boundary_of(overlay_rule, fill)), overlay_rule)
+    }
+
+    /// Finds the fill of the subject and clip shapes at every point, see `fill_at`.
+    /// - `points`: The queried points, in any order.
+    /// - Returns: `PointFill` for every point, in the order of `points`.
+    pub fn fill_at_points(&self, points: &[IntPoint]) -> Vec<PointFill> {
+        self.locate_all(points, NONE, join_fill).into_iter().map(point_fill).collect()
+    }
+
+    /// Tells whether every point belongs to the result of an overlay rule, see `contains`.
+    /// - `points`: The queried points, in any order.
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    /// - Returns: The membership of every point, in the order of `points`.
+    pub fn contains_points(&self, points: &[IntPoint], overlay_rule: OverlayRule) -> Vec<bool> {
+        self.locate_all(points, false, |is_inside, fill| is_inside || is_boundary_of(overlay_rule, fill))
+            .into_iter()
+            .map(|location| is_contained(location, overlay_rule))
+            .collect()
+    }
+
+    fn locate<T: Copy, F: Fn(T, SegmentFill) -> T>(&self, p: IntPoint, init: T, fold: F) -> Location<T> {
+        // links are sorted by `a`, only the links which start not to the right of the point can touch it or be under it
+        let end = self.links.partition_point(|link| link.a.point.x <= p.x);
+
+        let mut touched: Option<T> = None;
+        let mut nearest: Option<(XSegment, SegmentFill)> = None;
+
+        for link in self.links[..end].iter() {
+            let x_segment = XSegment { a: link.a.point, b: link.b.point };
+            if x_segment.b.x < p.x {
+                continue;
+            }
+
+            if x_segment.is_contain(p) {
+                touched = Some(fold(touched.unwrap_or(init), link.fill));
+                continue;
+            }
+
+            // like in the scan lists, take the links which cross the vertical line right after the point
+            if touched.is_some() || x_segment.b.x == p.x || !x_segment.is_under_point(p) {
+                continue;
+            }
+
+            let is_nearer = match &nearest {
+                Some((other, _)) => other.is_under_segment(&x_segment),
+                None => true,
+            };
+
+            if is_nearer {
+                nearest = Some((x_segment, link.fill));
+            }
+        }
+
+        match touched {
+            Some(value) => Location::Boundary(value),
+            None => Location::Inside(nearest.map_or(NONE, |(_, fill)| fill)),
+        }
+    }
+
+    /// Locates all points in one sweep along x. The links which cross the sweep line are kept in a scan list sorted by y,
+    /// the nodes and the vertical links are found separately, because the scan list only keeps the links which go on to the right.
+    fn locate_all<T: Copy, F: Fn(T, SegmentFill) -> T>(&self, points: &[IntPoint], init: T, fold: F) -> Vec<Location<T>> {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        order.sort_unstable_by_key(|&i| points[i]);
+
+        let mut result = vec![Location::Inside(NONE); points.len()];
+        let mut scan_list = ScanFillList::new(self.links.len());
+        // the vertical links at the x of the sweep line, sorted by y
+        let mut verticals: Vec<(i32, i32, SegmentFill)> = Vec::new();
+
+        let mut next_link = 0;
+        let mut x0 = None;
+
+        for i in order {
+            let p = points[i];
+            if x0 != Some(p.x) {
+                x0 = Some(p.x);
+                verticals.clear();
+                scan_list.clear(p.x);
+
+                while next_link < self.links.len() && self.links[next_link].a.point.x <= p.x {
+                    let link = &self.links[next_link];
+                    let x_segment = XSegment { a: link.a.point, b: link.b.point };
+                    if !x_segment.is_not_vertical() {
+                        if x_segment.a.x == p.x {
+                            verticals.push((x_segment.a.y, x_segment.b.y, link.fill));
+                        }
+                    } else if x_segment.b.x > p.x {
+                        scan_list.insert(CountSegment { count: link.fill, x_segment });
+                    }
+                    next_link += 1;
+                }
+            }
+
+            if let Some(node_id) = self.find_node(p) {
+                let indices: &[usize] = match self.node(node_id) {
+                    OverlayNode::Bridge(bridge) => bridge,
+                    OverlayNode::Cross(indices) => indices,
+                };
+                let value = indices.iter().fold(init, |value, &index| fold(value, self.link(index).fill));
+                result[i] = Location::Boundary(value);
+                continue;
+            }
+
+            // the vertical links do not overlap, so only the last one which starts below the point can go through it
+            let vertical_index = verticals.partition_point(|&(y0, _, _)| y0 < p.y);
+            if let Some(&(_, y1, fill)) = vertical_index.checked_sub(1).map(|j| &verticals[j]) {
+                if y1 > p.y {
+                    result[i] = Location::Boundary(fold(init, fill));
+                    continue;
+                }
+            }
+
+            result[i] = match scan_list.find_through_or_under(p) {
+                Some((segment, true)) => Location::Boundary(fold(init, segment.count)),
+                Some((segment, false)) => Location::Inside(segment.count),
+                None => Location::Inside(NONE),
+            };
+        }
+
+        result
+    }
+}
+
+#[inline(always)]
+fn join_fill(fill: SegmentFill, other: SegmentFill) -> SegmentFill {
+    fill | other
+}
+
+#[inline(always)]
+fn is_boundary_of(overlay_rule: OverlayRule, fill: SegmentFill) -> bool {
+    overlay_rule.is_inside_top(fill) || overlay_rule.is_inside_bottom(fill)
+}
+
+#[inline]
+fn point_fill(location: Location<SegmentFill>) -> PointFill {
+    match location {
+        Location::Boundary(fill) => PointFill { subj: fill & SUBJ_BOTH != NONE, clip: fill & CLIP_BOTH != NONE, is_boundary: true },
+        Location::Inside(fill) => PointFill { subj: fill & SUBJ_TOP != NONE, clip: fill & CLIP_TOP != NONE, is_boundary: false },
+    }
+}
+
+#[inline]
+fn is_contained(location: Location<bool>, overlay_rule: OverlayRule) -> bool {
+    match location {
+        Location::Boundary(is_inside) => is_inside,
+        Location::Inside(fill) => overlay_rule.is_inside_top(fill),
+    }
+}
+
+impl XSegment {
+    #[inline]
+    fn is_contain(&self, p: IntPoint) -> bool {
+        let range = self.y_range();
+        self.a.x <= p.x && p.x <= self.b.x && range.min <= p.y && p.y <= range.max
+            && Triangle::area_two_point(self.a, p, self.b) == 0
+    }
+}
@@ -0,0 +1,102 @@
+//! The range of integer coordinates which is safe for `Overlay`. Cross points of edges are calculated
+//! with `i64` products of coordinate differences, so every `abs(x)` and `abs(y)` must stay below `2^30`.
+//!
+//! Input out of this range can be checked with `Overlay::validate_range` or brought into it with `Overlay::fit_range`.
+//! Paths are simplified when they are added, so even for `fit_range` the width and the height of every path must fit in `i32`.
+
+use i_float::point::IntPoint;
+use i_shape::int::shape::IntShapes;
+
+/// The largest coordinate value accepted by `Overlay`.
+pub const MAX_COORD: i32 = (1 << 30) - 1;
+
+/// The smallest coordinate value accepted by `Overlay`.
+pub const MIN_COORD: i32 = -MAX_COORD;
+
+/// Tells whether both coordinates of the point are in the range `MIN_COORD..=MAX_COORD`.
+#[inline(always)]
+pub fn is_in_range(p: IntPoint) -> bool {
+    MIN_COORD <= p.x && p.x <= MAX_COORD && MIN_COORD <= p.y && p.y <= MAX_COORD
+}
+
+/// The transform which `Overlay::fit_range` applies to the input: every point is moved by `-offset`
+/// and then divided by `2^shift` with rounding. Use `restore` to map result points back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeTransform {
+    pub offset: IntPoint,
+    pub shift: u32,
+}
+
+impl RangeTransform {
+    /// The transform which leaves points as they are.
+    pub const IDENTITY: Self = Self { offset: IntPoint { x: 0, y: 0 }, shift: 0 };
+
+    /// Creates the transform which maps the rectangle into the safe range.
+    /// If the rectangle is already in the range, `IDENTITY` is returned.
+    pub(crate) fn with_bounds(min: IntPoint, max: IntPoint) -> Self {
+        if is_in_range(min) && is_in_range(max) {
+            return Self::IDENTITY;
+        }
+
+        let (min_x, max_x) = (min.x as i64, max.x as i64);
+        let (min_y, max_y) = (min.y as i64, max.y as i64);
+
+        let cx = (min_x + max_x) >> 1;
+        let cy = (min_y + max_y) >> 1;
+
+        let radius = (max_x - cx).max(cx - min_x).max(max_y - cy).max(cy - min_y);
+
+        let mut shift = 0;
+        while Self::round_shift(radius, shift) > MAX_COORD as i64 {
+            shift += 1;
+        }
+
+        Self { offset: IntPoint::new(cx as i32, cy as i32), shift }
+    }
+
+    #[inline(always)]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Maps an input point into the safe range.
+    #[inline]
+    pub fn apply(&self, p: IntPoint) -> IntPoint {
+        let x = Self::round_shift(p.x as i64 - self.offset.x as i64, self.shift);
+        let y = Self::round_shift(p.y as i64 - self.offset.y as i64, self.shift);
+        IntPoint::new(x as i32, y as i32)
+    }
+
+    /// Maps a result point back to the input coordinates. Values out of `i32` are clamped.
+    #[inline]
+    pub fn restore(&self, p: IntPoint) -> IntPoint {
+        let x = ((p.x as i64) << self.shift) + self.offset.x as i64;
+        let y = ((p.y as i64) << self.shift) + self.offset.y as i64;
+        IntPoint::new(Self::clamp(x), Self::clamp(y))
+    }
+
+    /// Maps all points of the result shapes back to the input coordinates.
+    pub fn restore_shapes(&self, shapes: &mut IntShapes) {
+        if self.is_identity() {
+            return;
+        }
+
+        for p in shapes.iter_mut().flatten().flatten() {
+            *p = self.restore(*p);
+        }
+    }
+
+    #[inline(always)]
+    fn round_shift(value: i64, shift: u32) -> i64 {
+        if shift == 0 {
+            value
+        } else {
+            (value + (1 << (shift - 1))) >> shift
+        }
+    }
+
+    #[inline(always)]
+    fn clamp(value: i64) -> i32 {
+        value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+}
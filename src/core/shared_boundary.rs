@@ -0,0 +1,37 @@
+//! This module extracts the parts of the boundaries which the subject and the clip shapes have in common,
+//! like the common border of two neighbour parcels.
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::line::extract::join_lines;
+use crate::segm::segment::{CLIP_BOTH, CLIP_BOTTOM, CLIP_TOP, SUBJ_BOTH, SUBJ_BOTTOM, SUBJ_TOP};
+
+impl OverlayGraph {
+    /// Extracts the edges where a boundary of the subject coincides with a boundary of the clip, joined into maximal polylines.
+    /// Shapes are taken after applying the fill rule, so edges inside the subject or inside the clip are not boundaries.
+    /// - Returns: A vector of open paths. Every polyline keeps the subject on its right side and is split where the subject changes its side.
+    ///
+    /// Polylines are joined where exactly two common edges meet. A closed chain is returned with its first point repeated at the end.
+    pub fn extract_shared_boundaries(&self) -> Vec<IntPath> {
+        let edges: Vec<(IntPoint, IntPoint)> = self.links.iter().filter_map(|link| {
+            let subj = link.fill & SUBJ_BOTH;
+            let clip = link.fill & CLIP_BOTH;
+            let is_subj_boundary = subj == SUBJ_TOP || subj == SUBJ_BOTTOM;
+            let is_clip_boundary = clip == CLIP_TOP || clip == CLIP_BOTTOM;
+
+            if !is_subj_boundary || !is_clip_boundary {
+                return None;
+            }
+
+            // moving from `a` to `b` keeps the bottom side on the right
+            if subj == SUBJ_BOTTOM {
+                Some((link.a.point, link.b.point))
+            } else {
+                Some((link.b.point, link.a.point))
+            }
+        }).collect();
+
+        join_lines(&edges)
+    }
+}
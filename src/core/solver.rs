@@ -23,14 +23,19 @@ pub enum Precision {
     Auto,
 }
 
+/// Options for the parallel parts of the pipeline, they are used only with the `allow_multithreading` feature.
+/// - `par_sort_min_size`: The minimum number of segments to sort them in parallel.
+/// - `par_fill_min_size`: The minimum number of segments to fill them by parallel bands. The fills are the same as with a single sweep,
+///   but every band has to collect the segments crossing its border, so it pays off only for large graphs.
 #[derive(Clone, Copy)]
 pub struct MultithreadOptions {
     pub par_sort_min_size: usize,
+    pub par_fill_min_size: usize,
 }
 
 impl Default for MultithreadOptions {
     fn default() -> Self {
-        Self { par_sort_min_size: 32768 }
+        Self { par_sort_min_size: 32768, par_fill_min_size: 262144 }
     }
 }
 
@@ -48,14 +53,14 @@ impl Default for Solver {
 }
 
 impl Solver {
-    pub const LIST: Self = Self { strategy: List, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768 }) };
-    pub const TREE: Self = Self { strategy: Tree, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768 }) };
-    pub const AUTO: Self = Self { strategy: Auto, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768 }) };
+    pub const LIST: Self = Self { strategy: List, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768, par_fill_min_size: 262144 }) };
+    pub const TREE: Self = Self { strategy: Tree, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768, par_fill_min_size: 262144 }) };
+    pub const AUTO: Self = Self { strategy: Auto, precision: Precision::Auto, multithreading: Some(MultithreadOptions { par_sort_min_size: 32768, par_fill_min_size: 262144 }) };
 
     const MAX_SPLIT_LIST_COUNT: usize = 4_000;
     const MAX_FILL_LIST_COUNT: usize = 8_000;
 
-    pub(crate) fn is_list_split(&self, segments: &[Segment]) -> bool {
+    pub(crate) fn is_list_split<C, P>(&self, segments: &[Segment<C, P>]) -> bool {
         match self.strategy {
             List => { true }
             Tree => { false }
@@ -65,7 +70,7 @@ impl Solver {
         }
     }
 
-    pub(crate) fn is_list_fill(&self, segments: &[Segment]) -> bool {
+    pub(crate) fn is_list_fill<C, P>(&self, segments: &[Segment<C, P>]) -> bool {
         match self.strategy {
             List => { true }
             Tree => { false }
@@ -0,0 +1,239 @@
+//! This module provides a union of many shapes at once. Shapes whose bounding rects touch are joined into clusters,
+//! the clusters are split spatially into a tree of small groups, every group is united by its own overlay
+//! and the united shapes are merged up the tree. Edges of different clusters can not cross and a cluster can not lie
+//! inside another one, so the shapes of two groups are merged by collecting them.
+//! A cluster is never split, so its shapes are united by one overlay like by a single union.
+
+use i_float::point::IntPoint;
+use i_float::rect::IntRect;
+use i_shape::int::shape::{IntShape, IntShapes, PointsCount};
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::{clean_if_needed, BuildEdges, ShapeType};
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::segm::segment::{Segment, ShapeEdgesMerge};
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+// groups with fewer points are united by a single overlay
+const LEAF_POINTS_COUNT: usize = 4096;
+
+pub trait UnionAll {
+    /// Unites all shapes with the `NonZero` fill rule. It is much faster and uses less memory than a single overlay for a large number of shapes,
+    /// especially if most of them are far from each other, like building footprints.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// Note: The result is the same as one `NonZero` union, also for overlapping shapes which cancel each other's winding.
+    /// The speedup comes from shapes which are apart from each other: they are united in small groups and the results are merged.
+    /// Shapes which overlap or touch, directly or through other shapes, are always united together by one overlay.
+    #[inline(always)]
+    fn union_all(&self) -> IntShapes {
+        self.union_all_with_solver(Solver::AUTO)
+    }
+
+    /// Same as `union_all`, but with a specific solver. If multithreading is allowed by the solver, the groups are united in parallel.
+    /// - `solver`: Type of solver to use.
+    fn union_all_with_solver(&self, solver: Solver) -> IntShapes;
+}
+
+impl UnionAll for [IntShape] {
+    fn union_all_with_solver(&self, solver: Solver) -> IntShapes {
+        let items: Vec<Item> = self.iter().filter_map(Item::new).collect();
+        if items.is_empty() {
+            return Vec::new();
+        }
+
+        let mut clusters = Cluster::with_items(&items);
+
+        union_clusters(&mut clusters, solver)
+    }
+}
+
+struct Item<'a> {
+    rect: IntRect,
+    shape: &'a IntShape,
+}
+
+impl<'a> Item<'a> {
+    fn new(shape: &'a IntShape) -> Option<Self> {
+        let rect = shape.iter().fold(None, |rect, path| IntRect::with_optional_rects(rect, IntRect::with_points(path)))?;
+        Some(Self { rect, shape })
+    }
+}
+
+/// Shapes whose bounding rects touch each other, directly or through other shapes of the cluster.
+/// The rects of different clusters do not touch, so their edges can not cross.
+struct Cluster<'a> {
+    center: IntPoint,
+    shapes: Vec<&'a IntShape>,
+    points_count: usize,
+}
+
+impl<'a> Cluster<'a> {
+    fn with_items(items: &[Item<'a>]) -> Vec<Self> {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        order.sort_unstable_by_key(|&i| items[i].rect.min_x);
+
+        // join the items with touching rects, sweeping along x
+        let mut parents: Vec<usize> = (0..items.len()).collect();
+        let mut active: Vec<usize> = Vec::new();
+        for &i in order.iter() {
+            let rect = &items[i].rect;
+            active.retain(|&j| items[j].rect.max_x >= rect.min_x);
+            for &j in active.iter() {
+                if items[j].rect.is_intersect_border_include(rect) {
+                    let (root_i, root_j) = (find_root(&mut parents, i), find_root(&mut parents, j));
+                    parents[root_i] = root_j;
+                }
+            }
+            active.push(i);
+        }
+
+        let mut cluster_indices = vec![usize::MAX; items.len()];
+        let mut rects: Vec<IntRect> = Vec::new();
+        let mut clusters: Vec<Self> = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            let root = find_root(&mut parents, i);
+            if cluster_indices[root] == usize::MAX {
+                cluster_indices[root] = clusters.len();
+                rects.push(item.rect.clone());
+                clusters.push(Self { center: IntPoint::ZERO, shapes: Vec::new(), points_count: 0 });
+            }
+
+            let index = cluster_indices[root];
+            rects[index] = IntRect::with_rects(&rects[index], &item.rect);
+            let cluster = &mut clusters[index];
+            cluster.shapes.push(item.shape);
+            cluster.points_count += item.shape.points_count();
+        }
+
+        for (cluster, rect) in clusters.iter_mut().zip(rects) {
+            let x = (rect.min_x as i64 + rect.max_x as i64) >> 1;
+            let y = (rect.min_y as i64 + rect.max_y as i64) >> 1;
+            cluster.center = IntPoint::new(x as i32, y as i32);
+        }
+
+        clusters
+    }
+}
+
+fn find_root(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    // compress the path
+    let mut i = index;
+    while parents[i] != root {
+        let next = parents[i];
+        parents[i] = root;
+        i = next;
+    }
+
+    root
+}
+
+/// Unites the shapes of all clusters. Small groups of clusters are united by one overlay, larger ones are split in two halves
+/// which are united separately and merged.
+fn union_clusters(clusters: &mut [Cluster], solver: Solver) -> IntShapes {
+    let points_count: usize = clusters.iter().map(|cluster| cluster.points_count).sum();
+    if clusters.len() == 1 || points_count <= LEAF_POINTS_COUNT {
+        let mut segments = Vec::with_capacity(points_count);
+        for shape in clusters.iter().flat_map(|cluster| cluster.shapes.iter()) {
+            for path in shape.iter() {
+                segments.append_edges(path, ShapeType::Subject);
+            }
+        }
+
+        return union(segments, solver);
+    }
+
+    // split by the median center along the longer side
+    let first = clusters[0].center;
+    let centers = clusters.iter().fold(IntRect::new(first.x, first.x, first.y, first.y), |mut rect, cluster| {
+        rect.add_point(&cluster.center);
+        rect
+    });
+
+    let middle = clusters.len() >> 1;
+    if centers.max_x as i64 - centers.min_x as i64 >= centers.max_y as i64 - centers.min_y as i64 {
+        clusters.select_nth_unstable_by_key(middle, |cluster| cluster.center.x);
+    } else {
+        clusters.select_nth_unstable_by_key(middle, |cluster| cluster.center.y);
+    }
+
+    let (left, right) = clusters.split_at_mut(middle);
+    let (shapes_0, shapes_1) = join(
+        &solver,
+        || union_clusters(left, solver),
+        || union_clusters(right, solver),
+    );
+
+    // the clusters of both halves do not touch each other, so their united shapes do not overlap
+    merge(shapes_0, shapes_1)
+}
+
+/// Merges the shapes of two groups in the order of one overlay, which extracts shapes by the smallest point of their outer boundaries.
+/// The shapes of different groups never share this point, so the order is the same as for a union of both groups.
+fn merge(shapes_0: IntShapes, shapes_1: IntShapes) -> IntShapes {
+    if shapes_0.is_empty() {
+        return shapes_1;
+    } else if shapes_1.is_empty() {
+        return shapes_0;
+    }
+
+    let mut shapes = Vec::with_capacity(shapes_0.len() + shapes_1.len());
+    let mut iter_0 = shapes_0.into_iter().map(|shape| (min_point(&shape), shape)).peekable();
+    let mut iter_1 = shapes_1.into_iter().map(|shape| (min_point(&shape), shape)).peekable();
+    while let (Some((point_0, _)), Some((point_1, _))) = (iter_0.peek(), iter_1.peek()) {
+        let next = if point_0 <= point_1 { iter_0.next() } else { iter_1.next() };
+        shapes.extend(next.map(|(_, shape)| shape));
+    }
+    shapes.extend(iter_0.map(|(_, shape)| shape));
+    shapes.extend(iter_1.map(|(_, shape)| shape));
+
+    shapes
+}
+
+#[inline]
+fn min_point(shape: &IntShape) -> Option<IntPoint> {
+    shape.first().and_then(|path| path.iter().min().copied())
+}
+
+fn union(mut segments: Vec<Segment>, solver: Solver) -> IntShapes {
+    if segments.is_empty() {
+        return Vec::new();
+    }
+
+    segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+    segments.merge_if_needed();
+
+    SplitSolver::new(solver).split(&mut segments);
+
+    let mut fills = FillSolver::fill(&FillRule::NonZero, &solver, &segments);
+    clean_if_needed(&mut segments, &mut fills);
+
+    OverlayGraph::new(solver, (segments, fills)).extract_shapes(OverlayRule::Subject)
+}
+
+#[inline]
+fn join<A, B, RA, RB>(_solver: &Solver, a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "allow_multithreading")]
+    {
+        if _solver.multithreading.is_some() {
+            return rayon::join(a, b);
+        }
+    }
+
+    (a(), b())
+}
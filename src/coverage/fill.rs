@@ -0,0 +1,23 @@
+use crate::fill::solver::FillStrategy;
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
+
+/// The coverage level on both sides of a segment, which is the absolute subject winding number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CoverageFill {
+    pub(crate) top: u32,
+    pub(crate) bottom: u32,
+}
+
+/// Fill strategy which keeps the raw subject winding instead of applying a fill rule. Clip counts are ignored.
+pub(crate) struct CoverageStrategy;
+
+impl FillStrategy<ShapeCount, CoverageFill> for CoverageStrategy {
+    #[inline(always)]
+    fn add_and_fill(&self, this: &ShapeCount, bot: &ShapeCount) -> (ShapeCount, CoverageFill) {
+        let top = bot.add(*this);
+        let fill = CoverageFill { top: top.subj.unsigned_abs(), bottom: bot.subj.unsigned_abs() };
+
+        (top, fill)
+    }
+}
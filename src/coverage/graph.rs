@@ -0,0 +1,148 @@
+//! This module defines a graph which keeps the raw subject winding on both sides of every link.
+//! It is used to extract regions by the number of subject paths covering them.
+
+use i_shape::int::shape::IntShapes;
+
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::solver::Solver;
+use crate::coverage::fill::{CoverageFill, CoverageStrategy};
+use crate::fill::solver::FillSolver;
+use crate::id_point::IdPoint;
+use crate::segm::segment::{NONE, Segment, ShapeEdgesMerge};
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+/// An `OverlayGraph` which knows the coverage level on both sides of every link.
+///
+/// The coverage level of a region is the absolute value of its subject winding number. For subject paths with the same orientation (and holes with the opposite one) it is the number of paths covering the region. Clip paths are ignored.
+///
+/// Create it with `Overlay::into_coverage_graph`.
+pub struct CoverageOverlayGraph {
+    pub(crate) graph: OverlayGraph,
+    fills: Vec<CoverageFill>,
+    max_level: u32,
+}
+
+impl CoverageOverlayGraph {
+    pub(crate) fn build(solver: Solver, segments: Vec<Segment>) -> Self {
+        if segments.is_empty() {
+            return Self { graph: OverlayGraph::with_links(solver, Vec::new()), fills: Vec::new(), max_level: 0 };
+        }
+
+        let mut segments = segments;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let all_fills: Vec<CoverageFill> = FillSolver::fill(&CoverageStrategy, &solver, &segments);
+
+        let mut links = Vec::with_capacity(segments.len());
+        let mut fills = Vec::with_capacity(segments.len());
+        let mut max_level = 0;
+
+        for (segment, fill) in segments.into_iter().zip(all_fills) {
+            // a segment between regions of the same level never bounds a result
+            if fill.top == fill.bottom {
+                continue;
+            }
+
+            max_level = max_level.max(fill.top).max(fill.bottom);
+            fills.push(fill);
+            links.push(OverlayLink::new(
+                IdPoint::new(0, segment.x_segment.a),
+                IdPoint::new(0, segment.x_segment.b),
+                NONE,
+            ));
+        }
+
+        let graph = OverlayGraph::with_links(solver, links);
+
+        Self { graph, fills, max_level }
+    }
+
+    /// The highest coverage level of any region.
+    #[inline(always)]
+    pub fn max_level(&self) -> usize {
+        self.max_level as usize
+    }
+
+    /// Extracts shapes from the graph for the regions whose coverage level is accepted by a predicate.
+    /// - `predicate`: Receives the coverage level of a region and returns `true` if the region is part of the result.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// For example, `|level| level >= 2` selects the regions covered by at least two paths and `|level| level == 2` by exactly two.
+    /// The predicate must return `false` for zero, otherwise the result is unbounded.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes<P>(&self, predicate: P) -> IntShapes
+    where
+        P: Fn(usize) -> bool,
+    {
+        self.extract_shapes_min_area(predicate, 0)
+    }
+
+    /// Extracts shapes from the graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes.
+    /// - `predicate`: Receives the coverage level of a region and returns `true` if the region is part of the result.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_min_area<P>(&self, predicate: P, min_area: i64) -> IntShapes
+    where
+        P: Fn(usize) -> bool,
+    {
+        let visited = self.fills.iter()
+            .map(|fill| predicate(fill.top as usize) == predicate(fill.bottom as usize))
+            .collect();
+
+        self.graph.extract_shapes_visited(visited, |link_index| predicate(self.fills[link_index].top as usize), min_area)
+    }
+
+    /// Extracts the regions of every coverage level at once.
+    /// - Returns: A vector of `max_level()` items, where the item at index `k - 1` contains the shapes covered exactly `k` times.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_levels(&self) -> Vec<IntShapes> {
+        self.extract_levels_min_area(0)
+    }
+
+    /// Extracts the regions of every coverage level at once similar to `extract_levels`, but with an additional constraint on the minimum area of the shapes.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_levels_min_area(&self, min_area: i64) -> Vec<IntShapes> {
+        let mut level_links = vec![Vec::new(); self.max_level as usize];
+        for (link_index, fill) in self.fills.iter().enumerate() {
+            if fill.top > 0 {
+                level_links[fill.top as usize - 1].push(link_index);
+            }
+            if fill.bottom > 0 {
+                level_links[fill.bottom as usize - 1].push(link_index);
+            }
+        }
+
+        let mut visited = vec![true; self.fills.len()];
+
+        level_links.iter().enumerate().map(|(index, link_indices)| {
+            let level = index as u32 + 1;
+            for &link_index in link_indices.iter() {
+                visited[link_index] = false;
+            }
+
+            self.graph.extract_shapes_from(
+                link_indices.iter().copied(),
+                &mut visited,
+                |link_index| self.fills[link_index].top == level,
+                min_area,
+            )
+        }).collect()
+    }
+}
@@ -3,10 +3,19 @@
 //! based on the overlay rule applied.
 
 use i_float::f32_adapter::F32PointAdapter;
-use i_shape::f32::adapter::ShapesToFloat;
-use i_shape::f32::shape::F32Shapes;
+use i_float::f32_point::F32Point;
+use i_float::point::IntPoint;
+use i_shape::f32::adapter::{ShapeToFloat, ShapesToFloat};
+use i_shape::f32::shape::{F32Path, F32Shapes};
+use crate::core::error::OverlayError;
+use crate::core::extract::VertexTouch;
+use crate::core::metrics::AreaMetrics;
 use crate::core::overlay_graph::OverlayGraph;
 use crate::core::overlay_rule::OverlayRule;
+use crate::core::predicate::{IntersectionMatrix, Predicate};
+use crate::core::query::PointFill;
+use crate::line::extract::LinePosition;
+use crate::line::slice::Slices;
 
 /// The `FloatOverlayGraph` struct represents an overlay graph with floating point precision,
 /// providing methods to extract geometric shapes from the graph after applying boolean operations.
@@ -47,11 +56,48 @@ impl F32OverlayGraph {
     /// - Each path `Vec<F32Point>` is a sequence of points, forming a closed path.
     ///
     /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
     #[inline(always)]
     pub fn extract_shapes(&self, overlay_rule: OverlayRule) -> F32Shapes {
         self.extract_shapes_min_area(overlay_rule, 0.0)
     }
 
+    /// Extracts shapes like `extract_shapes`, but resolves contours which touch at a single vertex as requested, see `OverlayGraph::extract_shapes_with_touch`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - `vertex_touch`: How to extract contours which touch at a vertex, `VertexTouch::Split` gives strictly simple paths.
+    ///
+    /// # Returns
+    /// A vector of `F32Shape`, representing the geometric result of the applied overlay rule.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
+    #[inline]
+    pub fn extract_shapes_with_touch(&self, overlay_rule: OverlayRule, vertex_touch: VertexTouch) -> F32Shapes {
+        self.extract_shapes_with_touch_min_area(overlay_rule, vertex_touch, 0.0)
+    }
+
+    /// Extracts shapes like `extract_shapes_with_touch`, but with an additional constraint on the minimum area of the shapes, see `OverlayGraph::extract_shapes_with_touch_min_area`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `vertex_touch`: How to extract contours which touch at a vertex, `VertexTouch::Split` gives strictly simple paths.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Returns
+    /// A vector of `F32Shape` that meet the specified area criteria.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
+    pub fn extract_shapes_with_touch_min_area(&self, overlay_rule: OverlayRule, vertex_touch: VertexTouch, min_area: f32) -> F32Shapes {
+        let sqr_scale = self.adapter.dir_scale * self.adapter.dir_scale;
+        let area = (sqr_scale * min_area) as i64;
+        self.graph.extract_shapes_with_touch_min_area(overlay_rule, vertex_touch, area).to_float(&self.adapter)
+    }
+
     /// Extracts shapes from the overlay graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes.
     /// This is useful for filtering out shapes that do not meet a certain size threshold, which can be beneficial for eliminating artifacts or noise from the output.
     ///
@@ -69,6 +115,9 @@ impl F32OverlayGraph {
     /// - Each path `Vec<F32Point>` is a sequence of points, forming a closed path.
     ///
     /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
     #[inline]
     pub fn extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: f32) -> F32Shapes {
         let sqr_scale = self.adapter.dir_scale * self.adapter.dir_scale;
@@ -77,4 +126,223 @@ impl F32OverlayGraph {
 
         shapes.to_float(&self.adapter)
     }
-}
\ No newline at end of file
+
+    /// Same as `extract_shapes`, but returns an error instead of panicking if a contour can not be traced, see `OverlayGraph::try_extract_shapes`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    ///
+    /// # Returns
+    /// A vector of `F32Shape` or `OverlayError::TopologyFailure` if the graph is inconsistent.
+    #[inline(always)]
+    pub fn try_extract_shapes(&self, overlay_rule: OverlayRule) -> Result<F32Shapes, OverlayError> {
+        self.try_extract_shapes_min_area(overlay_rule, 0.0)
+    }
+
+    /// Same as `extract_shapes_min_area`, but returns an error instead of panicking if a contour can not be traced.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Returns
+    /// A vector of `F32Shape` or `OverlayError::TopologyFailure` if the graph is inconsistent.
+    #[inline]
+    pub fn try_extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: f32) -> Result<F32Shapes, OverlayError> {
+        let sqr_scale = self.adapter.dir_scale * self.adapter.dir_scale;
+        let area = (sqr_scale * min_area) as i64;
+        let shapes = self.graph.try_extract_shapes_min_area(overlay_rule, area)?;
+
+        Ok(shapes.to_float(&self.adapter))
+    }
+
+    /// Extracts shapes for several overlay rules at once, see `OverlayGraph::extract_shapes_multi`.
+    ///
+    /// # Parameters
+    /// - `overlay_rules`: The boolean operation rules to apply.
+    ///
+    /// # Returns
+    /// A vector with one `F32Shapes` per rule, in the same order as `overlay_rules`.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `OverlayGraph::extract_shapes_multi`.
+    #[inline(always)]
+    pub fn extract_shapes_multi(&self, overlay_rules: &[OverlayRule]) -> Vec<F32Shapes> {
+        self.extract_shapes_multi_min_area(overlay_rules, 0.0)
+    }
+
+    /// Extracts shapes for several overlay rules at once similar to `extract_shapes_multi`, but with an additional constraint on the minimum area of the shapes.
+    ///
+    /// # Parameters
+    /// - `overlay_rules`: The boolean operation rules to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Returns
+    /// A vector with one `F32Shapes` per rule, in the same order as `overlay_rules`.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `OverlayGraph::extract_shapes_multi`.
+    #[inline]
+    pub fn extract_shapes_multi_min_area(&self, overlay_rules: &[OverlayRule], min_area: f32) -> Vec<F32Shapes> {
+        let sqr_scale = self.adapter.dir_scale * self.adapter.dir_scale;
+        let area = (sqr_scale * min_area) as i64;
+        self.graph.extract_shapes_multi_min_area(overlay_rules, area).iter()
+            .map(|shapes| shapes.to_float(&self.adapter))
+            .collect()
+    }
+
+    /// Extracts the pieces of the polylines added with `F32Overlay::add_polyline` which have the given position relative to the result of an overlay rule, see `OverlayGraph::extract_lines`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule which defines the area.
+    /// - `position`: Which pieces to extract: inside, outside or on the boundary of the area.
+    ///
+    /// # Returns
+    /// A vector of open `F32Path`.
+    #[inline]
+    pub fn extract_lines(&self, overlay_rule: OverlayRule, position: LinePosition) -> Vec<F32Path> {
+        self.graph.extract_lines(overlay_rule, position).to_float(&self.adapter)
+    }
+
+    /// Extracts the edges where a boundary of the subject coincides with a boundary of the clip, see `OverlayGraph::extract_shared_boundaries`.
+    ///
+    /// # Returns
+    /// A vector of open `F32Path`, every polyline keeps the subject on its right side.
+    #[inline]
+    pub fn extract_shared_boundaries(&self) -> Vec<F32Path> {
+        self.graph.extract_shared_boundaries().to_float(&self.adapter)
+    }
+
+    /// Cuts the result of an overlay rule by the polylines added with `F32Overlay::add_polyline`, see `OverlayGraph::extract_slices`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule which defines the area to cut.
+    ///
+    /// # Returns
+    /// `Slices` with the `F32Shapes` on the left and on the right side of the polylines.
+    ///
+    /// # Panics
+    /// If a piece can not be traced, see `try_extract_slices`.
+    #[inline]
+    pub fn extract_slices(&self, overlay_rule: OverlayRule) -> Slices<F32Shapes> {
+        let slices = self.graph.extract_slices(overlay_rule);
+        Slices {
+            left: slices.left.to_float(&self.adapter),
+            right: slices.right.to_float(&self.adapter),
+            rest: slices.rest.to_float(&self.adapter),
+        }
+    }
+
+    /// Same as `extract_slices`, but returns an error instead of panicking if a piece can not be traced, see `OverlayGraph::try_extract_slices`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule which defines the area to cut.
+    ///
+    /// # Returns
+    /// `Slices` with the `F32Shapes` or `OverlayError::TopologyFailure` if the graph is inconsistent.
+    #[inline]
+    pub fn try_extract_slices(&self, overlay_rule: OverlayRule) -> Result<Slices<F32Shapes>, OverlayError> {
+        let slices = self.graph.try_extract_slices(overlay_rule)?;
+        Ok(Slices {
+            left: slices.left.to_float(&self.adapter),
+            right: slices.right.to_float(&self.adapter),
+            rest: slices.rest.to_float(&self.adapter),
+        })
+    }
+
+    /// Splits the graph into independent clusters, see `OverlayGraph::components`.
+    ///
+    /// # Returns
+    /// A vector of `F32OverlayGraph` with the same adapter, one for every cluster.
+    pub fn components(&self) -> Vec<F32OverlayGraph> {
+        self.graph.components().into_iter().map(|graph| {
+            let adapter = F32PointAdapter {
+                dir_scale: self.adapter.dir_scale,
+                inv_scale: self.adapter.inv_scale,
+                offset: self.adapter.offset,
+            };
+            F32OverlayGraph::new(graph, adapter)
+        }).collect()
+    }
+
+    /// Finds the fill of the subject and clip shapes at a point, see `OverlayGraph::fill_at`.
+    ///
+    /// # Parameters
+    /// - `point`: The queried point, it is rounded to the integer grid of the graph.
+    ///
+    /// # Returns
+    /// `PointFill` with the membership of the point in the subject and in the clip.
+    #[inline]
+    pub fn fill_at(&self, point: F32Point) -> PointFill {
+        self.graph.fill_at(self.adapter.convert_to_int(&point))
+    }
+
+    /// Tells whether a point belongs to the result of an overlay rule, see `OverlayGraph::contains`.
+    ///
+    /// # Parameters
+    /// - `point`: The queried point, it is rounded to the integer grid of the graph.
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    #[inline]
+    pub fn contains(&self, point: F32Point, overlay_rule: OverlayRule) -> bool {
+        self.graph.contains(self.adapter.convert_to_int(&point), overlay_rule)
+    }
+
+    /// Finds the fill of the subject and clip shapes at every point, see `OverlayGraph::fill_at_points`.
+    ///
+    /// # Parameters
+    /// - `points`: The queried points, they are rounded to the integer grid of the graph.
+    ///
+    /// # Returns
+    /// `PointFill` for every point, in the order of `points`.
+    pub fn fill_at_points(&self, points: &[F32Point]) -> Vec<PointFill> {
+        let points: Vec<IntPoint> = points.iter().map(|point| self.adapter.convert_to_int(point)).collect();
+        self.graph.fill_at_points(&points)
+    }
+
+    /// Tells whether every point belongs to the result of an overlay rule, see `OverlayGraph::contains_points`.
+    ///
+    /// # Parameters
+    /// - `points`: The queried points, they are rounded to the integer grid of the graph.
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    pub fn contains_points(&self, points: &[F32Point], overlay_rule: OverlayRule) -> Vec<bool> {
+        let points: Vec<IntPoint> = points.iter().map(|point| self.adapter.convert_to_int(point)).collect();
+        self.graph.contains_points(&points, overlay_rule)
+    }
+
+    /// Computes the DE-9IM intersection matrix of the subject and the clip shapes, see `OverlayGraph::relate`.
+    #[inline]
+    pub fn relate(&self) -> IntersectionMatrix {
+        self.graph.relate()
+    }
+
+    /// Tells whether the subject and the clip shapes satisfy a spatial predicate, see `OverlayGraph::satisfies`.
+    ///
+    /// # Parameters
+    /// - `predicate`: The spatial relation to check.
+    #[inline]
+    pub fn satisfies(&self, predicate: Predicate) -> bool {
+        self.graph.satisfies(predicate)
+    }
+
+    /// Calculates the area of the shapes which `extract_shapes` would return, see `OverlayGraph::area`.
+    ///
+    /// # Parameters
+    /// - `overlay_rule`: The boolean operation rule which defines the result.
+    ///
+    /// # Returns
+    /// The area of the result in the units of the input points.
+    #[inline]
+    pub fn area(&self, overlay_rule: OverlayRule) -> f32 {
+        let scale = self.adapter.inv_scale as f64;
+        (self.graph.area(overlay_rule) * scale * scale) as f32
+    }
+
+    /// Measures the areas of the subject, the clip, their intersection and their union at once, see `OverlayGraph::metrics`.
+    ///
+    /// # Returns
+    /// `AreaMetrics` in the units of the input points.
+    #[inline]
+    pub fn metrics(&self) -> AreaMetrics {
+        self.graph.metrics().scaled(self.adapter.inv_scale as f64)
+    }
+}
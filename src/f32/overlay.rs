@@ -7,6 +7,7 @@ use i_float::f32_rect::F32Rect;
 use i_shape::f32::adapter::ShapeToInt;
 use i_shape::f32::rect::RectInit;
 use i_shape::f32::shape::{F32Path, F32Shapes};
+use crate::core::error::OverlayError;
 use crate::core::fill_rule::FillRule;
 use crate::core::overlay::{Overlay, ShapeType};
 use crate::core::solver::Solver;
@@ -17,12 +18,13 @@ use crate::f32::graph::F32OverlayGraph;
 pub struct F32Overlay {
     subj_paths: Vec<F32Path>,
     clip_paths: Vec<F32Path>,
+    lines: Vec<F32Path>,
 }
 
 impl F32Overlay {
     #[inline]
     pub fn new() -> Self {
-        Self { subj_paths: vec![], clip_paths: vec![] }
+        Self { subj_paths: vec![], clip_paths: vec![], lines: vec![] }
     }
 
     /// Creates a new `Overlay` instance and initializes it with subject and clip paths.
@@ -32,7 +34,7 @@ impl F32Overlay {
     pub fn with_shapes(subj_shapes: F32Shapes, clip_shapes: F32Shapes) -> Self {
         let subj_paths = subj_shapes.into_iter().flat_map(|v| v.into_iter()).collect();
         let clip_paths = clip_shapes.into_iter().flat_map(|v| v.into_iter()).collect();
-        Self { subj_paths, clip_paths }
+        Self { subj_paths, clip_paths, lines: vec![] }
     }
 
     /// Creates a new `Overlay` instance and initializes it with subject and clip paths.
@@ -40,7 +42,7 @@ impl F32Overlay {
     /// - `clip_paths`: An array of paths that together define the clip.
     #[inline]
     pub fn with_paths(subj_paths: Vec<F32Path>, clip_paths: Vec<F32Path>) -> Self {
-        Self { subj_paths, clip_paths }
+        Self { subj_paths, clip_paths, lines: vec![] }
     }
 
     /// Creates a new `Overlay` instance and initializes it with subject and clip path.
@@ -48,7 +50,7 @@ impl F32Overlay {
     /// - `clip_path`: A path that define the clip.
     #[inline]
     pub fn with_path(subj_path: F32Path, clip_path: F32Path) -> Self {
-        Self { subj_paths: vec![subj_path], clip_paths: vec![clip_path] }
+        Self { subj_paths: vec![subj_path], clip_paths: vec![clip_path], lines: vec![] }
     }
 
     /// Adds a single path to the overlay as either subject or clip paths.
@@ -82,8 +84,26 @@ impl F32Overlay {
         }
     }
 
+    /// Adds an open polyline to the overlay. Its pieces can be extracted with `F32OverlayGraph::extract_lines`.
+    /// - `path`: A `F32Path` instance, a sequence of at least two points.
+    #[inline]
+    pub fn add_polyline(&mut self, path: F32Path) {
+        self.lines.push(path);
+    }
+
+    /// Adds multiple open polylines to the overlay.
+    /// - `paths`: An array of `F32Path` instances to be added to the overlay as polylines.
+    #[inline]
+    pub fn add_polylines(&mut self, paths: Vec<F32Path>) {
+        let mut mpaths = paths;
+        self.lines.append(&mut mpaths);
+    }
+
     /// Convert into `FloatOverlayGraph` from the added paths or shapes using the specified fill rule. This graph is the foundation for executing boolean operations, allowing for the analysis and manipulation of the geometric data. The `OverlayGraph` created by this method represents a preprocessed state of the input shapes, optimized for the application of boolean operations based on the provided fill rule.
     /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes, influencing how the resulting graph represents intersections and unions.
+    ///
+    /// # Panics
+    /// If the graph can not be built, see `try_into_graph`.
     #[inline(always)]
     pub fn into_graph(self, fill_rule: FillRule) -> F32OverlayGraph {
         self.into_graph_with_solver(fill_rule, Solver::AUTO)
@@ -92,11 +112,55 @@ impl F32Overlay {
     /// Convert into `FloatOverlayGraph` from the added paths or shapes using the specified fill rule. This graph is the foundation for executing boolean operations, allowing for the analysis and manipulation of the geometric data. The `OverlayGraph` created by this method represents a preprocessed state of the input shapes, optimized for the application of boolean operations based on the provided fill rule.
     /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes, influencing how the resulting graph represents intersections and unions.
     /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If the graph can not be built, see `try_into_graph_with_solver`.
     pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> F32OverlayGraph {
+        let (overlay, adapter) = self.into_overlay();
+
+        let graph = overlay.into_graph_with_solver(fill_rule, solver);
+
+        F32OverlayGraph::new(graph, adapter)
+    }
+
+    /// Same as `into_graph`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - Returns: `OverlayError::NonFiniteInput` if a point has a `NaN` or infinite coordinate, or `OverlayError::TopologyFailure` if the graph can not be built.
+    #[inline(always)]
+    pub fn try_into_graph(self, fill_rule: FillRule) -> Result<F32OverlayGraph, OverlayError> {
+        self.try_into_graph_with_solver(fill_rule, Solver::AUTO)
+    }
+
+    /// Same as `into_graph_with_solver`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    /// - Returns: `OverlayError::NonFiniteInput` if a point has a `NaN` or infinite coordinate, or `OverlayError::TopologyFailure` if the graph can not be built.
+    pub fn try_into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> Result<F32OverlayGraph, OverlayError> {
+        let is_finite = self.subj_paths.iter()
+            .chain(self.clip_paths.iter())
+            .chain(self.lines.iter())
+            .flatten()
+            .all(|p| p.x.is_finite() && p.y.is_finite());
+
+        if !is_finite {
+            return Err(OverlayError::NonFiniteInput);
+        }
+
+        let (overlay, adapter) = self.into_overlay();
+
+        let graph = overlay.try_into_graph_with_solver(fill_rule, solver)?;
+
+        Ok(F32OverlayGraph::new(graph, adapter))
+    }
+
+    fn into_overlay(self) -> (Overlay, F32PointAdapter) {
         let subj_rect = F32Rect::with_shape(&self.subj_paths);
         let clip_rect = F32Rect::with_shape(&self.clip_paths);
 
-        let union_rect = F32Rect::with_optional_rects(subj_rect, clip_rect)
+        let lines_rect = F32Rect::with_shape(&self.lines);
+
+        let shapes_rect = F32Rect::with_optional_rects(subj_rect, clip_rect);
+        let union_rect = F32Rect::with_optional_rects(shapes_rect, lines_rect)
             .unwrap_or(F32Rect {
                 min_x: -1.0,
                 max_x: 1.0,
@@ -109,10 +173,12 @@ impl F32Overlay {
         let int_subj = self.subj_paths.to_int(&adapter);
         let int_clip = self.clip_paths.to_int(&adapter);
 
-        let overlay = Overlay::with_paths(&int_subj, &int_clip);
-        let graph = overlay.into_graph_with_solver(fill_rule, solver);
+        let int_lines = self.lines.to_int(&adapter);
 
-        F32OverlayGraph::new(graph, adapter)
+        let mut overlay = Overlay::with_paths(&int_subj, &int_clip);
+        overlay.add_polylines(&int_lines);
+
+        (overlay, adapter)
     }
 }
 
@@ -0,0 +1,32 @@
+//! This module compares two float shape sets by their areas, see `crate::core::metrics`.
+
+use i_float::f64_adapter::F64PointAdapter;
+use i_float::f64_rect::F64Rect;
+use i_shape::f64::adapter::ShapeToInt;
+use i_shape::f64::rect::RectInit;
+use i_shape::f64::shape::F64Shape;
+use crate::core::fill_rule::FillRule;
+use crate::core::metrics::{AreaMetrics, Metrics};
+use crate::core::overlay::{Overlay, ShapeType};
+
+impl Metrics for [F64Shape] {
+    fn metrics(&self, other: &Self, fill_rule: FillRule) -> AreaMetrics {
+        let rect = match F64Rect::with_optional_rects(F64Rect::with_shapes(self), F64Rect::with_shapes(other)) {
+            Some(rect) => rect,
+            None => return AreaMetrics::default(),
+        };
+
+        // both sets must share one adapter to be compared on the same integer grid
+        let adapter = F64PointAdapter::new(rect);
+        let capacity = self.iter().chain(other.iter()).flat_map(|shape| shape.iter()).map(|path| path.len()).sum();
+        let mut overlay = Overlay::new(capacity);
+        for shape in self.iter() {
+            overlay.add_shape(&shape.to_int(&adapter), ShapeType::Subject);
+        }
+        for shape in other.iter() {
+            overlay.add_shape(&shape.to_int(&adapter), ShapeType::Clip);
+        }
+
+        overlay.into_graph(fill_rule).metrics().scaled(adapter.inv_scale)
+    }
+}
@@ -1,3 +1,5 @@
 pub mod overlay;
 pub mod graph;
 pub mod simplify;
+pub mod union_all;
+pub mod metrics;
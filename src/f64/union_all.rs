@@ -0,0 +1,38 @@
+//! This module provides a union of many shapes at once, see `crate::core::union_all`.
+
+use i_float::f64_adapter::F64PointAdapter;
+use i_float::f64_rect::F64Rect;
+use i_shape::f64::adapter::{ShapesToFloat, ShapeToInt};
+use i_shape::f64::rect::RectInit;
+use i_shape::f64::shape::{F64Shape, F64Shapes};
+use i_shape::int::shape::IntShapes;
+use crate::core::solver::Solver;
+use crate::core::union_all::UnionAll as IntUnionAll;
+
+pub trait UnionAll {
+    /// Unites all shapes with the `NonZero` fill rule, see `crate::core::union_all::UnionAll::union_all`.
+    /// - Returns: A vector of `F64Shape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    #[inline(always)]
+    fn union_all(&self) -> F64Shapes {
+        self.union_all_with_solver(Solver::AUTO)
+    }
+
+    /// Same as `union_all`, but with a specific solver. If multithreading is allowed by the solver, the groups are united in parallel.
+    /// - `solver`: Type of solver to use.
+    fn union_all_with_solver(&self, solver: Solver) -> F64Shapes;
+}
+
+impl UnionAll for [F64Shape] {
+    fn union_all_with_solver(&self, solver: Solver) -> F64Shapes {
+        let rect = match F64Rect::with_shapes(self) {
+            Some(rect) => rect,
+            None => return Vec::new(),
+        };
+
+        let adapter = F64PointAdapter::new(rect);
+        let int_shapes: IntShapes = self.iter().map(|shape| shape.to_int(&adapter)).collect();
+        let shapes = int_shapes.union_all_with_solver(solver);
+
+        shapes.to_float(&adapter)
+    }
+}
@@ -1,30 +1,31 @@
 use std::cmp::Ordering;
+use i_float::point::IntPoint;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::x_segment::XSegment;
-use crate::segm::shape_count::ShapeCount;
 
 #[derive(Debug, Clone)]
-pub(super) struct CountSegment {
-    pub(super) count: ShapeCount,
-    pub(super) x_segment: XSegment
+pub(crate) struct CountSegment<C, P = IntPoint> {
+    pub(crate) count: C,
+    pub(crate) x_segment: XSegment<P>
 }
 
-impl PartialEq<Self> for CountSegment {
+impl<C, P: PartialEq> PartialEq<Self> for CountSegment<C, P> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.x_segment == other.x_segment
     }
 }
 
-impl Eq for CountSegment {}
+impl<C, P: Eq> Eq for CountSegment<C, P> {}
 
-impl PartialOrd for CountSegment {
+impl<C, P: OverlayPoint> PartialOrd for CountSegment<C, P> {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for CountSegment {
+impl<C, P: OverlayPoint> Ord for CountSegment<C, P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
         if self.x_segment.is_under_segment(&other.x_segment) {
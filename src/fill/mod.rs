@@ -1,4 +1,6 @@
 pub(crate) mod solver;
-mod count_segment;
-mod solver_list;
+pub(crate) mod count_segment;
+pub(crate) mod solver_list;
 mod solver_tree;
+#[cfg(feature = "allow_multithreading")]
+mod solver_par;
@@ -1,39 +1,98 @@
 use crate::core::fill_rule::FillRule;
+use crate::core::solver::Solver;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::shape_count::ShapeCount;
 use crate::segm::segment::{Segment, SegmentFill};
+use crate::segm::winding_count::WindingCount;
+
+pub(crate) trait FillStrategy<C, F> {
+    fn add_and_fill(&self, this: &C, bot: &C) -> (C, F);
+}
 
 pub(crate) struct FillSolver {}
 
 impl FillSolver {
-    pub(crate) fn fill(fill_rule: FillRule, is_list: bool, segments: &[Segment]) -> Vec<SegmentFill> {
+    pub(crate) fn fill<C, P, F, S>(strategy: &S, solver: &Solver, segments: &[Segment<C, P>]) -> Vec<F>
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        F: Clone + Default + Send,
+        S: FillStrategy<C, F> + Sync,
+    {
+        let mut result = Vec::new();
+        Self::fill_into(strategy, solver, segments, &mut result);
+        result
+    }
+
+    /// Same as `fill`, but writes the fills into `result`, so its memory can be reused.
+    pub(crate) fn fill_into<C, P, F, S>(strategy: &S, solver: &Solver, segments: &[Segment<C, P>], result: &mut Vec<F>)
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        F: Clone + Default + Send,
+        S: FillStrategy<C, F> + Sync,
+    {
+        let is_list = solver.is_list_fill(segments);
+
+        #[cfg(feature = "allow_multithreading")]
+        {
+            if let Some(multithreading) = solver.multithreading {
+                if segments.len() >= multithreading.par_fill_min_size {
+                    let bands_count = rayon::current_num_threads() << 2;
+                    Self::par_fill(segments, strategy, is_list, bands_count, result);
+                    return;
+                }
+            }
+        }
+
         if is_list {
-            Self::list_fill(segments, fill_rule)
+            Self::list_fill(segments, strategy, result)
         } else {
-            Self::tree_fill(segments, fill_rule)
+            Self::tree_fill(segments, strategy, result)
         }
     }
 }
 
-impl Segment {
+impl FillStrategy<ShapeCount, SegmentFill> for FillRule {
     #[inline]
-    pub(super) fn add_and_fill(&self, sum_count: ShapeCount, fill_rule: FillRule) -> (ShapeCount, SegmentFill) {
-        let new_count = sum_count.add(self.count);
+    fn add_and_fill(&self, this: &ShapeCount, bot: &ShapeCount) -> (ShapeCount, SegmentFill) {
+        let top = bot.add(*this);
 
-        let fill: SegmentFill = match fill_rule {
+        let fill: SegmentFill = match self {
             FillRule::EvenOdd => {
-                (1 & new_count.subj as SegmentFill) // SUBJ_TOP
-                    | ((1 & sum_count.subj as SegmentFill) << 1) // SUBJ_BOTTOM
-                    | ((1 & new_count.clip as SegmentFill) << 2) // CLIP_TOP
-                    | ((1 & sum_count.clip as SegmentFill) << 3) // CLIP_BOTTOM
+                (1 & top.subj as SegmentFill) // SUBJ_TOP
+                    | ((1 & bot.subj as SegmentFill) << 1) // SUBJ_BOTTOM
+                    | ((1 & top.clip as SegmentFill) << 2) // CLIP_TOP
+                    | ((1 & bot.clip as SegmentFill) << 3) // CLIP_BOTTOM
             }
             FillRule::NonZero => {
-                (new_count.subj != 0) as SegmentFill // SUBJ_TOP
-                    | ((sum_count.subj != 0) as SegmentFill) << 1 // SUBJ_BOTTOM
-                    | ((new_count.clip != 0) as SegmentFill) << 2 // CLIP_TOP
-                    | ((sum_count.clip != 0) as SegmentFill) << 3 // CLIP_BOTTOM
+                (top.subj != 0) as SegmentFill // SUBJ_TOP
+                    | ((bot.subj != 0) as SegmentFill) << 1 // SUBJ_BOTTOM
+                    | ((top.clip != 0) as SegmentFill) << 2 // CLIP_TOP
+                    | ((bot.clip != 0) as SegmentFill) << 3 // CLIP_BOTTOM
+            }
+            FillRule::Positive => {
+                (top.subj > 0) as SegmentFill // SUBJ_TOP
+                    | ((bot.subj > 0) as SegmentFill) << 1 // SUBJ_BOTTOM
+                    | ((top.clip > 0) as SegmentFill) << 2 // CLIP_TOP
+                    | ((bot.clip > 0) as SegmentFill) << 3 // CLIP_BOTTOM
+            }
+            FillRule::Negative => {
+                (top.subj < 0) as SegmentFill // SUBJ_TOP
+                    | ((bot.subj < 0) as SegmentFill) << 1 // SUBJ_BOTTOM
+                    | ((top.clip < 0) as SegmentFill) << 2 // CLIP_TOP
+                    | ((bot.clip < 0) as SegmentFill) << 3 // CLIP_BOTTOM
+            }
+            FillRule::Custom(fill) => {
+                let (subj_top, clip_top) = fill(top);
+                let (subj_bot, clip_bot) = fill(*bot);
+                subj_top as SegmentFill // SUBJ_TOP
+                    | (subj_bot as SegmentFill) << 1 // SUBJ_BOTTOM
+                    | (clip_top as SegmentFill) << 2 // CLIP_TOP
+                    | (clip_bot as SegmentFill) << 3 // CLIP_BOTTOM
             }
         };
 
-        (new_count, fill)
+        (top, fill)
     }
-}
\ No newline at end of file
+}
@@ -1,33 +1,32 @@
 use std::cmp::Ordering;
 use i_float::point::IntPoint;
-use i_float::triangle::Triangle;
-use crate::core::fill_rule::FillRule;
 use crate::fill::count_segment::CountSegment;
-use crate::fill::solver::FillSolver;
+use crate::fill::solver::{FillSolver, FillStrategy};
+use crate::overlay_point::OverlayPoint;
 use crate::segm::end::End;
-use crate::segm::segment::{NONE, Segment, SegmentFill};
-use crate::segm::shape_count::ShapeCount;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
 use crate::util::Int;
 
-struct ScanFillList {
-    buffer: Vec<CountSegment>,
+pub(crate) struct ScanFillList<C, P = IntPoint> {
+    buffer: Vec<CountSegment<C, P>>,
 }
 
-impl ScanFillList {
+impl<C: Clone + Default, P: OverlayPoint> ScanFillList<C, P> {
     #[inline(always)]
-    fn new(count: usize) -> Self {
+    pub(crate) fn new(count: usize) -> Self {
         Self { buffer: Vec::with_capacity(count.log2_sqrt()) }
     }
 }
 
-impl ScanFillList {
+impl<C: Clone + Default, P: OverlayPoint> ScanFillList<C, P> {
     #[inline(always)]
-    fn clear(&mut self, x: i32) {
-        self.buffer.retain(|s| s.x_segment.b.x > x);
+    pub(crate) fn clear(&mut self, x: P::Coord) {
+        self.buffer.retain(|s| s.x_segment.b.x() > x);
     }
 
     #[inline(always)]
-    fn insert(&mut self, segment: CountSegment) {
+    pub(crate) fn insert(&mut self, segment: CountSegment<C, P>) {
         match self.buffer.binary_search(&segment) {
             Ok(_) => unreachable!("Buffer can only contain unique elements"),
             Err(index) => self.buffer.insert(index, segment)
@@ -35,7 +34,7 @@ impl ScanFillList {
     }
 
     #[inline(always)]
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> ShapeCount {
+    pub(crate) fn find_under_and_nearest(&mut self, p: P) -> C {
         match self.buffer.binary_search_by(|s|
         if s.x_segment.is_under_point(p) {
             Ordering::Less
@@ -46,32 +45,53 @@ impl ScanFillList {
             Ok(_) => unreachable!("This condition should never occur"),
             Err(index) => {
                 if index == 0 {
-                    ShapeCount { subj: 0, clip: 0 }
+                    C::default()
                 } else {
-                    unsafe { self.buffer.get_unchecked(index - 1) }.count
+                    unsafe { self.buffer.get_unchecked(index - 1) }.count.clone()
                 }
             }
         }
     }
+
+    /// Finds the segment which goes through `p`, or the nearest segment under `p` if there is no such segment.
+    /// `p` must not be an end of any segment in the list.
+    /// - Returns: The found segment and whether it goes through `p`.
+    pub(crate) fn find_through_or_under(&self, p: P) -> Option<(&CountSegment<C, P>, bool)> {
+        let index = self.buffer.partition_point(|s| s.x_segment.is_under_point(p));
+        if let Some(s) = self.buffer.get(index) {
+            if P::area_two(s.x_segment.a, p, s.x_segment.b) == P::Product::default() {
+                return Some((s, true));
+            }
+        }
+
+        index.checked_sub(1).map(|i| (&self.buffer[i], false))
+    }
 }
 
 impl FillSolver {
-    pub(super) fn list_fill(segments: &[Segment], fill_rule: FillRule) -> Vec<SegmentFill> {
+    pub(super) fn list_fill<C, P, F, S>(segments: &[Segment<C, P>], strategy: &S, result: &mut Vec<F>)
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        F: Clone + Default,
+        S: FillStrategy<C, F>,
+    {
         // Mark. self is sorted by x_segment.a
         let mut scan_list = ScanFillList::new(segments.len());
         let mut buf = Vec::with_capacity(4);
 
         let n = segments.len();
-        let mut result = vec![NONE; n];
+        result.clear();
+        result.resize(n, F::default());
         let mut i = 0;
 
-        let mut x0 = 0;
+        let mut x0 = P::ZERO.x();
 
         while i < n {
             let p = segments[i].x_segment.a;
-            if p.x != x0 {
-                scan_list.clear(p.x);
-                x0 = p.x;
+            if p.x() != x0 {
+                scan_list.clear(p.x());
+                x0 = p.x();
             }
 
             buf.push(End { index: i, point: segments[i].x_segment.b });
@@ -83,27 +103,25 @@ impl FillSolver {
             }
 
             buf.sort_by(|s0, s1|
-            if Triangle::is_clockwise_point(p, s1.point, s0.point) {
+            if P::is_clockwise(p, s1.point, s0.point) {
                 Ordering::Less
             } else {
                 Ordering::Greater
             });
 
             let mut sum_count = scan_list.find_under_and_nearest(p);
-            let mut fill: SegmentFill;
+            let mut fill: F;
 
             for se in buf.iter() {
                 let sid = unsafe { segments.get_unchecked(se.index) };
-                (sum_count, fill) = sid.add_and_fill(sum_count, fill_rule);
+                (sum_count, fill) = strategy.add_and_fill(&sid.count, &sum_count);
                 *unsafe { result.get_unchecked_mut(se.index) } = fill;
                 if sid.x_segment.is_not_vertical() {
-                    scan_list.insert(CountSegment { count: sum_count, x_segment: sid.x_segment });
+                    scan_list.insert(CountSegment { count: sum_count.clone(), x_segment: sid.x_segment });
                 }
             }
 
             buf.clear();
         }
-
-        result
     }
 }
@@ -0,0 +1,211 @@
+use std::cmp::Ordering;
+use rayon::prelude::*;
+use crate::fill::count_segment::CountSegment;
+use crate::fill::solver::{FillSolver, FillStrategy};
+use crate::fill::solver_list::ScanFillList;
+use crate::fill::solver_tree::ScanFillTree;
+use crate::overlay_point::OverlayPoint;
+use crate::segm::end::End;
+use crate::segm::segment::Segment;
+use crate::segm::winding_count::WindingCount;
+
+const GROUND: usize = usize::MAX;
+
+/// A winding count inside a band, relative to the winding above the segment `base` which crosses the left border of the band.
+/// The count is absolute if there is no segment under it at the border.
+#[derive(Clone)]
+struct BandCount<C> {
+    base: usize,
+    delta: C,
+}
+
+impl<C: Default> Default for BandCount<C> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self { base: GROUND, delta: C::default() }
+    }
+}
+
+impl<C: WindingCount> WindingCount for BandCount<C> {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.delta.is_not_empty()
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        Self { base: self.base, delta: self.delta.add(count.delta) }
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        self.delta.apply(count.delta)
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        Self { base: self.base, delta: self.delta.invert() }
+    }
+}
+
+trait BandScan<C, P: OverlayPoint> {
+    fn clear(&mut self, x: P::Coord);
+    fn insert(&mut self, segment: CountSegment<C, P>);
+    fn find_under_and_nearest(&mut self, p: P) -> C;
+}
+
+impl<C: WindingCount, P: OverlayPoint> BandScan<C, P> for ScanFillList<C, P> {
+    #[inline(always)]
+    fn clear(&mut self, x: P::Coord) {
+        ScanFillList::clear(self, x)
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, segment: CountSegment<C, P>) {
+        ScanFillList::insert(self, segment)
+    }
+
+    #[inline(always)]
+    fn find_under_and_nearest(&mut self, p: P) -> C {
+        ScanFillList::find_under_and_nearest(self, p)
+    }
+}
+
+impl<C: WindingCount, P: OverlayPoint> BandScan<C, P> for ScanFillTree<C, P> {
+    #[inline(always)]
+    fn clear(&mut self, _x: P::Coord) {
+        // the tree removes passed segments by itself
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, segment: CountSegment<C, P>) {
+        ScanFillTree::insert(self, segment)
+    }
+
+    #[inline(always)]
+    fn find_under_and_nearest(&mut self, p: P) -> C {
+        ScanFillTree::find_under_and_nearest(self, p)
+    }
+}
+
+impl FillSolver {
+    /// Splits segments into x bands and sweeps every band in parallel. A band starts with the segments which cross its left border,
+    /// the windings above them are not known yet, so the band keeps all windings relative to them. At the end the windings are resolved
+    /// from left to right, and the fills are the same as after a single sweep.
+    pub(super) fn par_fill<C, P, F, S>(segments: &[Segment<C, P>], strategy: &S, is_list: bool, bands_count: usize, result: &mut Vec<F>)
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        F: Clone + Default + Send,
+        S: FillStrategy<C, F> + Sync,
+    {
+        let n = segments.len();
+        result.clear();
+        if n == 0 {
+            return;
+        }
+
+        let band_size = n.div_ceil(bands_count);
+
+        // all segments with the same start point must be in the same band
+        let mut starts = Vec::with_capacity(bands_count + 1);
+        starts.push(0);
+        for band in 1..bands_count {
+            let mut start = (band * band_size).max(*starts.last().unwrap());
+            while start < n && segments[start].x_segment.a == segments[start - 1].x_segment.a {
+                start += 1;
+            }
+            if start >= n {
+                break;
+            }
+            if start > *starts.last().unwrap() {
+                starts.push(start);
+            }
+        }
+        starts.push(n);
+
+        let bands: Vec<Vec<BandCount<C>>> = starts.par_windows(2).map(|range| {
+            let (start, end) = (range[0], range[1]);
+            if is_list {
+                Self::band_fill(segments, start, end, ScanFillList::new(end - start))
+            } else {
+                Self::band_fill(segments, start, end, ScanFillTree::new(end - start))
+            }
+        }).collect();
+
+        result.reserve(n);
+
+        let mut tops: Vec<C> = Vec::with_capacity(n);
+        for bot in bands.into_iter().flatten() {
+            let bot = if bot.base == GROUND {
+                bot.delta
+            } else {
+                tops[bot.base].clone().add(bot.delta)
+            };
+
+            let (top, fill) = strategy.add_and_fill(&segments[tops.len()].count, &bot);
+            tops.push(top);
+            result.push(fill);
+        }
+    }
+
+    /// Sweeps the segments `start..end`.
+    /// - Returns: The winding under every segment of the band, relative to the segments which cross the left border.
+    fn band_fill<C, P, L>(segments: &[Segment<C, P>], start: usize, end: usize, mut scan_list: L) -> Vec<BandCount<C>>
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        L: BandScan<BandCount<C>, P>,
+    {
+        let x_start = segments[start].x_segment.a.x();
+        for (index, s) in segments[..start].iter().enumerate() {
+            if s.x_segment.is_not_vertical() && s.x_segment.b.x() > x_start {
+                scan_list.insert(CountSegment { count: BandCount { base: index, delta: C::default() }, x_segment: s.x_segment });
+            }
+        }
+
+        let mut result = vec![BandCount::default(); end - start];
+        let mut buf = Vec::with_capacity(4);
+
+        let mut i = start;
+        let mut x0 = x_start;
+
+        while i < end {
+            let p = segments[i].x_segment.a;
+            if p.x() != x0 {
+                scan_list.clear(p.x());
+                x0 = p.x();
+            }
+
+            buf.push(End { index: i, point: segments[i].x_segment.b });
+            i += 1;
+
+            while i < end && segments[i].x_segment.a == p {
+                buf.push(End { index: i, point: segments[i].x_segment.b });
+                i += 1;
+            }
+
+            buf.sort_by(|s0, s1|
+            if P::is_clockwise(p, s1.point, s0.point) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+
+            let mut sum_count = scan_list.find_under_and_nearest(p);
+
+            for se in buf.iter() {
+                let sid = &segments[se.index];
+                result[se.index - start] = sum_count.clone();
+                sum_count = BandCount { base: sum_count.base, delta: sum_count.delta.add(sid.count.clone()) };
+                if sid.x_segment.is_not_vertical() {
+                    scan_list.insert(CountSegment { count: sum_count.clone(), x_segment: sid.x_segment });
+                }
+            }
+
+            buf.clear();
+        }
+
+        result
+    }
+}
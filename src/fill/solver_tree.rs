@@ -1,34 +1,33 @@
 use std::cmp::Ordering;
 use i_float::point::IntPoint;
-use i_float::triangle::Triangle;
 use i_tree::node::{Color, EMPTY_REF};
 use i_tree::tree::Tree;
-use crate::core::fill_rule::FillRule;
 use crate::fill::count_segment::CountSegment;
-use crate::fill::solver::FillSolver;
+use crate::fill::solver::{FillSolver, FillStrategy};
+use crate::overlay_point::OverlayPoint;
 use crate::segm::end::End;
-use crate::segm::segment::{NONE, Segment, SegmentFill};
+use crate::segm::segment::Segment;
 use crate::segm::x_segment::XSegment;
-use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
 use crate::util::Int;
 
-pub(super) struct ScanFillTree {
-    tree: Tree<CountSegment>,
+pub(super) struct ScanFillTree<C, P = IntPoint> {
+    tree: Tree<CountSegment<C, P>>,
 }
 
-impl ScanFillTree {
+impl<C: WindingCount, P: OverlayPoint> ScanFillTree<C, P> {
     #[inline]
     pub(super) fn new(count: usize) -> Self {
         let capacity = count.log2_sqrt();
-        let count = ShapeCount { subj: 0, clip: 0 };
-        let x_segment = XSegment { a: IntPoint::ZERO, b: IntPoint::ZERO };
+        let count = C::default();
+        let x_segment = XSegment { a: P::ZERO, b: P::ZERO };
         Self { tree: Tree::new(CountSegment { count, x_segment }, capacity) }
     }
 }
 
-impl ScanFillTree {
-    pub(super) fn insert(&mut self, segment: CountSegment) {
-        let stop = segment.x_segment.a.x;
+impl<C: WindingCount, P: OverlayPoint> ScanFillTree<C, P> {
+    pub(super) fn insert(&mut self, segment: CountSegment<C, P>) {
+        let stop = segment.x_segment.a.x();
         let mut index = self.tree.root;
         let mut p_index = EMPTY_REF;
         let mut is_left = false;
@@ -36,7 +35,7 @@ impl ScanFillTree {
         while index != EMPTY_REF {
             let node = self.tree.node(index);
             p_index = index;
-            if node.value.x_segment.b.x <= stop {
+            if node.value.x_segment.b.x() <= stop {
                 let nd_parent = node.parent;
                 _ = self.tree.delete_index(index);
                 if nd_parent != EMPTY_REF {
@@ -78,12 +77,12 @@ impl ScanFillTree {
         }
     }
 
-    fn find_under_and_nearest(&mut self, p: IntPoint) -> ShapeCount {
+    pub(super) fn find_under_and_nearest(&mut self, p: P) -> C {
         let mut index = self.tree.root;
-        let mut result = ShapeCount { subj: 0, clip: 0 };
+        let mut result = C::default();
         while index != EMPTY_REF {
             let node = self.tree.node(index);
-            if node.value.x_segment.b.x <= p.x {
+            if node.value.x_segment.b.x() <= p.x() {
                 let nd_parent = node.parent;
                 _ = self.tree.delete_index(index);
                 if nd_parent != EMPTY_REF {
@@ -92,7 +91,7 @@ impl ScanFillTree {
                     index = self.tree.root;
                 }
             } else if node.value.x_segment.is_under_point(p) {
-                result = node.value.count;
+                result = node.value.count.clone();
                 index = node.right;
             } else {
                 index = node.left;
@@ -105,13 +104,20 @@ impl ScanFillTree {
 
 
 impl FillSolver {
-    pub(super) fn tree_fill(segments: &[Segment], fill_rule: FillRule) -> Vec<SegmentFill> {
+    pub(super) fn tree_fill<C, P, F, S>(segments: &[Segment<C, P>], strategy: &S, result: &mut Vec<F>)
+    where
+        C: WindingCount,
+        P: OverlayPoint,
+        F: Clone + Default,
+        S: FillStrategy<C, F>,
+    {
         // Mark. self is sorted by x_segment.a
         let mut scan_list = ScanFillTree::new(segments.len());
         let mut buf = Vec::with_capacity(4);
 
         let n = segments.len();
-        let mut result = vec![NONE; n];
+        result.clear();
+        result.resize(n, F::default());
         let mut i = 0;
 
         while i < n {
@@ -126,27 +132,25 @@ impl FillSolver {
             }
 
             buf.sort_by(|s0, s1|
-            if Triangle::is_clockwise_point(p, s1.point, s0.point) {
+            if P::is_clockwise(p, s1.point, s0.point) {
                 Ordering::Less
             } else {
                 Ordering::Greater
             });
 
             let mut sum_count = scan_list.find_under_and_nearest(p);
-            let mut fill: SegmentFill;
+            let mut fill: F;
 
             for se in buf.iter() {
                 let sid = unsafe { segments.get_unchecked(se.index) };
-                (sum_count, fill) = sid.add_and_fill(sum_count, fill_rule);
+                (sum_count, fill) = strategy.add_and_fill(&sid.count, &sum_count);
                 *unsafe { result.get_unchecked_mut(se.index) } = fill;
                 if sid.x_segment.is_not_vertical() {
-                    scan_list.insert(CountSegment { count: sum_count, x_segment: sid.x_segment });
+                    scan_list.insert(CountSegment { count: sum_count.clone(), x_segment: sid.x_segment });
                 }
             }
 
             buf.clear();
         }
-
-        result
     }
 }
\ No newline at end of file
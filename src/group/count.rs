@@ -0,0 +1,87 @@
+use crate::core::fill_rule::FillRule;
+use crate::fill::solver::FillStrategy;
+use crate::group::overlay::{GroupMask, MAX_GROUP_COUNT};
+use crate::segm::winding_count::WindingCount;
+
+/// Packed group membership of both sides of a segment: the low half is the top side, the high half is the bottom side.
+pub(crate) type GroupFill = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct GroupCount {
+    pub(crate) counts: [i32; MAX_GROUP_COUNT],
+}
+
+impl GroupCount {
+    #[inline(always)]
+    pub(crate) fn with_group(group: usize, value: i32) -> Self {
+        let mut counts = [0; MAX_GROUP_COUNT];
+        counts[group] = value;
+        Self { counts }
+    }
+
+    #[inline]
+    fn mask(&self, fill_rule: FillRule) -> GroupMask {
+        let mut mask = 0;
+        for (i, &count) in self.counts.iter().enumerate() {
+            mask |= (fill_rule.is_fill(count) as GroupMask) << i;
+        }
+        mask
+    }
+}
+
+impl WindingCount for GroupCount {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.counts.iter().any(|&count| count != 0)
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        let mut result = self;
+        result.apply(count);
+        result
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        for (a, b) in self.counts.iter_mut().zip(count.counts.iter()) {
+            *a += b;
+        }
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        let mut result = self;
+        for count in result.counts.iter_mut() {
+            *count = -*count;
+        }
+        result
+    }
+}
+
+impl FillStrategy<GroupCount, GroupFill> for FillRule {
+    #[inline]
+    fn add_and_fill(&self, this: &GroupCount, bot: &GroupCount) -> (GroupCount, GroupFill) {
+        let top = bot.add(*this);
+        let fill = top.mask(*self) as GroupFill | (bot.mask(*self) as GroupFill) << MAX_GROUP_COUNT;
+
+        (top, fill)
+    }
+}
+
+pub(crate) trait GroupSides {
+    fn top(&self) -> GroupMask;
+    fn bottom(&self) -> GroupMask;
+}
+
+impl GroupSides for GroupFill {
+    #[inline(always)]
+    fn top(&self) -> GroupMask {
+        *self as GroupMask
+    }
+
+    #[inline(always)]
+    fn bottom(&self) -> GroupMask {
+        (*self >> MAX_GROUP_COUNT) as GroupMask
+    }
+}
@@ -0,0 +1,69 @@
+//! This module defines the graph built from a `GroupOverlay`. Every link keeps the set of groups
+//! on each of its sides, so shapes can be extracted for any combination of groups.
+
+use i_shape::int::shape::IntShapes;
+
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::solver::Solver;
+use crate::group::count::{GroupCount, GroupFill, GroupSides};
+use crate::group::overlay::GroupMask;
+use crate::id_point::IdPoint;
+use crate::segm::segment::{NONE, Segment};
+
+/// A representation of many groups of shapes organized for boolean operations between them.
+///
+/// Use `GroupOverlayGraph` to extract the regions whose group membership satisfies a predicate, for example the regions covered by at least two groups.
+pub struct GroupOverlayGraph {
+    pub(crate) graph: OverlayGraph,
+    pub(crate) fills: Vec<GroupFill>,
+}
+
+impl GroupOverlayGraph {
+    pub(super) fn new(solver: Solver, segments: Vec<Segment<GroupCount>>, fills: Vec<GroupFill>) -> Self {
+        let links = segments.iter()
+            .map(|segment| OverlayLink::new(
+                IdPoint::new(0, segment.x_segment.a),
+                IdPoint::new(0, segment.x_segment.b),
+                NONE,
+            )).collect();
+
+        let graph = OverlayGraph::with_links(solver, links);
+
+        Self { graph, fills }
+    }
+
+    /// Extracts shapes from the graph for the regions accepted by a predicate.
+    /// - `predicate`: Receives the `GroupMask` of a region, where bit `i` is set if the region is inside group `i`, and returns `true` if the region is part of the result.
+    /// - Returns: A vector of `IntShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// For example, `|mask| mask.count_ones() >= 2` selects the regions covered by at least two groups.
+    /// The predicate must return `false` for an empty mask, otherwise the result is unbounded.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes<P>(&self, predicate: P) -> IntShapes
+    where
+        P: Fn(GroupMask) -> bool,
+    {
+        self.extract_shapes_min_area(predicate, 0)
+    }
+
+    /// Extracts shapes from the graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes.
+    /// - `predicate`: Receives the `GroupMask` of a region and returns `true` if the region is part of the result.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_min_area<P>(&self, predicate: P, min_area: i64) -> IntShapes
+    where
+        P: Fn(GroupMask) -> bool,
+    {
+        let visited = self.fills.iter()
+            .map(|fill| predicate(fill.top()) == predicate(fill.bottom()))
+            .collect();
+
+        self.graph.extract_shapes_visited(visited, |link_index| predicate(self.fills[link_index].top()), min_area)
+    }
+}
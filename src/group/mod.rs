@@ -0,0 +1,3 @@
+pub mod overlay;
+pub mod graph;
+pub(crate) mod count;
@@ -0,0 +1,147 @@
+//! This module contains functionality to construct overlays of many groups of shapes at once.
+//! Unlike `Overlay`, which only knows subject and clip, every path here is tagged with a group
+//! index, and the winding of each group is tracked separately while filling.
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::IntShape;
+use i_shape::int::simple::Simple;
+
+use crate::core::fill_rule::FillRule;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::group::count::{GroupCount, GroupFill, GroupSides};
+use crate::group::graph::GroupOverlayGraph;
+use crate::segm::segment::{Segment, ShapeEdgesMerge};
+use crate::segm::x_segment::XSegment;
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+/// The maximum number of groups supported by `GroupOverlay`.
+pub const MAX_GROUP_COUNT: usize = 16;
+
+/// A set of groups a region belongs to, where bit `i` is set if the region is inside group `i`.
+pub type GroupMask = u16;
+
+/// This struct describes the geometry of an overlay with up to `MAX_GROUP_COUNT` groups of shapes.
+/// All groups are split against each other once, so any combination of them can be extracted from the same graph.
+#[derive(Clone)]
+pub struct GroupOverlay {
+    edges: Vec<Segment<GroupCount>>,
+}
+
+impl GroupOverlay {
+    /// Constructs a new `GroupOverlay` instance.
+    /// - `capacity`: The initial capacity for storing edge data. Ideally, this should be set to the sum of the edges of all shapes to be added to the overlay.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            edges: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a single path to the overlay.
+    /// - `path`: A reference to a `IntPath` instance to be added.
+    /// - `group`: The index of the group the path belongs to, must be less than `MAX_GROUP_COUNT`.
+    pub fn add_path(&mut self, path: &[IntPoint], group: usize) {
+        assert!(group < MAX_GROUP_COUNT, "group index {} is out of range", group);
+        if path.is_simple() {
+            self.append_edges(path, group);
+        } else {
+            let path = path.to_simple();
+            if path.len() > 2 {
+                self.append_edges(path.as_slice(), group);
+            }
+        }
+    }
+
+    /// Adds multiple paths to the overlay.
+    /// - `paths`: An array of `IntPath` instances to be added to the overlay.
+    /// - `group`: The index of the group the paths belong to, must be less than `MAX_GROUP_COUNT`.
+    pub fn add_paths(&mut self, paths: &[IntPath], group: usize) {
+        for path in paths.iter() {
+            self.add_path(path, group);
+        }
+    }
+
+    /// Adds a single shape to the overlay.
+    /// - `shape`: A reference to a `IntShape` instance to be added.
+    /// - `group`: The index of the group the shape belongs to, must be less than `MAX_GROUP_COUNT`.
+    pub fn add_shape(&mut self, shape: &IntShape, group: usize) {
+        self.add_paths(shape, group);
+    }
+
+    /// Adds multiple shapes to the overlay.
+    /// - `shapes`: An array of `IntShape` instances to be added to the overlay.
+    /// - `group`: The index of the group the shapes belong to, must be less than `MAX_GROUP_COUNT`.
+    pub fn add_shapes(&mut self, shapes: &[IntShape], group: usize) {
+        for shape in shapes.iter() {
+            self.add_paths(shape, group);
+        }
+    }
+
+    /// Convert into `GroupOverlayGraph` from the added paths or shapes using the specified fill rule. The fill rule is applied to every group independently.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes of each group.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph(self, fill_rule: FillRule) -> GroupOverlayGraph {
+        self.into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `GroupOverlayGraph` from the added paths or shapes using the specified fill rule. The fill rule is applied to every group independently.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes of each group.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> GroupOverlayGraph {
+        if self.edges.is_empty() {
+            return GroupOverlayGraph::new(solver, Vec::new(), Vec::new());
+        }
+
+        let mut segments = self.edges;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let mut fills: Vec<GroupFill> = FillSolver::fill(&fill_rule, &solver, &segments);
+
+        clean_if_needed(&mut segments, &mut fills);
+
+        GroupOverlayGraph::new(solver, segments, fills)
+    }
+
+    fn append_edges(&mut self, path: &[IntPoint], group: usize) {
+        let direct = GroupCount::with_group(group, 1);
+        let invert = GroupCount::with_group(group, -1);
+
+        let mut p0 = path[path.len() - 1];
+        for &p1 in path {
+            let segment = if p0 < p1 {
+                Segment { x_segment: XSegment { a: p0, b: p1 }, count: direct }
+            } else {
+                Segment { x_segment: XSegment { a: p1, b: p0 }, count: invert }
+            };
+            self.edges.push(segment);
+            p0 = p1
+        }
+    }
+}
+
+fn clean_if_needed(segments: &mut Vec<Segment<GroupCount>>, fills: &mut Vec<GroupFill>) {
+    // a segment is useless if every group is on the same side of it
+    let mut j = 0;
+    for i in 0..fills.len() {
+        let fill = fills[i];
+        if fill.top() != fill.bottom() {
+            fills[j] = fill;
+            segments[j] = segments[i];
+            j += 1;
+        }
+    }
+
+    fills.truncate(j);
+    segments.truncate(j);
+}
@@ -0,0 +1,272 @@
+use crate::i64::point::{I64Point, I64Triangle, I64Vec};
+use crate::segm::x_segment::XSegment;
+use crate::split::cross_solver::{CollinearMask, CrossResult, CrossType, EndMask};
+
+/// The same as `CrossSolver`, but for `i64` coordinates. Intermediate values are calculated with `i128` and the cross point with 256 bit math.
+pub(super) struct I64CrossSolver;
+
+impl I64CrossSolver {
+    pub(super) fn cross(target: &XSegment<I64Point>, other: &XSegment<I64Point>, radius: i128) -> Option<CrossResult<I64Point>> {
+        let a0b0a1 = I64Triangle::clock_direction(target.a, target.b, other.a);
+        let a0b0b1 = I64Triangle::clock_direction(target.a, target.b, other.b);
+
+        let a1b1a0 = I64Triangle::clock_direction(other.a, other.b, target.a);
+        let a1b1b0 = I64Triangle::clock_direction(other.a, other.b, target.b);
+
+        let s = (1 & (a0b0a1 + 1)) + (1 & (a0b0b1 + 1)) + (1 & (a1b1a0 + 1)) + (1 & (a1b1b0 + 1));
+
+        if s == 4 {
+            return Some(CrossResult {
+                point: I64Point::ZERO,
+                cross_type: CrossType::Overlay,
+                is_round: false,
+            });
+        }
+
+        let is_not_cross = a0b0a1 == a0b0b1 || a1b1a0 == a1b1b0;
+
+        if s > 1 || is_not_cross {
+            return None;
+        }
+
+        if s != 0 {
+            let (point, cross_type) = if a0b0a1 == 0 {
+                (other.a, CrossType::OtherEnd)
+            } else if a0b0b1 == 0 {
+                (other.b, CrossType::OtherEnd)
+            } else if a1b1a0 == 0 {
+                (target.a, CrossType::TargetEnd)
+            } else {
+                (target.b, CrossType::TargetEnd)
+            };
+
+            return Some(CrossResult { point, cross_type, is_round: false });
+        }
+
+        Some(Self::middle_cross(target, other, radius))
+    }
+
+    pub(super) fn collinear(target: &XSegment<I64Point>, other: &XSegment<I64Point>) -> CollinearMask {
+        let v1 = I64Vec::new(other.a, other.b);
+
+        let aa0 = I64Vec::new(other.a, target.a).dot_product(v1).signum();
+        let ab0 = I64Vec::new(other.b, target.a).dot_product(v1).signum();
+        let ba0 = I64Vec::new(other.a, target.b).dot_product(v1).signum();
+        let bb0 = I64Vec::new(other.b, target.b).dot_product(v1).signum();
+
+        let aa1 = -aa0;
+        let ab1 = -ba0;
+        let ba1 = -ab0;
+        let bb1 = -bb0;
+
+        let is_target_a = aa0 == -ab0 && aa0 != 0;
+        let is_target_b = ba0 == -bb0 && ba0 != 0;
+
+        let is_other_a = aa1 == -ab1 && aa1 != 0;
+        let is_other_b = ba1 == -bb1 && ba1 != 0;
+
+        CollinearMask::new(is_target_a, is_target_b, is_other_a, is_other_b)
+    }
+
+    fn middle_cross(target: &XSegment<I64Point>, other: &XSegment<I64Point>, radius: i128) -> CrossResult<I64Point> {
+        let p = Self::cross_point(target, other);
+
+        if I64Triangle::is_line(target.a, p, target.b) && I64Triangle::is_line(other.a, p, other.b) {
+            return CrossResult { point: p, cross_type: CrossType::Pure, is_round: false };
+        }
+
+        // still can be common ends because of rounding
+
+        let ra0 = target.a.sqr_distance(p);
+        let rb0 = target.b.sqr_distance(p);
+
+        let ra1 = other.a.sqr_distance(p);
+        let rb1 = other.b.sqr_distance(p);
+
+        if ra0 <= radius || ra1 <= radius || rb0 <= radius || rb1 <= radius {
+            let r0 = ra0.min(rb0);
+            let r1 = ra1.min(rb1);
+
+            if r0 <= r1 {
+                let p = if ra0 < rb0 { target.a } else { target.b };
+                // ignore if it's a clean point
+                if !I64Triangle::is_line(other.a, p, other.b) {
+                    return CrossResult { point: p, cross_type: CrossType::TargetEnd, is_round: true };
+                }
+            } else {
+                let p = if ra1 < rb1 { other.a } else { other.b };
+                // ignore if it's a clean point
+                if !I64Triangle::is_line(target.a, p, target.b) {
+                    return CrossResult { point: p, cross_type: CrossType::OtherEnd, is_round: true };
+                }
+            }
+        }
+
+        CrossResult { point: p, cross_type: CrossType::Pure, is_round: true }
+    }
+
+    fn cross_point(target: &XSegment<I64Point>, other: &XSegment<I64Point>) -> I64Point {
+        // the same offset approach as for `IntPoint`, all picture is moved by -a0
+        // any abs(x) and abs(y) <= 2^60, so the offsets are < 2^62 and the products < 2^124
+
+        let a0x = target.a.x as i128;
+        let a0y = target.a.y as i128;
+
+        let a1x = target.b.x as i128 - a0x;
+        let b0x = other.a.x as i128 - a0x;
+        let b1x = other.b.x as i128 - a0x;
+
+        let a1y = target.b.y as i128 - a0y;
+        let b0y = other.a.y as i128 - a0y;
+        let b1y = other.b.y as i128 - a0y;
+
+        let dy_b = b0y - b1y;
+        let dx_b = b0x - b1x;
+
+        let xy_b = b0x * b1y - b0y * b1x;
+
+        let x0: i128;
+        let y0: i128;
+
+        if a1x == 0 {
+            x0 = 0;
+            y0 = xy_b / dx_b;
+        } else if a1y == 0 {
+            y0 = 0;
+            x0 = -xy_b / dy_b;
+        } else {
+            let div = a1y * dx_b - a1x * dy_b;
+
+            // calculate result sign
+            let s = div.signum() * xy_b.signum();
+            let sx = a1x.signum() * s;
+            let sy = a1y.signum() * s;
+
+            // a1 * xy_b needs up to 186 bits
+            let uxy_b = xy_b.unsigned_abs();
+            let udiv = div.unsigned_abs();
+
+            let ux = UInt256::multiply(a1x.unsigned_abs(), uxy_b).divide_with_rounding(udiv);
+            let uy = UInt256::multiply(a1y.unsigned_abs(), uxy_b).divide_with_rounding(udiv);
+
+            x0 = sx * ux as i128;
+            y0 = sy * uy as i128;
+        }
+
+        let x = (x0 + a0x) as i64;
+        let y = (y0 + a0y) as i64;
+
+        I64Point::new(x, y)
+    }
+}
+
+struct UInt256 {
+    high: u128,
+    low: u128,
+}
+
+const LOW_MASK: u128 = u64::MAX as u128;
+
+impl UInt256 {
+    fn multiply(a: u128, b: u128) -> Self {
+        let (a0, a1) = (a & LOW_MASK, a >> 64);
+        let (b0, b1) = (b & LOW_MASK, b >> 64);
+
+        let ll = a0 * b0;
+        let lh = a0 * b1;
+        let hl = a1 * b0;
+        let hh = a1 * b1;
+
+        let mid = (ll >> 64) + (lh & LOW_MASK) + (hl & LOW_MASK);
+
+        let low = (ll & LOW_MASK) | (mid << 64);
+        let high = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+
+        Self { high, low }
+    }
+
+    /// The quotient must fit into `u128`, so `high` must be less than `divisor`.
+    fn divide_with_rounding(&self, divisor: u128) -> u128 {
+        debug_assert!(self.high < divisor);
+
+        let (quotient, remainder) = if self.high == 0 {
+            (self.low / divisor, self.low % divisor)
+        } else {
+            let mut remainder = self.high;
+            let mut quotient = 0;
+            for i in (0..u128::BITS).rev() {
+                let carry = remainder >> (u128::BITS - 1) == 1;
+                remainder = (remainder << 1) | ((self.low >> i) & 1);
+                quotient <<= 1;
+                if carry || remainder >= divisor {
+                    remainder = remainder.wrapping_sub(divisor);
+                    quotient |= 1;
+                }
+            }
+            (quotient, remainder)
+        };
+
+        if remainder >= (divisor + 1) >> 1 {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i64::cross::{I64CrossSolver, UInt256};
+    use crate::i64::point::I64Point;
+    use crate::segm::x_segment::XSegment;
+    use crate::split::cross_solver::CrossType;
+
+    fn segment(a: [i64; 2], b: [i64; 2]) -> XSegment<I64Point> {
+        XSegment { a: I64Point::new(a[0], a[1]), b: I64Point::new(b[0], b[1]) }
+    }
+
+    #[test]
+    fn test_multiply_divide() {
+        let a = (1u128 << 61) + 12345;
+        let b = (1u128 << 123) + 67890;
+        let d = (1u128 << 122) + 3;
+        let product = UInt256::multiply(a, b);
+        assert_eq!(product.high, 72057594037928321);
+
+        let q = product.divide_with_rounding(d);
+        assert_eq!(q, 4611686018427412594);
+    }
+
+    #[test]
+    fn test_big_cross() {
+        let s: i64 = 1 << 60;
+        let ea = segment([-s, -s], [s, s]);
+        let eb = segment([s / 2 + 1, -s], [s / 2 + 1, s]);
+
+        let result = I64CrossSolver::cross(&ea, &eb, 2).unwrap();
+
+        match result.cross_type {
+            CrossType::Pure => {
+                assert_eq!(I64Point::new(s / 2 + 1, s / 2 + 1), result.point);
+            }
+            _ => panic!("Fail cross result"),
+        }
+    }
+
+    #[test]
+    fn test_big_cross_round() {
+        let s: i64 = 1 << 60;
+        let ea = segment([-s, -s + 1], [s, s]);
+        let eb = segment([-s, s], [s, -s]);
+
+        let result = I64CrossSolver::cross(&ea, &eb, 2).unwrap();
+
+        match result.cross_type {
+            CrossType::Pure => {
+                assert!(result.is_round);
+                assert_eq!(I64Point::new(0, 0), result.point);
+            }
+            _ => panic!("Fail cross result"),
+        }
+    }
+}
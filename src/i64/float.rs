@@ -0,0 +1,241 @@
+//! The `f64` API on top of `I64Overlay`. The points are scaled into `I64Point` instead of `IntPoint`,
+//! so much more of the `f64` precision is kept than with `F64Overlay`.
+
+use i_float::f64_point::F64Point;
+use i_float::f64_rect::F64Rect;
+use i_shape::f64::rect::RectInit;
+use i_shape::f64::shape::{F64Path, F64Shape, F64Shapes};
+use crate::core::error::OverlayError;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::i64::graph::I64OverlayGraph;
+use crate::i64::overlay::I64Overlay;
+use crate::i64::point::{I64Path, I64Point, I64Shapes};
+
+/// Converts `F64Point` into `I64Point` and back. The same as `F64PointAdapter`, but the points are scaled up to `I64Point::MAX_COORD`.
+pub struct I64F64Adapter {
+    pub dir_scale: f64,
+    pub inv_scale: f64,
+    pub offset: F64Point,
+}
+
+impl I64F64Adapter {
+    pub fn new(rect: F64Rect) -> Self {
+        let a = rect.width() * 0.5;
+        let b = rect.height() * 0.5;
+
+        let offset = F64Point { x: rect.min_x + a, y: rect.min_y + b };
+
+        let max = a.max(b);
+
+        // degenerate case
+        if max == 0.0 {
+            return Self { dir_scale: 1.0, inv_scale: 1.0, offset };
+        }
+
+        let log2 = max.log2() as i32;
+        let e = 59 - log2;
+
+        Self { dir_scale: 2f64.powi(e), inv_scale: 2f64.powi(-e), offset }
+    }
+
+    #[inline(always)]
+    pub fn convert_to_float(&self, point: &I64Point) -> F64Point {
+        let x = point.x as f64 * self.inv_scale + self.offset.x;
+        let y = point.y as f64 * self.inv_scale + self.offset.y;
+        F64Point { x, y }
+    }
+
+    #[inline(always)]
+    pub fn convert_to_int(&self, point: &F64Point) -> I64Point {
+        let x = ((point.x - self.offset.x) * self.dir_scale) as i64;
+        let y = ((point.y - self.offset.y) * self.dir_scale) as i64;
+        I64Point { x, y }
+    }
+
+    fn path_to_int(&self, path: &[F64Point]) -> I64Path {
+        path.iter().map(|p| self.convert_to_int(p)).collect()
+    }
+
+    fn shapes_to_float(&self, shapes: I64Shapes) -> F64Shapes {
+        shapes.iter().map(|shape| {
+            shape.iter().map(|path| path.iter().map(|p| self.convert_to_float(p)).collect()).collect()
+        }).collect()
+    }
+}
+
+/// The same as `F64Overlay`, but the boolean operations are done by `I64Overlay`.
+#[derive(Clone, Default)]
+pub struct I64F64Overlay {
+    subj_paths: Vec<F64Path>,
+    clip_paths: Vec<F64Path>,
+}
+
+impl I64F64Overlay {
+    #[inline]
+    pub fn new() -> Self {
+        Self { subj_paths: vec![], clip_paths: vec![] }
+    }
+
+    /// Creates a new `I64F64Overlay` instance and initializes it with subject and clip paths.
+    /// - `subj_paths`: An array of paths that together define the subject.
+    /// - `clip_paths`: An array of paths that together define the clip.
+    #[inline]
+    pub fn with_paths(subj_paths: Vec<F64Path>, clip_paths: Vec<F64Path>) -> Self {
+        Self { subj_paths, clip_paths }
+    }
+
+    /// Creates a new `I64F64Overlay` instance and initializes it with subject and clip shapes.
+    /// - `subj_shapes`: An array of shapes that together define the subject.
+    /// - `clip_shapes`: An array of shapes that together define the clip.
+    #[inline]
+    pub fn with_shapes(subj_shapes: F64Shapes, clip_shapes: F64Shapes) -> Self {
+        let subj_paths = subj_shapes.into_iter().flatten().collect();
+        let clip_paths = clip_shapes.into_iter().flatten().collect();
+        Self { subj_paths, clip_paths }
+    }
+
+    /// Adds a single path to the overlay as either subject or clip paths.
+    /// - `path`: A `F64Path` instance to be added.
+    /// - `shape_type`: Specifies the role of the added path in the overlay operation, either as `Subject` or `Clip`.
+    #[inline]
+    pub fn add_path(&mut self, path: F64Path, shape_type: ShapeType) {
+        match shape_type {
+            ShapeType::Subject => self.subj_paths.push(path),
+            ShapeType::Clip => self.clip_paths.push(path),
+        }
+    }
+
+    /// Adds a single shape to the overlay as either a subject or clip shape.
+    /// - `shape`: A `F64Shape` instance to be added.
+    /// - `shape_type`: Specifies the role of the added shape in the overlay operation, either as `Subject` or `Clip`.
+    #[inline]
+    pub fn add_shape(&mut self, shape: F64Shape, shape_type: ShapeType) {
+        let mut paths = shape;
+        match shape_type {
+            ShapeType::Subject => self.subj_paths.append(&mut paths),
+            ShapeType::Clip => self.clip_paths.append(&mut paths),
+        }
+    }
+
+    /// Convert into `I64F64OverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    ///
+    /// # Panics
+    /// If a point has a `NaN` or infinite coordinate or the graph can not be built, see `try_into_graph`.
+    #[inline(always)]
+    pub fn into_graph(self, fill_rule: FillRule) -> I64F64OverlayGraph {
+        self.into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `I64F64OverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If a point has a `NaN` or infinite coordinate or the graph can not be built, see `try_into_graph_with_solver`.
+    pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> I64F64OverlayGraph {
+        self.try_into_graph_with_solver(fill_rule, solver).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `into_graph`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - Returns: `OverlayError::NonFiniteInput` if a point has a `NaN` or infinite coordinate, or `OverlayError::TopologyFailure` if the graph can not be built.
+    #[inline(always)]
+    pub fn try_into_graph(self, fill_rule: FillRule) -> Result<I64F64OverlayGraph, OverlayError> {
+        self.try_into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Same as `into_graph_with_solver`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    /// - Returns: `OverlayError::NonFiniteInput` if a point has a `NaN` or infinite coordinate, or `OverlayError::TopologyFailure` if the graph can not be built.
+    pub fn try_into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> Result<I64F64OverlayGraph, OverlayError> {
+        let is_finite = self.subj_paths.iter()
+            .chain(self.clip_paths.iter())
+            .flatten()
+            .all(|p| p.x.is_finite() && p.y.is_finite());
+
+        if !is_finite {
+            return Err(OverlayError::NonFiniteInput);
+        }
+
+        let (overlay, adapter) = self.into_overlay();
+
+        let graph = overlay.try_into_graph_with_solver(fill_rule, solver)?;
+
+        Ok(I64F64OverlayGraph { graph, adapter })
+    }
+
+    /// Convert into `I64Overlay` from the added paths, all points are scaled to fit into `-I64Point::MAX_COORD..=I64Point::MAX_COORD`.
+    pub fn into_overlay(self) -> (I64Overlay, I64F64Adapter) {
+        let subj_rect = F64Rect::with_shape(&self.subj_paths);
+        let clip_rect = F64Rect::with_shape(&self.clip_paths);
+
+        let union_rect = F64Rect::with_optional_rects(subj_rect, clip_rect)
+            .unwrap_or(F64Rect {
+                min_x: -1.0,
+                max_x: 1.0,
+                min_y: -1.0,
+                max_y: 1.0,
+            });
+
+        let adapter = I64F64Adapter::new(union_rect);
+
+        let subj_paths: Vec<I64Path> = self.subj_paths.iter().map(|path| adapter.path_to_int(path)).collect();
+        let clip_paths: Vec<I64Path> = self.clip_paths.iter().map(|path| adapter.path_to_int(path)).collect();
+
+        (I64Overlay::with_paths(&subj_paths, &clip_paths), adapter)
+    }
+}
+
+/// The same as `F64OverlayGraph`, but for `I64F64Overlay`.
+pub struct I64F64OverlayGraph {
+    pub graph: I64OverlayGraph,
+    pub adapter: I64F64Adapter,
+}
+
+impl I64F64OverlayGraph {
+    /// Extracts shapes from the overlay graph based on the specified overlay rule, see `OverlayGraph::extract_shapes`.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    ///
+    /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
+    #[inline(always)]
+    pub fn extract_shapes(&self, overlay_rule: OverlayRule) -> F64Shapes {
+        self.extract_shapes_min_area(overlay_rule, 0.0)
+    }
+
+    /// Extracts shapes from the overlay graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
+    pub fn extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: f64) -> F64Shapes {
+        self.try_extract_shapes_min_area(overlay_rule, min_area)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `extract_shapes`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    #[inline(always)]
+    pub fn try_extract_shapes(&self, overlay_rule: OverlayRule) -> Result<F64Shapes, OverlayError> {
+        self.try_extract_shapes_min_area(overlay_rule, 0.0)
+    }
+
+    /// Same as `extract_shapes_min_area`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    pub fn try_extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: f64) -> Result<F64Shapes, OverlayError> {
+        let sqr_scale = self.adapter.dir_scale * self.adapter.dir_scale;
+        let area = (sqr_scale * min_area) as u128;
+        let shapes = self.graph.try_extract_shapes_min_area(overlay_rule, area)?;
+
+        Ok(self.adapter.shapes_to_float(shapes))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::core::error::OverlayError;
+use crate::core::extract::Validate;
+use crate::core::filter::Filter;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::id_point::IdPoint;
+use crate::i64::point::{I64Path, I64PathExtension, I64Point, I64Shapes};
+use crate::segm::segment::{Segment, SegmentFill, NONE};
+use crate::segm::shape_count::ShapeCount;
+
+/// The same as `OverlayGraph`, but for `i64` coordinates. It is created by `I64Overlay::into_graph`.
+pub struct I64OverlayGraph {
+    graph: OverlayGraph<I64Point>,
+}
+
+impl I64OverlayGraph {
+    /// The segments must be split and sorted, the segments with an empty fill are skipped.
+    pub(super) fn try_new(solver: Solver, segments: Vec<Segment<ShapeCount, I64Point>>, fills: Vec<SegmentFill>) -> Result<Self, OverlayError> {
+        let links = segments.into_iter().zip(fills)
+            .filter(|&(_, fill)| fill != NONE)
+            .map(|(segment, fill)| OverlayLink::new(
+                IdPoint { id: 0, point: segment.x_segment.a },
+                IdPoint { id: 0, point: segment.x_segment.b },
+                fill,
+            ))
+            .collect();
+
+        let graph = OverlayGraph::try_with_links(solver, links)?;
+
+        Ok(Self { graph })
+    }
+
+    /// Extracts shapes from the overlay graph based on the specified overlay rule, see `OverlayGraph::extract_shapes`.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of `I64Shape`, where the first path of each shape is the outer boundary and all subsequent paths are holes.
+    ///
+    /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes`.
+    #[inline(always)]
+    pub fn extract_shapes(&self, overlay_rule: OverlayRule) -> I64Shapes {
+        self.extract_shapes_min_area(overlay_rule, 0)
+    }
+
+    /// Extracts shapes from the overlay graph similar to `extract_shapes`, but with an additional constraint on the minimum area of the shapes.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for paths to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, see `try_extract_shapes_min_area`.
+    pub fn extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: u128) -> I64Shapes {
+        self.try_extract_shapes_min_area(overlay_rule, min_area)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `extract_shapes`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of `I64Shape` or `OverlayError::TopologyFailure` if the graph is inconsistent.
+    #[inline(always)]
+    pub fn try_extract_shapes(&self, overlay_rule: OverlayRule) -> Result<I64Shapes, OverlayError> {
+        self.try_extract_shapes_min_area(overlay_rule, 0)
+    }
+
+    /// Same as `extract_shapes_min_area`, but returns an error instead of panicking if a contour can not be traced.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for paths to be included in the result.
+    pub fn try_extract_shapes_min_area(&self, overlay_rule: OverlayRule, min_area: u128) -> Result<I64Shapes, OverlayError> {
+        let graph = &self.graph;
+        let mut visited = graph.links.filter(overlay_rule);
+        let link_indices = 0..visited.len();
+        let min_area = i128::try_from(min_area).unwrap_or(i128::MAX);
+        graph.try_extract_shapes_from(link_indices, &mut visited, |link_index| overlay_rule.is_fill_top(graph.link(link_index).fill), min_area)
+    }
+}
+
+impl Validate<i128> for I64Path {
+    fn validate(&mut self, min_area: i128) -> bool {
+        let path = self.to_simple();
+        let _ = std::mem::replace(self, path);
+
+        if self.is_empty() {
+            return false;
+        }
+
+        min_area == 0 || self.area_two().abs() >> 1 >= min_area
+    }
+}
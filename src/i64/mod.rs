@@ -0,0 +1,5 @@
+pub mod point;
+pub mod overlay;
+pub mod graph;
+pub mod float;
+pub(crate) mod cross;
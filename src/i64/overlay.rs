@@ -0,0 +1,163 @@
+use crate::core::error::OverlayError;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::i64::graph::I64OverlayGraph;
+use crate::i64::point::{I64Path, I64PathExtension, I64Point, I64Shape};
+use crate::segm::segment::{Segment, ShapeEdgesMerge};
+use crate::segm::shape_count::ShapeCount;
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+/// The same as `Overlay`, but for `i64` coordinates, so large-extent data can be processed without scaling.
+///
+/// All points must be in the range `-I64Point::MAX_COORD..=I64Point::MAX_COORD`, see `validate_range`.
+#[derive(Clone)]
+pub struct I64Overlay {
+    edges: Vec<Segment<ShapeCount, I64Point>>,
+    /// The first added point out of the range, its path is not added.
+    invalid_point: Option<I64Point>,
+}
+
+impl I64Overlay {
+    /// Constructs a new `I64Overlay` instance.
+    /// - `capacity`: The initial capacity for storing edge data, ideally the sum of the points of all paths.
+    pub fn new(capacity: usize) -> Self {
+        Self { edges: Vec::with_capacity(capacity), invalid_point: None }
+    }
+
+    /// Creates a new `I64Overlay` instance and initializes it with subject and clip paths.
+    /// - `subject_paths`: An array of paths that together define the subject shape.
+    /// - `clip_paths`: An array of paths that together define the clip shape.
+    pub fn with_paths(subject_paths: &[I64Path], clip_paths: &[I64Path]) -> Self {
+        let capacity = subject_paths.iter().chain(clip_paths.iter()).map(|path| path.len()).sum();
+        let mut overlay = Self::new(capacity);
+        overlay.add_paths(subject_paths, ShapeType::Subject);
+        overlay.add_paths(clip_paths, ShapeType::Clip);
+        overlay
+    }
+
+    /// Creates a new `I64Overlay` instance and initializes it with subject and clip shapes.
+    /// - `subject_shapes`: An array of shapes to be used as the subject in the overlay operation.
+    /// - `clip_shapes`: An array of shapes to be used as the clip in the overlay operation.
+    pub fn with_shapes(subject_shapes: &[I64Shape], clip_shapes: &[I64Shape]) -> Self {
+        let mut overlay = Self::new(0);
+        overlay.add_shapes(subject_shapes, ShapeType::Subject);
+        overlay.add_shapes(clip_shapes, ShapeType::Clip);
+        overlay
+    }
+
+    /// Adds a single path to the overlay as either subject or clip paths.
+    /// - `path`: A sequence of points which forms a closed path.
+    /// - `shape_type`: Specifies the role of the added path in the overlay operation, either as `Subject` or `Clip`.
+    ///
+    /// A path with a point out of the range is not added, it is reported by `validate_range` and `try_into_graph`.
+    pub fn add_path(&mut self, path: &[I64Point], shape_type: ShapeType) {
+        if let Some(&p) = path.iter().find(|p| !p.is_valid()) {
+            self.invalid_point.get_or_insert(p);
+            return;
+        }
+
+        let path = path.to_simple();
+        if path.is_empty() {
+            return;
+        }
+
+        let count = match shape_type {
+            ShapeType::Subject => ShapeCount::new(1, 0),
+            ShapeType::Clip => ShapeCount::new(0, 1),
+        };
+
+        let mut p0 = path[path.len() - 1];
+        for &p1 in path.iter() {
+            self.edges.push(Segment::create_and_validate(p0, p1, count));
+            p0 = p1;
+        }
+    }
+
+    /// Adds multiple paths to the overlay as either subject or clip paths.
+    /// - `paths`: An array of `I64Path` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added paths in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_paths(&mut self, paths: &[I64Path], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.add_path(path, shape_type);
+        }
+    }
+
+    /// Adds a single shape to the overlay as either a subject or clip shape.
+    /// - `shape`: A reference to a `I64Shape` instance to be added.
+    /// - `shape_type`: Specifies the role of the added shape in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shape(&mut self, shape: &I64Shape, shape_type: ShapeType) {
+        self.add_paths(shape, shape_type);
+    }
+
+    /// Adds multiple shapes to the overlay as either subject or clip shapes.
+    /// - `shapes`: An array of `I64Shape` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added shapes in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shapes(&mut self, shapes: &[I64Shape], shape_type: ShapeType) {
+        for shape in shapes.iter() {
+            self.add_paths(shape, shape_type);
+        }
+    }
+
+    /// Convert into `I64OverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    ///
+    /// # Panics
+    /// If a point is out of the range or the graph can not be built, see `try_into_graph`.
+    pub fn into_graph(self, fill_rule: FillRule) -> I64OverlayGraph {
+        self.into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `I64OverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If a point is out of the range or the graph can not be built, see `try_into_graph_with_solver`.
+    pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> I64OverlayGraph {
+        self.try_into_graph_with_solver(fill_rule, solver).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `into_graph`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - Returns: `OverlayError::I64CoordinateOverflow` if a point is out of the range, or `OverlayError::TopologyFailure` if the graph can not be built.
+    #[inline(always)]
+    pub fn try_into_graph(self, fill_rule: FillRule) -> Result<I64OverlayGraph, OverlayError> {
+        self.try_into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Same as `into_graph_with_solver`, but returns an error instead of panicking.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    /// - Returns: `OverlayError::I64CoordinateOverflow` if a point is out of the range, or `OverlayError::TopologyFailure` if the graph can not be built.
+    pub fn try_into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> Result<I64OverlayGraph, OverlayError> {
+        self.validate_range()?;
+
+        let mut segments = self.edges;
+        if segments.is_empty() {
+            return I64OverlayGraph::try_new(solver, segments, Vec::new());
+        }
+
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let fills = FillSolver::fill(&fill_rule, &solver, &segments);
+
+        I64OverlayGraph::try_new(solver, segments, fills)
+    }
+
+    /// Checks that all added points are in the range `-I64Point::MAX_COORD..=I64Point::MAX_COORD`.
+    /// Points out of this range can overflow the calculation of cross points.
+    /// - Returns: `OverlayError::I64CoordinateOverflow` with the first point out of the range.
+    pub fn validate_range(&self) -> Result<(), OverlayError> {
+        match self.invalid_point {
+            Some(p) => Err(OverlayError::I64CoordinateOverflow { x: p.x, y: p.y }),
+            None => Ok(()),
+        }
+    }
+}
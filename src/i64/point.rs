@@ -0,0 +1,193 @@
+use std::fmt;
+use crate::i64::cross::I64CrossSolver;
+use crate::overlay_point::OverlayPoint;
+use crate::segm::x_segment::XSegment;
+use crate::split::cross_solver::{CollinearMask, CrossResult};
+
+/// A point with `i64` coordinates. Used by `I64Overlay` to process data which does not fit into `IntPoint` without scaling.
+///
+/// Both coordinates must be in the range `-I64Point::MAX_COORD..=I64Point::MAX_COORD`, so all intermediate products fit into `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct I64Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A closed path of `I64Point`.
+pub type I64Path = Vec<I64Point>;
+
+/// A shape of `I64Path`, where the first path is the outer boundary and all subsequent paths are holes.
+pub type I64Shape = Vec<I64Path>;
+
+/// A set of `I64Shape`.
+pub type I64Shapes = Vec<I64Shape>;
+
+impl I64Point {
+    pub const ZERO: Self = Self { x: 0, y: 0 };
+
+    /// The maximum absolute value of a coordinate.
+    pub const MAX_COORD: i64 = 1 << 60;
+
+    #[inline(always)]
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    #[inline(always)]
+    pub(crate) fn sqr_distance(self, other: I64Point) -> i128 {
+        let x = self.x as i128 - other.x as i128;
+        let y = self.y as i128 - other.y as i128;
+        x * x + y * y
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_valid(&self) -> bool {
+        (-Self::MAX_COORD..=Self::MAX_COORD).contains(&self.x) && (-Self::MAX_COORD..=Self::MAX_COORD).contains(&self.y)
+    }
+}
+
+impl OverlayPoint for I64Point {
+    type Coord = i64;
+    type Product = i128;
+
+    const ZERO: Self = I64Point::ZERO;
+
+    #[inline(always)]
+    fn x(&self) -> i64 {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> i64 {
+        self.y
+    }
+
+    #[inline(always)]
+    fn area_two(p0: Self, p1: Self, p2: Self) -> i128 {
+        I64Triangle::area_two(p0, p1, p2)
+    }
+
+    #[inline(always)]
+    fn cross_product(c: Self, a: Self, b: Self) -> i128 {
+        I64Vec::new(c, a).cross_product(I64Vec::new(c, b))
+    }
+
+    #[inline(always)]
+    fn sqr_distance(self, other: Self) -> i128 {
+        I64Point::sqr_distance(self, other)
+    }
+
+    #[inline(always)]
+    fn cross(target: &XSegment<Self>, other: &XSegment<Self>, radius: i128) -> Option<CrossResult<Self>> {
+        I64CrossSolver::cross(target, other, radius)
+    }
+
+    #[inline(always)]
+    fn collinear(target: &XSegment<Self>, other: &XSegment<Self>) -> CollinearMask {
+        I64CrossSolver::collinear(target, other)
+    }
+}
+
+impl fmt::Display for I64Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+/// A vector between two `I64Point`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct I64Vec {
+    pub(crate) x: i128,
+    pub(crate) y: i128,
+}
+
+impl I64Vec {
+    #[inline(always)]
+    pub(crate) fn new(a: I64Point, b: I64Point) -> Self {
+        Self { x: b.x as i128 - a.x as i128, y: b.y as i128 - a.y as i128 }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cross_product(self, v: Self) -> i128 {
+        self.x * v.y - self.y * v.x
+    }
+
+    #[inline(always)]
+    pub(crate) fn dot_product(self, v: Self) -> i128 {
+        self.x * v.x + self.y * v.y
+    }
+}
+
+/// The same predicates as `Triangle` provides for `IntPoint`.
+pub(crate) struct I64Triangle;
+
+impl I64Triangle {
+    #[inline(always)]
+    pub(crate) fn area_two(p0: I64Point, p1: I64Point, p2: I64Point) -> i128 {
+        I64Vec::new(p0, p1).cross_product(I64Vec::new(p2, p1))
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_line(p0: I64Point, p1: I64Point, p2: I64Point) -> bool {
+        Self::area_two(p0, p1, p2) == 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn clock_direction(p0: I64Point, p1: I64Point, p2: I64Point) -> i128 {
+        Self::area_two(p0, p1, p2).signum()
+    }
+}
+
+pub(crate) trait I64PathExtension {
+    /// Twice the signed area of the path. It is positive for a clockwise path.
+    fn area_two(&self) -> i128;
+
+    /// Removes degenerate and collinear points. Returns an empty path if less than three points are left.
+    fn to_simple(&self) -> I64Path;
+}
+
+impl I64PathExtension for [I64Point] {
+    fn area_two(&self) -> i128 {
+        let mut area = 0;
+        let mut p0 = match self.last() {
+            Some(&p) => p,
+            None => return 0,
+        };
+        for &p1 in self.iter() {
+            area += (p1.x as i128 - p0.x as i128) * (p1.y as i128 + p0.y as i128);
+            p0 = p1;
+        }
+
+        area
+    }
+
+    fn to_simple(&self) -> I64Path {
+        let mut path: I64Path = Vec::with_capacity(self.len());
+        for &p in self.iter() {
+            path.push(p);
+            while path.len() >= 3 && I64Triangle::is_line(path[path.len() - 3], path[path.len() - 2], path[path.len() - 1]) {
+                path.remove(path.len() - 2);
+            }
+        }
+
+        // the joint between the last and the first point
+        let mut is_modified = true;
+        while is_modified && path.len() >= 3 {
+            is_modified = false;
+            let n = path.len();
+            if I64Triangle::is_line(path[n - 2], path[n - 1], path[0]) {
+                path.pop();
+                is_modified = true;
+            } else if I64Triangle::is_line(path[n - 1], path[0], path[1]) {
+                path.remove(0);
+                is_modified = true;
+            }
+        }
+
+        if path.len() < 3 {
+            path.clear();
+        }
+
+        path
+    }
+}
@@ -1,26 +1,27 @@
 use i_float::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
+use crate::overlay_point::OverlayPoint;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub(crate) struct IdPoint {
+pub(crate) struct IdPoint<P = IntPoint> {
     pub(crate) id: usize,
-    pub(crate) point: IntPoint,
+    pub(crate) point: P,
 }
 
-impl IdPoint {
-    pub(crate) fn new(id: usize, point: IntPoint) -> Self {
+impl<P> IdPoint<P> {
+    pub(crate) fn new(id: usize, point: P) -> Self {
         Self { id, point }
     }
 }
 
-impl BinKey for IdPoint {
+impl<P: OverlayPoint> BinKey for IdPoint<P> {
     #[inline(always)]
     fn key(&self) -> i64 {
-        self.point.x.into()
+        self.point.x().into()
     }
 
     #[inline(always)]
     fn bin(&self, layout: &BinLayout) -> usize {
-        layout.index(self.point.x.into())
+        layout.index(self.point.x().into())
     }
 }
\ No newline at end of file
@@ -0,0 +1,117 @@
+use std::cmp::Ordering;
+
+use i_float::triangle::Triangle;
+
+use crate::core::fill_rule::FillRule;
+use crate::fill::count_segment::CountSegment;
+use crate::fill::solver::FillStrategy;
+use crate::fill::solver_list::ScanFillList;
+use crate::segm::end::End;
+use crate::segm::segment::{Segment, SegmentFill};
+use crate::segm::shape_count::ShapeCount;
+
+/// Fills segments like `FillRule`, but also keeps the winding numbers above every segment.
+pub(super) struct TopCountStrategy(pub(super) FillRule);
+
+impl FillStrategy<ShapeCount, (ShapeCount, SegmentFill)> for TopCountStrategy {
+    #[inline(always)]
+    fn add_and_fill(&self, this: &ShapeCount, bot: &ShapeCount) -> (ShapeCount, (ShapeCount, SegmentFill)) {
+        let (top, fill) = self.0.add_and_fill(this, bot);
+        (top, (top, fill))
+    }
+}
+
+/// Fills the changed segments of an incremental graph.
+/// - `kept`: The unchanged segments around the changed ones sorted by `x_segment`, with the winding numbers above them in `kept_tops`.
+/// - `changed`: The changed segments sorted by `x_segment`.
+/// - Returns: The winding numbers above and the fill of every changed segment.
+///
+/// Only the unchanged segments which overlap the x range of the changed ones take part. Their winding numbers are not affected by the change,
+/// so the segments which start before this range are put into the scan list as they are, and the sweep starts at the first changed segment.
+pub(super) fn fill_band(fill_rule: &FillRule, kept: &[Segment], kept_tops: &[ShapeCount], changed: &[Segment]) -> Vec<(ShapeCount, SegmentFill)> {
+    let mut result = vec![(ShapeCount::default(), 0); changed.len()];
+    if changed.is_empty() {
+        return result;
+    }
+
+    let min_x = changed[0].x_segment.a.x;
+    let max_x = changed.iter().map(|s| s.x_segment.b.x).max().unwrap_or(min_x);
+
+    // merge both lists, indices of changed segments are shifted by `kept.len()`
+    let kept_band = kept.iter().enumerate()
+        .filter(|(_, s)| s.x_segment.a.x <= max_x && s.x_segment.b.x >= min_x)
+        .map(|(i, _)| i);
+
+    let n = kept.len();
+    let mut band: Vec<usize> = Vec::with_capacity(changed.len());
+    let mut changed_iter = (0..changed.len()).peekable();
+    for i in kept_band {
+        while let Some(&j) = changed_iter.peek() {
+            if changed[j].x_segment < kept[i].x_segment {
+                band.push(n + j);
+                changed_iter.next();
+            } else {
+                break;
+            }
+        }
+        band.push(i);
+    }
+    band.extend(changed_iter.map(|j| n + j));
+
+    let segment = |index: usize| if index < n { &kept[index] } else { &changed[index - n] };
+
+    let mut scan_list = ScanFillList::new(band.len());
+    let mut buf = Vec::with_capacity(4);
+
+    let mut i = 0;
+    let mut x0 = i32::MIN;
+
+    while i < band.len() {
+        let p = segment(band[i]).x_segment.a;
+        if p.x != x0 {
+            scan_list.clear(p.x);
+            x0 = p.x;
+        }
+
+        if p.x < min_x {
+            // an unchanged segment, it keeps its winding numbers
+            let index = band[i];
+            let s = &kept[index];
+            if s.x_segment.is_not_vertical() {
+                scan_list.insert(CountSegment { count: kept_tops[index], x_segment: s.x_segment });
+            }
+            i += 1;
+            continue;
+        }
+
+        while i < band.len() && segment(band[i]).x_segment.a == p {
+            buf.push(End { index: band[i], point: segment(band[i]).x_segment.b });
+            i += 1;
+        }
+
+        buf.sort_by(|s0, s1|
+        if Triangle::is_clockwise_point(p, s1.point, s0.point) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        });
+
+        let mut sum_count = scan_list.find_under_and_nearest(p);
+        let mut fill: SegmentFill;
+
+        for se in buf.iter() {
+            let s = segment(se.index);
+            (sum_count, fill) = fill_rule.add_and_fill(&s.count, &sum_count);
+            if se.index >= n {
+                result[se.index - n] = (sum_count, fill);
+            }
+            if s.x_segment.is_not_vertical() {
+                scan_list.insert(CountSegment { count: sum_count, x_segment: s.x_segment });
+            }
+        }
+
+        buf.clear();
+    }
+
+    result
+}
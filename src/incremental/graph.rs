@@ -0,0 +1,375 @@
+//! This module defines an overlay graph which can be edited after it is built. Added and removed paths
+//! only re-split and re-fill the segments around them, so a small edit of a large input stays cheap.
+
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::IntShape;
+
+use crate::core::error::OverlayError;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::{BuildEdges, Fill, ShapeType};
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::overlay_node::OverlayNode;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::id_point::IdPoint;
+use crate::incremental::fill::{fill_band, TopCountStrategy};
+use crate::line_range::LineRange;
+use crate::segm::end::End;
+use crate::segm::segment::{Segment, SegmentFill, ShapeEdgesMerge};
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
+use crate::segm::x_segment::XSegment;
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+/// An overlay graph which supports adding and removing paths after it is built.
+///
+/// The graph keeps the split segments together with the winding numbers around them in a sorted map. An edit only re-splits the segments
+/// which overlap the bounding box of the edited path and re-fills the segments in its x range, everything else is reused.
+/// Once the `OverlayGraph` is built by `graph`, every edit replaces its links and nodes in the edited x range in place.
+/// The links and nodes behind this range are only shifted and renumbered, so an edit of a built graph still costs O(N)
+/// in the size of the graph, though with a much smaller factor than building it again (see `benches/incremental.rs`).
+///
+/// Edges are split in the order of edits. If cross points have to be rounded, they can be rounded differently
+/// than by `Overlay::into_graph`, so for nearly collinear edges the result may differ by a unit.
+///
+/// Create it with `Overlay::into_incremental_graph`. Polylines are not supported.
+pub struct IncrementalOverlayGraph {
+    solver: Solver,
+    fill_rule: FillRule,
+    segments: BTreeMap<XSegment, SegmentState>,
+    // the largest x length of a segment, so a segment which reaches `x` starts not before `x - max_width`
+    max_width: i64,
+    graph: Option<OverlayGraph>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SegmentState {
+    count: ShapeCount,
+    // the winding numbers of the region above the segment
+    top: ShapeCount,
+    fill: SegmentFill,
+}
+
+impl IncrementalOverlayGraph {
+    pub(crate) fn build(solver: Solver, fill_rule: FillRule, segments: Vec<Segment>) -> Self {
+        let mut graph = Self { solver, fill_rule, segments: BTreeMap::new(), max_width: 0, graph: None };
+        if segments.is_empty() {
+            return graph;
+        }
+
+        let mut segments = segments;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let fills: Vec<(ShapeCount, SegmentFill)> = FillSolver::fill(&TopCountStrategy(fill_rule), &solver, &segments);
+
+        graph.insert(segments.into_iter().zip(fills));
+
+        graph
+    }
+
+    /// Adds a single path as either subject or clip path.
+    /// - `path`: A reference to a `IntPath` instance to be added.
+    /// - `shape_type`: Specifies the role of the added path in the overlay operation, either as `Subject` or `Clip`.
+    #[inline]
+    pub fn add_path(&mut self, path: &[IntPoint], shape_type: ShapeType) {
+        self.edit(path, shape_type, false);
+    }
+
+    /// Adds multiple paths as either subject or clip paths.
+    /// - `paths`: An array of `IntPath` instances to be added.
+    /// - `shape_type`: Specifies the role of the added paths in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.edit(path, shape_type, false);
+        }
+    }
+
+    /// Adds a single shape as either a subject or clip shape.
+    /// - `shape`: A reference to a `IntShape` instance to be added.
+    /// - `shape_type`: Specifies the role of the added shape in the overlay operation, either as `Subject` or `Clip`.
+    #[inline]
+    pub fn add_shape(&mut self, shape: &IntShape, shape_type: ShapeType) {
+        self.add_paths(shape, shape_type);
+    }
+
+    /// Removes a path which was added before with the same `shape_type`, either to the `Overlay` or to this graph.
+    /// - `path`: The removed path, it must have the same points and the same direction as the added one.
+    /// - `shape_type`: The role the path was added with.
+    ///
+    /// Note: The removed path is subtracted from the winding numbers, so removing a path which was never added leaves a path with the opposite winding.
+    /// Points where the removed path crossed other edges stay as vertices of these edges.
+    #[inline]
+    pub fn remove_path(&mut self, path: &[IntPoint], shape_type: ShapeType) {
+        self.edit(path, shape_type, true);
+    }
+
+    /// Removes multiple paths, see `remove_path`.
+    /// - `paths`: The removed paths.
+    /// - `shape_type`: The role the paths were added with.
+    pub fn remove_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.edit(path, shape_type, true);
+        }
+    }
+
+    /// Removes a shape, see `remove_path`.
+    /// - `shape`: The removed shape.
+    /// - `shape_type`: The role the shape was added with.
+    #[inline]
+    pub fn remove_shape(&mut self, shape: &IntShape, shape_type: ShapeType) {
+        self.remove_paths(shape, shape_type);
+    }
+
+    /// Returns the `OverlayGraph` for the current paths. It is built on the first call and then kept up to date by every edit.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn graph(&mut self) -> &OverlayGraph {
+        let solver = self.solver;
+        let segments = &self.segments;
+        self.graph.get_or_insert_with(|| {
+            let links = segments.iter()
+                .filter(|(_, state)| !state.fill.is_empty())
+                .map(|(x_segment, state)| link(x_segment, state.fill))
+                .collect();
+
+            OverlayGraph::with_links(solver, links)
+        })
+    }
+
+    fn edit(&mut self, path: &[IntPoint], shape_type: ShapeType, is_remove: bool) {
+        let mut edges = Vec::with_capacity(path.len());
+        edges.append_edges(path, shape_type);
+        if edges.is_empty() {
+            return;
+        }
+
+        if is_remove {
+            for edge in edges.iter_mut() {
+                edge.count = edge.count.invert();
+            }
+        }
+
+        // rounded cross points can move an edge by one unit
+        let rect = Rect::with_segments(&edges).inflated(1);
+
+        let removed: Vec<XSegment> = self.reaching(rect.min_x, rect.max_x)
+            .filter(|(x_segment, _)| !x_segment.is_not_intersect_y_range(&rect.y_range))
+            .map(|(&x_segment, _)| x_segment)
+            .collect();
+
+        // the x range where the changed segments start
+        let mut min_x = rect.min_x;
+        let mut max_x = rect.max_x;
+
+        let mut changed = edges;
+        for x_segment in removed {
+            if let Some(state) = self.segments.remove(&x_segment) {
+                min_x = min_x.min(x_segment.a.x);
+                changed.push(Segment { x_segment, count: state.count });
+            }
+        }
+
+        changed.smart_sort_by(&self.solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        changed.merge_if_needed();
+
+        SplitSolver::new(self.solver).split(&mut changed);
+
+        let (kept, kept_tops): (Vec<Segment>, Vec<ShapeCount>) = if let Some(first) = changed.first() {
+            let band_max_x = changed.iter().map(|s| s.x_segment.b.x).max().unwrap_or(max_x);
+            self.reaching(first.x_segment.a.x, band_max_x)
+                .map(|(&x_segment, state)| (Segment { x_segment, count: state.count }, state.top))
+                .unzip()
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        let changed_fills = fill_band(&self.fill_rule, &kept, &kept_tops, &changed);
+
+        for s in changed.iter() {
+            min_x = min_x.min(s.x_segment.a.x);
+            max_x = max_x.max(s.x_segment.a.x);
+        }
+
+        self.insert(changed.into_iter().zip(changed_fills));
+
+        if self.patch_graph(min_x, max_x).is_err() {
+            // the graph is built again by the next `graph` call
+            self.graph = None;
+        }
+    }
+
+    fn insert<I: IntoIterator<Item = (Segment, (ShapeCount, SegmentFill))>>(&mut self, segments: I) {
+        for (segment, (top, fill)) in segments {
+            let width = segment.x_segment.b.x as i64 - segment.x_segment.a.x as i64;
+            self.max_width = self.max_width.max(width);
+            self.segments.insert(segment.x_segment, SegmentState { count: segment.count, top, fill });
+        }
+    }
+
+    /// Returns the segments which have a point in the x range `min_x..=max_x`.
+    fn reaching(&self, min_x: i32, max_x: i32) -> impl Iterator<Item = (&XSegment, &SegmentState)> {
+        let start_x = (min_x as i64 - self.max_width).max(i32::MIN as i64) as i32;
+        starting(&self.segments, start_x, max_x).filter(move |(x_segment, _)| x_segment.b.x >= min_x)
+    }
+
+    /// Replaces the links of the graph which start in the x range `min_x..=max_x` with the current segments of this range.
+    /// The nodes of all points in the range of the replaced and the new links are rebuilt, the rest are only renumbered.
+    /// Shifting and renumbering the links and nodes behind the range is O(N) in the size of the graph.
+    fn patch_graph(&mut self, min_x: i32, max_x: i32) -> Result<(), OverlayError> {
+        let graph = match self.graph.as_mut() {
+            Some(graph) => graph,
+            None => return Ok(()),
+        };
+
+        let new_links: Vec<OverlayLink> = starting(&self.segments, min_x, max_x)
+            .filter(|(_, state)| !state.fill.is_empty())
+            .map(|(x_segment, state)| link(x_segment, state.fill))
+            .collect();
+
+        let lo = graph.links.partition_point(|link| link.a.point.x < min_x);
+        let hi = graph.links.partition_point(|link| link.a.point.x <= max_x);
+
+        // the nodes of all ends of the replaced and the new links
+        let max_end_x = graph.links[lo..hi].iter().chain(new_links.iter())
+            .map(|link| link.b.point.x)
+            .fold(max_x, i32::max);
+
+        let n0 = graph.node_partition(|point| point.x < min_x);
+        let n1 = graph.node_partition(|point| point.x <= max_end_x);
+
+        let old_link_count = hi - lo;
+        let new_link_count = new_links.len();
+        graph.links.splice(lo..hi, new_links);
+
+        // every link which has an end in the node range starts not before `min_x - max_width`
+        let start_x = min_x as i64 - self.max_width;
+        let w0 = graph.links.partition_point(|link| (link.a.point.x as i64) < start_x);
+        let w1 = graph.links.partition_point(|link| link.a.point.x <= max_end_x);
+
+        let mut ends = Vec::new();
+        for (index, link) in graph.links[w0..w1].iter().enumerate() {
+            for point in [link.a.point, link.b.point] {
+                if min_x <= point.x && point.x <= max_end_x {
+                    ends.push(End { index: w0 + index, point });
+                }
+            }
+        }
+        ends.sort_unstable_by(|e0, e1| e0.point.cmp(&e1.point).then(e0.index.cmp(&e1.index)));
+
+        let node_count = ends.iter().enumerate()
+            .filter(|&(i, end)| i == 0 || ends[i - 1].point != end.point)
+            .count();
+
+        let old_node_count = n1 - n0;
+        if node_count != old_node_count {
+            for link in graph.links.iter_mut() {
+                for id_point in [&mut link.a, &mut link.b] {
+                    if id_point.id >= n1 {
+                        id_point.id = id_point.id - old_node_count + node_count;
+                    }
+                }
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(node_count);
+        let mut indices = Vec::with_capacity(4);
+        let mut i = 0;
+        while i < ends.len() {
+            let point = ends[i].point;
+            let node_id = n0 + nodes.len();
+            while i < ends.len() && ends[i].point == point {
+                let index = ends[i].index;
+                let link = &mut graph.links[index];
+                if link.a.point == point {
+                    link.a.id = node_id;
+                } else {
+                    link.b.id = node_id;
+                }
+                indices.push(index);
+                i += 1;
+            }
+
+            if indices.len() < 2 {
+                return Err(OverlayError::TopologyFailure);
+            }
+
+            nodes.push(OverlayNode::new(indices.as_slice()));
+            indices.clear();
+        }
+
+        // the nodes behind the range only have links which are not before the replaced ones
+        if new_link_count != old_link_count {
+            for node in graph.nodes[n1..].iter_mut() {
+                let indices: &mut [usize] = match node {
+                    OverlayNode::Bridge(bridge) => bridge,
+                    OverlayNode::Cross(indices) => indices,
+                };
+                for index in indices.iter_mut() {
+                    if *index >= hi {
+                        *index = *index - old_link_count + new_link_count;
+                    }
+                }
+            }
+        }
+
+        graph.nodes.splice(n0..n1, nodes);
+
+        Ok(())
+    }
+}
+
+#[inline(always)]
+fn link(x_segment: &XSegment, fill: SegmentFill) -> OverlayLink {
+    OverlayLink::new(IdPoint { id: 0, point: x_segment.a }, IdPoint { id: 0, point: x_segment.b }, fill)
+}
+
+/// Returns the segments which start in the x range `min_x..=max_x`.
+fn starting(segments: &BTreeMap<XSegment, SegmentState>, min_x: i32, max_x: i32) -> btree_map::Range<'_, XSegment, SegmentState> {
+    let first = XSegment { a: IntPoint::new(min_x, i32::MIN), b: IntPoint::new(i32::MIN, i32::MIN) };
+    let last = XSegment { a: IntPoint::new(max_x, i32::MAX), b: IntPoint::new(i32::MAX, i32::MAX) };
+    segments.range(first..=last)
+}
+
+struct Rect {
+    min_x: i32,
+    max_x: i32,
+    y_range: LineRange,
+}
+
+impl Rect {
+    fn with_segments(segments: &[Segment]) -> Self {
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut y_range = LineRange { min: i32::MAX, max: i32::MIN };
+        for s in segments.iter() {
+            min_x = min_x.min(s.x_segment.a.x);
+            max_x = max_x.max(s.x_segment.b.x);
+            let range = s.x_segment.y_range();
+            y_range.min = y_range.min.min(range.min);
+            y_range.max = y_range.max.max(range.max);
+        }
+
+        Self { min_x, max_x, y_range }
+    }
+
+    #[inline]
+    fn inflated(self, radius: i32) -> Self {
+        Self {
+            min_x: self.min_x - radius,
+            max_x: self.max_x + radius,
+            y_range: LineRange { min: self.y_range.min - radius, max: self.y_range.max + radius },
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod graph;
+mod fill;
@@ -1,13 +1,13 @@
 //! # iOverlay
 //!
-//! The `iOverlay` library provides high-performance boolean operations on polygons, including union, intersection, difference, and xor. It is designed for applications that require precise polygon operations, such as computer graphics, CAD systems, and geographical information systems (GIS). By supporting both integer (i32) and floating-point (f32, f64) APIs, iOverlay offers flexibility and precision across diverse use cases.
+//! The `iOverlay` library provides high-performance boolean operations on polygons, including union, intersection, difference, and xor. It is designed for applications that require precise polygon operations, such as computer graphics, CAD systems, and geographical information systems (GIS). By supporting both integer (i32, i64) and floating-point (f32, f64) APIs, iOverlay offers flexibility and precision across diverse use cases.
 //!
 //! ## Features
 //! - **Operations**: union, intersection, difference, and exclusion.
 //! - **Polygons**: with holes, self-intersections, and multiple paths.
 //! - **Simplification**: removes degenerate vertices and merges collinear edges.
-//! - **Fill Rules**: even-odd and non-zero.
-//! - **Data Types**: Supports i32, f32, and f64 APIs.
+//! - **Fill Rules**: even-odd, non-zero, positive and negative.
+//! - **Data Types**: Supports i32, i64, f32, and f64 APIs.
 //!
 //! ## i32 Example
 //! Here's an example of performing a union operation between two polygons:
@@ -120,12 +120,20 @@ pub mod core;
 pub mod vector;
 pub mod f64;
 pub mod f32;
+pub mod i64;
+pub mod group;
+pub mod source;
+pub mod origin;
+pub mod coverage;
+pub mod line;
+pub mod incremental;
 
 pub(crate) mod split;
 pub(crate) mod bind;
 pub(crate) mod segm;
 pub(crate) mod line_range;
 pub(crate) mod id_point;
+pub(crate) mod overlay_point;
 
 mod util;
 mod sort;
@@ -0,0 +1,62 @@
+use crate::core::fill_rule::FillRule;
+use crate::fill::solver::FillStrategy;
+use crate::segm::segment::SegmentFill;
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
+
+/// The usual `ShapeCount` of a segment, together with the polyline edges lying on it.
+/// Polyline edges never change the winding, they are only split and classified.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LineCount {
+    pub(crate) shape: ShapeCount,
+    // the number of polyline edges on the segment
+    pub(crate) line: i32,
+    // the sum of polyline directions, positive if they mostly go from `a` to `b`
+    pub(crate) dir: i32,
+}
+
+impl LineCount {
+    #[inline(always)]
+    pub(crate) fn with_shape(shape: ShapeCount) -> Self {
+        Self { shape, line: 0, dir: 0 }
+    }
+
+    #[inline(always)]
+    pub(crate) fn with_line() -> Self {
+        Self { shape: ShapeCount::default(), line: 1, dir: 1 }
+    }
+}
+
+impl WindingCount for LineCount {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.shape.is_not_empty() || self.line != 0
+    }
+
+    #[inline(always)]
+    fn add(self, count: Self) -> Self {
+        let mut result = self;
+        result.apply(count);
+        result
+    }
+
+    #[inline(always)]
+    fn apply(&mut self, count: Self) {
+        self.shape.apply(count.shape);
+        self.line += count.line;
+        self.dir += count.dir;
+    }
+
+    #[inline(always)]
+    fn invert(self) -> Self {
+        Self { shape: self.shape.invert(), line: self.line, dir: -self.dir }
+    }
+}
+
+impl FillStrategy<LineCount, SegmentFill> for FillRule {
+    #[inline(always)]
+    fn add_and_fill(&self, this: &LineCount, bot: &LineCount) -> (LineCount, SegmentFill) {
+        let (shape, fill) = self.add_and_fill(&this.shape, &bot.shape);
+        (LineCount::with_shape(shape), fill)
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_rule::OverlayRule;
+
+/// The position of a polyline piece relative to the result of an overlay rule.
+/// - `Inside`: The piece lies inside the result.
+/// - `Outside`: The piece lies outside the result.
+/// - `Boundary`: The piece lies on the boundary of the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinePosition {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+impl OverlayGraph {
+    /// Extracts the pieces of the polylines added with `Overlay::add_polyline` which have the given position relative to the result of an overlay rule.
+    /// - `overlay_rule`: The boolean operation rule which defines the area, for example `OverlayRule::Subject` to clip polylines by the subject shapes.
+    /// - `position`: Which pieces to extract: inside, outside or on the boundary of the area.
+    /// - Returns: A vector of open paths. Pieces keep the direction of the input polylines and are joined where exactly two of them meet.
+    ///
+    /// A closed chain of pieces is returned with its first point repeated at the end.
+    /// Overlapping polyline edges are merged while the graph is built, so a piece covered by several polylines is returned once.
+    pub fn extract_lines(&self, overlay_rule: OverlayRule, position: LinePosition) -> Vec<IntPath> {
+        let lines: Vec<(IntPoint, IntPoint)> = self.lines.iter().filter(|line| {
+            let top = overlay_rule.is_inside_top(line.fill);
+            let bottom = overlay_rule.is_inside_bottom(line.fill);
+            match position {
+                LinePosition::Inside => top && bottom,
+                LinePosition::Outside => !top && !bottom,
+                LinePosition::Boundary => top != bottom,
+            }
+        }).map(|line| (line.start(), line.end())).collect();
+
+        join_lines(&lines)
+    }
+}
+
+/// Joins directed pieces `(start, end)` into polylines where exactly one piece comes into a point and one goes out of it.
+/// A closed chain is returned with its first point repeated at the end.
+pub(crate) fn join_lines(lines: &[(IntPoint, IntPoint)]) -> Vec<IntPath> {
+    // the pieces going out of a point and the number of pieces coming into it
+    let mut outgoing: HashMap<IntPoint, Vec<usize>> = HashMap::with_capacity(lines.len());
+    let mut incoming: HashMap<IntPoint, usize> = HashMap::with_capacity(lines.len());
+    for (index, &(start, end)) in lines.iter().enumerate() {
+        outgoing.entry(start).or_default().push(index);
+        *incoming.entry(end).or_default() += 1;
+    }
+
+    let next = |&(_, end): &(IntPoint, IntPoint)| -> Option<usize> {
+        let out = outgoing.get(&end)?;
+        if out.len() == 1 && incoming.get(&end) == Some(&1) {
+            Some(out[0])
+        } else {
+            None
+        }
+    };
+
+    let is_continuation = |&(start, _): &(IntPoint, IntPoint)| -> bool {
+        outgoing[&start].len() == 1 && incoming.get(&start) == Some(&1)
+    };
+
+    let mut visited = vec![false; lines.len()];
+    let mut paths = Vec::new();
+
+    // open chains start where the pieces can not be joined, the rest are closed chains
+    for pass in 0..2 {
+        for index in 0..lines.len() {
+            if visited[index] || pass == 0 && is_continuation(&lines[index]) {
+                continue;
+            }
+
+            let mut path = vec![lines[index].0];
+            let mut current = index;
+            loop {
+                visited[current] = true;
+                path.push(lines[current].1);
+                match next(&lines[current]) {
+                    Some(next_index) if !visited[next_index] => current = next_index,
+                    _ => break,
+                }
+            }
+
+            paths.push(path);
+        }
+    }
+
+    paths
+}
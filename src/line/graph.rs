@@ -0,0 +1,78 @@
+use i_float::point::IntPoint;
+
+use crate::core::error::OverlayError;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::Fill;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::id_point::IdPoint;
+use crate::line::count::LineCount;
+use crate::segm::segment::{Segment, SegmentFill, ShapeEdgesMerge};
+use crate::segm::winding_count::WindingCount;
+use crate::segm::x_segment::XSegment;
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+
+/// A piece of an input polyline after splitting. It is not a part of any contour, it only knows the fill around it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct OverlayLine {
+    pub(crate) x_segment: XSegment,
+    pub(crate) fill: SegmentFill,
+    pub(crate) is_direct: bool,
+}
+
+impl OverlayLine {
+    #[inline(always)]
+    pub(crate) fn start(&self) -> IntPoint {
+        if self.is_direct { self.x_segment.a } else { self.x_segment.b }
+    }
+
+    #[inline(always)]
+    pub(crate) fn end(&self) -> IntPoint {
+        if self.is_direct { self.x_segment.b } else { self.x_segment.a }
+    }
+}
+
+impl OverlayGraph {
+    /// Builds the graph for polygon edges together with polyline edges. Both are split against each other, but only the polygon edges become links.
+    pub(crate) fn with_lines(solver: Solver, fill_rule: FillRule, segments: Vec<Segment<LineCount>>) -> Self {
+        Self::try_with_lines(solver, fill_rule, segments).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `with_lines`, but returns an error instead of panicking if the graph can not be built.
+    pub(crate) fn try_with_lines(solver: Solver, fill_rule: FillRule, segments: Vec<Segment<LineCount>>) -> Result<Self, OverlayError> {
+        let mut segments = segments;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let fills: Vec<SegmentFill> = FillSolver::fill(&fill_rule, &solver, &segments);
+
+        let mut links = Vec::with_capacity(segments.len());
+        let mut lines = Vec::new();
+
+        for (segment, fill) in segments.into_iter().zip(fills) {
+            if segment.count.line != 0 {
+                lines.push(OverlayLine { x_segment: segment.x_segment, fill, is_direct: segment.count.dir >= 0 });
+            }
+
+            // a polyline alone never bounds a region
+            if segment.count.shape.is_not_empty() && !fill.is_empty() {
+                links.push(OverlayLink::new(
+                    IdPoint::new(0, segment.x_segment.a),
+                    IdPoint::new(0, segment.x_segment.b),
+                    fill,
+                ));
+            }
+        }
+
+        let mut graph = OverlayGraph::try_with_links(solver, links)?;
+        graph.lines = lines;
+
+        Ok(graph)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod extract;
+pub mod slice;
+pub(crate) mod count;
+pub(crate) mod graph;
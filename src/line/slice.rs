@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use i_float::point::IntPoint;
+use i_shape::int::path::{IntPath, PointPathExtension};
+use i_shape::int::shape::{IntShape, IntShapes};
+
+use crate::core::error::OverlayError;
+use crate::core::extract::{bind_holes, Validate};
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::overlay_node::OverlayNode;
+use crate::core::overlay_rule::OverlayRule;
+use crate::id_point::IdPoint;
+
+/// The pieces of shapes cut by polylines, see `OverlayGraph::extract_slices`.
+/// - `left`: Pieces which touch the cutting polylines only from their left side.
+/// - `right`: Pieces which touch the cutting polylines only from their right side.
+/// - `rest`: Pieces which are not touched by any cut, or touched from both sides.
+///
+/// Left and right are taken relative to the direction of the polylines.
+#[derive(Debug, Clone, Default)]
+pub struct Slices<S = IntShapes> {
+    pub left: S,
+    pub right: S,
+    pub rest: S,
+}
+
+const TOP: usize = 0;
+const BOTTOM: usize = 1;
+
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+
+#[derive(Debug, Clone, Copy)]
+struct SliceLink {
+    link: OverlayLink,
+    // which sides are inside the sliced area, indexed by `TOP` and `BOTTOM`
+    inside: [bool; 2],
+    // for a cut: whether the polyline goes from `a` to `b`
+    cut: Option<bool>,
+}
+
+impl OverlayGraph {
+    /// Cuts the result of an overlay rule by the polylines added with `Overlay::add_polyline`.
+    /// - `overlay_rule`: The boolean operation rule which defines the area to cut, for example `OverlayRule::Subject`.
+    /// - Returns: `Slices` with the pieces on the left and on the right side of the polylines.
+    ///
+    /// Only the polyline pieces which lie inside the area cut it. A piece which ends inside the area without reaching its boundary or another cut is ignored, so a polyline must cross the area to split it.
+    /// The cut edges of neighbour pieces have exactly the same points.
+    ///
+    /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If the pieces can not be traced, see `try_extract_slices`.
+    pub fn extract_slices(&self, overlay_rule: OverlayRule) -> Slices {
+        self.try_extract_slices(overlay_rule).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `extract_slices`, but returns `OverlayError::TopologyFailure` if a piece can not be closed, which is only possible for an inconsistent graph.
+    pub fn try_extract_slices(&self, overlay_rule: OverlayRule) -> Result<Slices, OverlayError> {
+        let mut items = Vec::with_capacity(self.links.len() + self.lines.len());
+
+        for link in self.links.iter() {
+            let top = overlay_rule.is_inside_top(link.fill);
+            let bottom = overlay_rule.is_inside_bottom(link.fill);
+            if top != bottom {
+                items.push(SliceLink { link: *link, inside: [top, bottom], cut: None });
+            }
+        }
+
+        for line in self.lines.iter() {
+            if overlay_rule.is_inside_top(line.fill) && overlay_rule.is_inside_bottom(line.fill) {
+                let link = OverlayLink::new(
+                    IdPoint::new(0, line.x_segment.a),
+                    IdPoint::new(0, line.x_segment.b),
+                    line.fill,
+                );
+                items.push(SliceLink { link, inside: [true, true], cut: Some(line.is_direct) });
+            }
+        }
+
+        items.remove_dangling_cuts();
+
+        if items.is_empty() {
+            return Ok(Slices::default());
+        }
+
+        items.sort_unstable_by(|s0, s1| s0.link.a.point.cmp(&s1.link.a.point)
+            .then(s0.link.b.point.cmp(&s1.link.b.point))
+        );
+
+        let links = items.iter().map(|item| item.link).collect();
+        let graph = OverlayGraph::try_with_links(self.solver, links)?;
+
+        graph.slice(&items)
+    }
+
+    fn slice(&self, items: &[SliceLink]) -> Result<Slices, OverlayError> {
+        let mut used = vec![[false; 2]; items.len()];
+        let mut shapes: Vec<IntShape> = Vec::new();
+        let mut shape_sides = Vec::new();
+        let mut holes: Vec<IntPath> = Vec::new();
+        let mut hole_sides = Vec::new();
+
+        for link_index in 0..items.len() {
+            for side in [TOP, BOTTOM] {
+                if !items[link_index].inside[side] || used[link_index][side] {
+                    continue;
+                }
+
+                let (mut path, sides) = self.get_face_path(link_index, side, items, &mut used).ok_or(OverlayError::TopologyFailure)?;
+
+                if !path.validate(0) {
+                    continue;
+                }
+
+                if path.unsafe_area() > 0 {
+                    shapes.push(vec![path]);
+                    shape_sides.push(sides);
+                } else {
+                    holes.push(path);
+                    hole_sides.push(sides);
+                }
+            }
+        }
+
+        if !shapes.is_empty() && !holes.is_empty() {
+            let solution = bind_holes(&self.solver, &shapes, &holes);
+            for (hole, (shape_index, sides)) in holes.into_iter()
+                .zip(solution.parent_for_child.into_iter().zip(hole_sides)) {
+                shapes[shape_index].push(hole);
+                shape_sides[shape_index] |= sides;
+            }
+        }
+
+        let mut slices: Slices = Slices::default();
+        for (shape, sides) in shapes.into_iter().zip(shape_sides) {
+            match sides {
+                LEFT => slices.left.push(shape),
+                RIGHT => slices.right.push(shape),
+                _ => slices.rest.push(shape),
+            }
+        }
+
+        Ok(slices)
+    }
+
+    /// Walks around a face keeping it on the right side, starting from the given side of a link.
+    /// Returns the contour and the sides of the cuts which the face touches, or `None` if the tour is broken.
+    fn get_face_path(&self, start_link: usize, start_side: usize, items: &[SliceLink], used: &mut [[bool; 2]]) -> Option<(IntPath, u8)> {
+        let link = self.link(start_link);
+        // moving from `a` to `b` keeps the bottom side on the right
+        let (begin, mut node_id) = if start_side == BOTTOM {
+            (link.a.point, link.b.id)
+        } else {
+            (link.b.point, link.a.id)
+        };
+
+        let mut path = IntPath::new();
+        path.push(begin);
+        used[start_link][start_side] = true;
+
+        let mut link_id = start_link;
+        let mut sides = items[start_link].cut_side(start_side == BOTTOM);
+
+        loop {
+            let next_id = match self.node(node_id) {
+                OverlayNode::Bridge(bridge) => {
+                    if bridge[0] == link_id { bridge[1] } else { bridge[0] }
+                }
+                OverlayNode::Cross(indices) => {
+                    self.find_nearest_counter_wise_link_by(link_id, node_id, indices, |index| {
+                        if index == link_id {
+                            return false;
+                        }
+                        let side = right_side(self.link(index), node_id);
+                        let is_start = index == start_link && side == start_side;
+                        items[index].inside[side] && (!used[index][side] || is_start)
+                    })?
+                }
+            };
+
+            let next = self.link(next_id);
+            let is_forward = next.a.id == node_id;
+            let side = if is_forward { BOTTOM } else { TOP };
+
+            if next_id == start_link && side == start_side {
+                break;
+            }
+
+            let (point, next_node_id) = if is_forward {
+                (next.a.point, next.b.id)
+            } else {
+                (next.b.point, next.a.id)
+            };
+
+            path.push(point);
+            used[next_id][side] = true;
+            sides |= items[next_id].cut_side(is_forward);
+
+            link_id = next_id;
+            node_id = next_node_id;
+        }
+
+        Some((path, sides))
+    }
+}
+
+/// The side of a link which is on the right when leaving the node.
+#[inline(always)]
+fn right_side(link: &OverlayLink, node_id: usize) -> usize {
+    if link.a.id == node_id { BOTTOM } else { TOP }
+}
+
+impl SliceLink {
+    /// The side of the polyline where the face is, if the link is a cut walked in the given direction.
+    #[inline(always)]
+    fn cut_side(&self, is_forward: bool) -> u8 {
+        match self.cut {
+            Some(is_direct) => if is_direct == is_forward { RIGHT } else { LEFT },
+            None => 0,
+        }
+    }
+}
+
+trait RemoveDanglingCuts {
+    fn remove_dangling_cuts(&mut self);
+}
+
+impl RemoveDanglingCuts for Vec<SliceLink> {
+    fn remove_dangling_cuts(&mut self) {
+        let mut degree: HashMap<IntPoint, usize> = HashMap::with_capacity(2 * self.len());
+        for item in self.iter() {
+            *degree.entry(item.link.a.point).or_default() += 1;
+            *degree.entry(item.link.b.point).or_default() += 1;
+        }
+
+        // a cut with a free end does not split anything, remove it until no free ends are left
+        let mut is_removed = vec![false; self.len()];
+        let mut is_modified = true;
+        while is_modified {
+            is_modified = false;
+            for (index, item) in self.iter().enumerate() {
+                if is_removed[index] || item.cut.is_none() {
+                    continue;
+                }
+                let a = item.link.a.point;
+                let b = item.link.b.point;
+                if degree[&a] == 1 || degree[&b] == 1 {
+                    is_removed[index] = true;
+                    is_modified = true;
+                    *degree.get_mut(&a).unwrap() -= 1;
+                    *degree.get_mut(&b).unwrap() -= 1;
+                }
+            }
+        }
+
+        let mut index = 0;
+        self.retain(|_| {
+            let keep = !is_removed[index];
+            index += 1;
+            keep
+        });
+    }
+}
@@ -1,12 +1,14 @@
+use crate::overlay_point::OverlayCoord;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct LineRange {
-    pub(crate) min: i32,
-    pub(crate) max: i32,
+pub(crate) struct LineRange<T = i32> {
+    pub(crate) min: T,
+    pub(crate) max: T,
 }
 
-impl LineRange {
+impl<T: OverlayCoord> LineRange<T> {
     #[inline(always)]
     pub(crate) fn width(&self) -> i64 {
-        self.max as i64 - self.min as i64
+        self.max.to_i64() - self.min.to_i64()
     }
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+use crate::core::fill_rule::FillRule;
+use crate::fill::solver::FillStrategy;
+use crate::origin::edge::EdgeOrigin;
+use crate::segm::segment::SegmentFill;
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
+
+/// The usual `ShapeCount` of a segment, together with the input edges it comes from.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OriginCount {
+    pub(crate) count: ShapeCount,
+    pub(crate) origins: Vec<EdgeOrigin>,
+}
+
+impl OriginCount {
+    #[inline]
+    pub(crate) fn with_origin(count: ShapeCount, origin: EdgeOrigin) -> Self {
+        Self { count, origins: vec![origin] }
+    }
+}
+
+impl WindingCount for OriginCount {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        self.count.is_not_empty()
+    }
+
+    #[inline]
+    fn add(self, count: Self) -> Self {
+        let mut result = self;
+        result.apply(count);
+        result
+    }
+
+    #[inline]
+    fn apply(&mut self, count: Self) {
+        self.count.apply(count.count);
+        if count.origins.is_empty() {
+            return;
+        }
+
+        if self.origins.is_empty() {
+            self.origins = count.origins;
+            return;
+        }
+
+        // both lists are sorted, so they are merged without sorting again
+        let a = std::mem::take(&mut self.origins);
+        let b = count.origins;
+        let mut origins = Vec::with_capacity(a.len() + b.len());
+        let mut j = 0;
+        for origin in a.into_iter() {
+            while j < b.len() && b[j].key() < origin.key() {
+                origins.push(b[j]);
+                j += 1;
+            }
+            origins.push(origin);
+        }
+        origins.extend_from_slice(&b[j..]);
+
+        self.origins = origins;
+    }
+
+    #[inline]
+    fn invert(self) -> Self {
+        Self { count: self.count.invert(), origins: self.origins }
+    }
+}
+
+impl FillStrategy<OriginCount, SegmentFill> for FillRule {
+    #[inline(always)]
+    fn add_and_fill(&self, this: &OriginCount, bot: &OriginCount) -> (OriginCount, SegmentFill) {
+        // the running sum only needs the winding, origins stay on the segments
+        let (count, fill) = self.add_and_fill(&this.count, &bot.count);
+        (OriginCount { count, origins: Vec::new() }, fill)
+    }
+}
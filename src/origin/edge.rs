@@ -0,0 +1,37 @@
+use i_float::point::IntPoint;
+
+use crate::core::overlay::ShapeType;
+use crate::vector::edge::SideFill;
+
+pub type OriginPath = Vec<OriginEdge>;
+pub type OriginShape = Vec<OriginPath>;
+
+/// Identifies an edge of an input path.
+/// - `shape_type`: The role of the input path, either `Subject` or `Clip`.
+/// - `path`: The index of the input path among the paths of the same type, in the order they were added.
+/// - `edge`: The index of the edge inside the input path, where edge `i` goes from point `i` to point `i + 1` (the last edge closes the path).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeOrigin {
+    pub shape_type: ShapeType,
+    pub path: usize,
+    pub edge: usize,
+}
+
+impl EdgeOrigin {
+    #[inline(always)]
+    pub(crate) fn key(&self) -> (bool, usize, usize) {
+        (matches!(self.shape_type, ShapeType::Clip), self.path, self.edge)
+    }
+}
+
+/// An edge of an extracted contour together with the input edges it lies on.
+///
+/// `origins` is sorted: subject edges come before clip edges, then by path and edge index.
+/// It contains more than one item when input edges overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginEdge {
+    pub a: IntPoint,
+    pub b: IntPoint,
+    pub fill: SideFill,
+    pub origins: Vec<EdgeOrigin>,
+}
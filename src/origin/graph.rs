@@ -0,0 +1,98 @@
+//! This module defines the graph built from an `OriginOverlay`. Every link keeps the input edges it
+//! lies on, so the edges of extracted contours can be traced back to the input paths.
+
+use std::cmp::Ordering;
+
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::Fill;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::id_point::IdPoint;
+use crate::origin::count::OriginCount;
+use crate::origin::edge::{EdgeOrigin, OriginEdge, OriginShape};
+use crate::segm::segment::{Segment, SegmentFill, ShapeEdgesMerge};
+use crate::sort::SmartSort;
+use crate::split::solver::SplitSolver;
+use crate::vector::edge::VectorEdge;
+
+/// An `OverlayGraph` which also knows the input edges behind every link.
+///
+/// Create it with `OriginOverlay::into_graph`.
+///
+/// The origins are kept beside the links instead of inside `OverlayLink`, so the links of a usual `OverlayGraph` stay small and `Copy`.
+pub struct OriginOverlayGraph {
+    graph: OverlayGraph,
+    origins: Vec<Vec<EdgeOrigin>>,
+}
+
+impl OriginOverlayGraph {
+    pub(crate) fn build(solver: Solver, fill_rule: FillRule, segments: Vec<Segment<OriginCount>>) -> Self {
+        if segments.is_empty() {
+            return Self { graph: OverlayGraph::with_links(solver, Vec::new()), origins: Vec::new() };
+        }
+
+        let mut segments = segments;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let fills: Vec<SegmentFill> = FillSolver::fill(&fill_rule, &solver, &segments);
+
+        let mut links = Vec::with_capacity(segments.len());
+        let mut origins = Vec::with_capacity(segments.len());
+
+        for (segment, fill) in segments.into_iter().zip(fills) {
+            if fill.is_empty() {
+                continue;
+            }
+
+            links.push(OverlayLink::new(
+                IdPoint::new(0, segment.x_segment.a),
+                IdPoint::new(0, segment.x_segment.b),
+                fill,
+            ));
+            origins.push(segment.count.origins);
+        }
+
+        let graph = OverlayGraph::with_links(solver, links);
+
+        Self { graph, origins }
+    }
+
+    /// Extracts shapes as vectors like `OverlayGraph::extract_shape_vectors`, where every edge also carries the input edges it lies on.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of `OriginShape`, where the first path of each shape is the outer boundary (clockwise) and the rest are holes (counterclockwise).
+    ///
+    /// A contour edge may be only a part of an input edge, if the input edge was split by an intersection.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shape_origins(&self, overlay_rule: OverlayRule) -> Vec<OriginShape> {
+        self.graph.extract_shape_vectors(overlay_rule).into_iter()
+            .map(|shape| shape.into_iter()
+                .map(|path| path.into_iter()
+                    .map(|edge| self.origin_edge(edge))
+                    .collect()
+                ).collect()
+            ).collect()
+    }
+
+    fn origin_edge(&self, edge: VectorEdge) -> OriginEdge {
+        let (a, b) = if edge.a < edge.b { (edge.a, edge.b) } else { (edge.b, edge.a) };
+
+        // links are sorted by their points and every pair of points is unique
+        let index = self.graph.links.binary_search_by(|link| {
+            match link.a.point.cmp(&a) {
+                Ordering::Equal => link.b.point.cmp(&b),
+                order => order
+            }
+        }).unwrap();
+
+        OriginEdge { a: edge.a, b: edge.b, fill: edge.fill, origins: self.origins[index].clone() }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod edge;
+pub mod overlay;
+pub mod graph;
+pub(crate) mod count;
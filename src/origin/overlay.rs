@@ -0,0 +1,150 @@
+//! This module contains functionality to construct overlays which remember the input edge behind
+//! every segment. Unlike `Overlay`, paths are not simplified, so edge indices always refer to the
+//! paths exactly as they were added.
+//!
+//! The origins go through the same sorting, `merge_if_needed`, splitting and filling as the usual
+//! segments, only with `OriginCount` instead of `ShapeCount`. It is a separate builder, so `Overlay`
+//! does not pay for a list of origins on every segment.
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::{IntShape, PointsCount};
+
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::origin::count::OriginCount;
+use crate::origin::edge::EdgeOrigin;
+use crate::origin::graph::OriginOverlayGraph;
+use crate::segm::segment::Segment;
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::x_segment::XSegment;
+
+/// This struct describes the geometry of an overlay like `Overlay` does, but every edge keeps its `EdgeOrigin`.
+/// Use it when attributes of the input edges must be transferred to the edges of the result.
+#[derive(Clone)]
+pub struct OriginOverlay {
+    edges: Vec<Segment<OriginCount>>,
+    subj_count: usize,
+    clip_count: usize,
+}
+
+impl OriginOverlay {
+    /// Constructs a new `OriginOverlay` instance.
+    /// - `capacity`: The initial capacity for storing edge data. Ideally, this should be set to the sum of the edges of all shapes to be added to the overlay.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            edges: Vec::with_capacity(capacity),
+            subj_count: 0,
+            clip_count: 0,
+        }
+    }
+
+    /// Creates a new `OriginOverlay` instance and initializes it with subject and clip paths.
+    /// - `subject_paths`: An array of paths that together define the subject shape.
+    /// - `clip_paths`: An array of paths that together define the clip shape.
+    pub fn with_paths(subject_paths: &[IntPath], clip_paths: &[IntPath]) -> Self {
+        let mut overlay = Self::new(subject_paths.points_count() + clip_paths.points_count());
+        overlay.add_paths(subject_paths, ShapeType::Subject);
+        overlay.add_paths(clip_paths, ShapeType::Clip);
+        overlay
+    }
+
+    /// Creates a new `OriginOverlay` instance and initializes it with subject and clip shapes.
+    /// - `subject_shapes`: An array of shapes to be used as the subject in the overlay operation.
+    /// - `clip_shapes`: An array of shapes to be used as the clip in the overlay operation.
+    pub fn with_shapes(subject_shapes: &[IntShape], clip_shapes: &[IntShape]) -> Self {
+        let mut overlay = Self::new(subject_shapes.points_count() + clip_shapes.points_count());
+        overlay.add_shapes(subject_shapes, ShapeType::Subject);
+        overlay.add_shapes(clip_shapes, ShapeType::Clip);
+        overlay
+    }
+
+    /// Adds a single path to the overlay as either subject or clip path.
+    /// The path gets the next index among the paths of the same type, which is reported in `EdgeOrigin::path`.
+    /// - `path`: A reference to a `IntPath` instance to be added.
+    /// - `shape_type`: Specifies the role of the added path in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_path(&mut self, path: &[IntPoint], shape_type: ShapeType) {
+        let index = match shape_type {
+            ShapeType::Subject => {
+                self.subj_count += 1;
+                self.subj_count - 1
+            }
+            ShapeType::Clip => {
+                self.clip_count += 1;
+                self.clip_count - 1
+            }
+        };
+
+        if path.len() > 2 {
+            self.append_edges(path, shape_type, index);
+        }
+    }
+
+    /// Adds multiple paths to the overlay as either subject or clip paths.
+    /// - `paths`: An array of `IntPath` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added paths in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.add_path(path, shape_type);
+        }
+    }
+
+    /// Adds a single shape to the overlay as either a subject or clip shape. Every contour of the shape is added as a separate path.
+    /// - `shape`: A reference to a `IntShape` instance to be added.
+    /// - `shape_type`: Specifies the role of the added shape in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shape(&mut self, shape: &IntShape, shape_type: ShapeType) {
+        self.add_paths(shape, shape_type);
+    }
+
+    /// Adds multiple shapes to the overlay as either subject or clip shapes.
+    /// - `shapes`: An array of `IntShape` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added shapes in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shapes(&mut self, shapes: &[IntShape], shape_type: ShapeType) {
+        for shape in shapes.iter() {
+            self.add_paths(shape, shape_type);
+        }
+    }
+
+    /// Convert into `OriginOverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph(self, fill_rule: FillRule) -> OriginOverlayGraph {
+        self.into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `OriginOverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> OriginOverlayGraph {
+        OriginOverlayGraph::build(solver, fill_rule, self.edges)
+    }
+
+    fn append_edges(&mut self, path: &[IntPoint], shape_type: ShapeType, index: usize) {
+        let (direct, invert) = match shape_type {
+            ShapeType::Subject => (ShapeCount::new(1, 0), ShapeCount::new(-1, 0)),
+            ShapeType::Clip => (ShapeCount::new(0, 1), ShapeCount::new(0, -1)),
+        };
+
+        let n = path.len();
+        for (i, &p0) in path.iter().enumerate() {
+            let p1 = path[(i + 1) % n];
+            if p0 == p1 {
+                continue;
+            }
+
+            let origin = EdgeOrigin { shape_type, path: index, edge: i };
+            let segment = if p0 < p1 {
+                Segment { x_segment: XSegment { a: p0, b: p1 }, count: OriginCount::with_origin(direct, origin) }
+            } else {
+                Segment { x_segment: XSegment { a: p1, b: p0 }, count: OriginCount::with_origin(invert, origin) }
+            };
+            self.edges.push(segment);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use i_float::point::IntPoint;
+use i_float::triangle::Triangle;
+use crate::segm::x_segment::XSegment;
+use crate::split::cross_solver::{CollinearMask, CrossResult, CrossSolver};
+
+/// A coordinate of `OverlayPoint`. The sum of any two coordinates must fit into `i64`.
+pub(crate) trait OverlayCoord: Copy + Ord + Debug + Send + Sync + Into<i64> {
+    const BITS: u32;
+
+    /// Converts a value which is known to be in the range of the coordinate.
+    fn from_i64(value: i64) -> Self;
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        self.into()
+    }
+}
+
+impl OverlayCoord for i32 {
+    const BITS: u32 = i32::BITS;
+
+    #[inline(always)]
+    fn from_i64(value: i64) -> Self {
+        value as i32
+    }
+}
+
+impl OverlayCoord for i64 {
+    const BITS: u32 = i64::BITS;
+
+    #[inline(always)]
+    fn from_i64(value: i64) -> Self {
+        value
+    }
+}
+
+/// A point which the split, fill and bind solvers work with. It is `IntPoint` for `Overlay` and `I64Point` for `I64Overlay`.
+pub(crate) trait OverlayPoint: Copy + Ord + Debug + Send + Sync {
+    type Coord: OverlayCoord;
+
+    /// The type of a product of two coordinate differences, such as a cross product or a squared distance.
+    type Product: Copy + Ord + Debug + Default + Send + Sync + From<i64>;
+
+    const ZERO: Self;
+
+    fn x(&self) -> Self::Coord;
+
+    fn y(&self) -> Self::Coord;
+
+    /// The doubled signed area of the triangle, it is positive if the points go clockwise.
+    fn area_two(p0: Self, p1: Self, p2: Self) -> Self::Product;
+
+    /// The cross product of the vectors `a - c` and `b - c`.
+    fn cross_product(c: Self, a: Self, b: Self) -> Self::Product;
+
+    fn sqr_distance(self, other: Self) -> Self::Product;
+
+    /// Finds the cross of two segments, see `CrossSolver::cross`.
+    fn cross(target: &XSegment<Self>, other: &XSegment<Self>, radius: Self::Product) -> Option<CrossResult<Self>>;
+
+    /// Finds the ends of two collinear segments which lie inside the other segment, see `CrossSolver::collinear`.
+    fn collinear(target: &XSegment<Self>, other: &XSegment<Self>) -> CollinearMask;
+
+    #[inline(always)]
+    fn is_clockwise(p0: Self, p1: Self, p2: Self) -> bool {
+        Self::area_two(p0, p1, p2) > Self::Product::default()
+    }
+}
+
+impl OverlayPoint for IntPoint {
+    type Coord = i32;
+    type Product = i64;
+
+    const ZERO: Self = IntPoint::ZERO;
+
+    #[inline(always)]
+    fn x(&self) -> i32 {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> i32 {
+        self.y
+    }
+
+    #[inline(always)]
+    fn area_two(p0: Self, p1: Self, p2: Self) -> i64 {
+        Triangle::area_two_point(p0, p1, p2)
+    }
+
+    #[inline(always)]
+    fn cross_product(c: Self, a: Self, b: Self) -> i64 {
+        a.subtract(c).cross_product(b.subtract(c))
+    }
+
+    #[inline(always)]
+    fn sqr_distance(self, other: Self) -> i64 {
+        IntPoint::sqr_distance(self, other)
+    }
+
+    #[inline(always)]
+    fn cross(target: &XSegment, other: &XSegment, radius: i64) -> Option<CrossResult> {
+        CrossSolver::cross(target, other, radius)
+    }
+
+    #[inline(always)]
+    fn collinear(target: &XSegment, other: &XSegment) -> CollinearMask {
+        CrossSolver::collinear(target, other)
+    }
+}
@@ -1,20 +1,21 @@
 use i_float::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
+use crate::overlay_point::OverlayPoint;
 
 #[derive(Clone)]
-pub(crate) struct End {
+pub(crate) struct End<P = IntPoint> {
     pub(crate) index: usize,
-    pub(crate) point: IntPoint,
+    pub(crate) point: P,
 }
 
-impl BinKey for End {
+impl<P: OverlayPoint> BinKey for End<P> {
     #[inline(always)]
     fn key(&self) -> i64 {
-        self.point.x.into()
+        self.point.x().into()
     }
 
     #[inline(always)]
     fn bin(&self, layout: &BinLayout) -> usize {
-        layout.index(self.point.x.into())
+        layout.index(self.point.x().into())
     }
 }
\ No newline at end of file
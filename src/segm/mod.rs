@@ -1,4 +1,5 @@
 pub(crate) mod x_segment;
 pub(crate) mod shape_count;
+pub(crate) mod winding_count;
 pub(crate) mod segment;
 pub(crate) mod end;
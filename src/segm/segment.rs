@@ -1,8 +1,11 @@
 use std::cmp::Ordering;
 use i_float::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
+use crate::core::range::RangeTransform;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::x_segment::XSegment;
 use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
 
 pub type SegmentFill = u8;
 
@@ -21,14 +24,14 @@ pub const BOTH_BOTTOM: SegmentFill = SUBJ_BOTTOM | CLIP_BOTTOM;
 pub const ALL: SegmentFill = SUBJ_BOTH | CLIP_BOTH;
 
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct Segment {
-    pub(crate) x_segment: XSegment,
-    pub(crate) count: ShapeCount,
+pub(crate) struct Segment<C = ShapeCount, P = IntPoint> {
+    pub(crate) x_segment: XSegment<P>,
+    pub(crate) count: C,
 }
 
-impl Segment {
+impl<C: WindingCount, P: OverlayPoint> Segment<C, P> {
     #[inline(always)]
-    pub(crate) fn create_and_validate(a: IntPoint, b: IntPoint, count: ShapeCount) -> Self {
+    pub(crate) fn create_and_validate(a: P, b: P, count: C) -> Self {
         if a < b {
             Self { x_segment: XSegment { a, b }, count }
         } else {
@@ -37,24 +40,41 @@ impl Segment {
     }
 }
 
+impl<C: WindingCount> Segment<C> {
+    /// Applies the transform to both ends, returns `None` if the segment collapses into a point.
+    #[inline]
+    pub(crate) fn transform(&self, transform: &RangeTransform) -> Option<Self>
+    where
+        C: Copy,
+    {
+        let a = transform.apply(self.x_segment.a);
+        let b = transform.apply(self.x_segment.b);
+        if a == b {
+            None
+        } else {
+            Some(Self::create_and_validate(a, b, self.count))
+        }
+    }
+}
+
 
-impl PartialEq<Self> for Segment {
+impl<C, P: PartialEq> PartialEq<Self> for Segment<C, P> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.x_segment == other.x_segment
     }
 }
 
-impl Eq for Segment {}
+impl<C, P: Eq> Eq for Segment<C, P> {}
 
-impl PartialOrd for Segment {
+impl<C, P: Ord> PartialOrd for Segment<C, P> {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Segment {
+impl<C, P: Ord> Ord for Segment<C, P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
         self.x_segment.cmp(&other.x_segment)
@@ -66,7 +86,7 @@ pub(crate) trait ShapeEdgesMerge {
     fn merge(&mut self, after: usize);
 }
 
-impl ShapeEdgesMerge for Vec<Segment> {
+impl<C: WindingCount, P: OverlayPoint> ShapeEdgesMerge for Vec<Segment<C, P>> {
     #[inline]
     fn merge_if_needed(&mut self) {
         if self.len() < 2 { return; }
@@ -85,17 +105,17 @@ impl ShapeEdgesMerge for Vec<Segment> {
     fn merge(&mut self, after: usize) {
         let mut i = after;
         let mut j = i - 1;
-        let mut prev = self[j];
+        let mut prev = self[j].clone();
 
         while i < self.len() {
             if prev.x_segment.eq(&self[i].x_segment) {
-                prev.count.apply(self[i].count);
+                prev.count.apply(self[i].count.clone());
             } else {
                 if prev.count.is_not_empty() {
                     self[j] = prev;
                     j += 1;
                 }
-                prev = self[i];
+                prev = self[i].clone();
             }
             i += 1;
         }
@@ -109,7 +129,7 @@ impl ShapeEdgesMerge for Vec<Segment> {
     }
 }
 
-impl BinKey for Segment {
+impl<C, P: OverlayPoint> BinKey for Segment<C, P> {
     #[inline(always)]
     fn key(&self) -> i64 {
         self.x_segment.key()
@@ -1,19 +1,31 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::segm::winding_count::WindingCount;
+
+/// The winding numbers of the subject and clip shapes on one side of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct ShapeCount {
     pub(crate) subj: i32,
     pub(crate) clip: i32,
 }
 
 impl ShapeCount {
+    #[inline(always)]
+    pub(crate) fn new(subj: i32, clip: i32) -> ShapeCount { ShapeCount { subj, clip } }
 
+    /// The winding number of the subject shape.
     #[inline(always)]
-    pub(crate) fn is_not_empty(self) -> bool { self.subj != 0 || self.clip != 0 }
+    pub fn subj(&self) -> i32 { self.subj }
 
+    /// The winding number of the clip shape.
     #[inline(always)]
-    pub(crate) fn new(subj: i32, clip: i32) -> ShapeCount { ShapeCount { subj, clip } }
+    pub fn clip(&self) -> i32 { self.clip }
+}
+
+impl WindingCount for ShapeCount {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool { self.subj != 0 || self.clip != 0 }
 
     #[inline(always)]
-    pub(crate) fn add(self, count: ShapeCount) -> ShapeCount {
+    fn add(self, count: ShapeCount) -> ShapeCount {
         let subj = self.subj + count.subj;
         let clip = self.clip + count.clip;
 
@@ -21,13 +33,13 @@ impl ShapeCount {
     }
 
     #[inline(always)]
-    pub(crate) fn apply(&mut self, count: ShapeCount) {
+    fn apply(&mut self, count: ShapeCount) {
         self.subj += count.subj;
         self.clip += count.clip;
     }
 
     #[inline(always)]
-    pub(crate) fn invert(self) -> ShapeCount {
+    fn invert(self) -> ShapeCount {
         ShapeCount { subj: -self.subj, clip: -self.clip }
     }
-}
\ No newline at end of file
+}
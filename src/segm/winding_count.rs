@@ -0,0 +1,6 @@
+pub(crate) trait WindingCount: Clone + Default + Send + Sync {
+    fn is_not_empty(&self) -> bool;
+    fn add(self, count: Self) -> Self;
+    fn apply(&mut self, count: Self);
+    fn invert(self) -> Self;
+}
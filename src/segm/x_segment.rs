@@ -1,65 +1,65 @@
 use std::cmp::Ordering;
 use i_float::point::IntPoint;
-use i_float::triangle::Triangle;
 use i_key_sort::index::{BinKey, BinLayout};
 use crate::line_range::LineRange;
+use crate::overlay_point::OverlayPoint;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct XSegment {
-    pub(crate) a: IntPoint,
-    pub(crate) b: IntPoint,
+pub(crate) struct XSegment<P = IntPoint> {
+    pub(crate) a: P,
+    pub(crate) b: P,
 }
 
-impl XSegment {
+impl<P: OverlayPoint> XSegment<P> {
     #[inline(always)]
-    pub(crate) fn y_range(&self) -> LineRange {
-        if self.a.y < self.b.y {
-            LineRange { min: self.a.y, max: self.b.y }
+    pub(crate) fn y_range(&self) -> LineRange<P::Coord> {
+        if self.a.y() < self.b.y() {
+            LineRange { min: self.a.y(), max: self.b.y() }
         } else {
-            LineRange { min: self.b.y, max: self.a.y }
+            LineRange { min: self.b.y(), max: self.a.y() }
         }
     }
 
     #[inline(always)]
     pub(crate) fn is_not_vertical(&self) -> bool {
-        self.a.x != self.b.x
+        self.a.x() != self.b.x()
     }
 
     #[inline(always)]
-    pub(crate) fn is_under_point(&self, p: IntPoint) -> bool {
-        debug_assert!(self.a.x <= p.x && p.x <= self.b.x);
+    pub(crate) fn is_under_point(&self, p: P) -> bool {
+        debug_assert!(self.a.x() <= p.x() && p.x() <= self.b.x());
         debug_assert!(p != self.a && p != self.b);
-        Triangle::area_two_point(self.a, p, self.b) > 0
+        P::area_two(self.a, p, self.b) > P::Product::default()
     }
 
     #[inline(always)]
-    pub(crate) fn is_under_segment(&self, other: &XSegment) -> bool {
+    pub(crate) fn is_under_segment(&self, other: &XSegment<P>) -> bool {
         match self.a.cmp(&other.a) {
             Ordering::Less => {
-                Triangle::is_clockwise_point(self.a, other.a, self.b)
+                P::is_clockwise(self.a, other.a, self.b)
             }
             Ordering::Equal => {
-                Triangle::is_clockwise_point(self.a, other.b, self.b)
+                P::is_clockwise(self.a, other.b, self.b)
             }
             Ordering::Greater => {
-                Triangle::is_clockwise_point(other.a, other.b, self.a)
+                P::is_clockwise(other.a, other.b, self.a)
             }
         }
     }
 
     #[inline(always)]
-    pub(crate) fn is_not_intersect_y_range(&self, range: &LineRange) -> bool {
-        range.min > self.a.y && range.min > self.b.y || range.max < self.a.y && range.max < self.b.y
+    pub(crate) fn is_not_intersect_y_range(&self, range: &LineRange<P::Coord>) -> bool {
+        range.min > self.a.y() && range.min > self.b.y() || range.max < self.a.y() && range.max < self.b.y()
     }
 }
 
-impl PartialOrd for XSegment {
+impl<P: Ord> PartialOrd for XSegment<P> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for XSegment {
+impl<P: Ord> Ord for XSegment<P> {
     #[inline(always)]
     fn cmp(&self, other: &Self) -> Ordering {
         let a = self.a.cmp(&other.a);
@@ -71,14 +71,14 @@ impl Ord for XSegment {
     }
 }
 
-impl BinKey for XSegment {
+impl<P: OverlayPoint> BinKey for XSegment<P> {
     #[inline(always)]
     fn key(&self) -> i64 {
-        self.a.x.into()
+        self.a.x().into()
     }
 
     #[inline(always)]
     fn bin(&self, layout: &BinLayout) -> usize {
-        layout.index(self.a.x.into())
+        layout.index(self.a.x().into())
     }
 }
\ No newline at end of file
@@ -80,6 +80,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn test_sort_by() {
         let mut data = vec![
             Point { x: 5, y: 1 },
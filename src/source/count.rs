@@ -0,0 +1,197 @@
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::fill::solver::FillStrategy;
+use crate::segm::segment::SegmentFill;
+use crate::segm::shape_count::ShapeCount;
+use crate::segm::winding_count::WindingCount;
+
+/// Identifies an input path or shape: its index among the inputs of the same type, shifted left, with the lowest bit set for clip inputs.
+pub(crate) type SourceId = usize;
+
+pub(crate) trait SourceIdExt {
+    fn new_source(index: usize, shape_type: ShapeType) -> Self;
+    fn index(&self) -> usize;
+    fn is_clip(&self) -> bool;
+}
+
+impl SourceIdExt for SourceId {
+    #[inline(always)]
+    fn new_source(index: usize, shape_type: ShapeType) -> Self {
+        match shape_type {
+            ShapeType::Subject => index << 1,
+            ShapeType::Clip => (index << 1) | 1,
+        }
+    }
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        self >> 1
+    }
+
+    #[inline(always)]
+    fn is_clip(&self) -> bool {
+        self & 1 == 1
+    }
+}
+
+/// Winding count of every input separately, sorted by source id without zero entries.
+/// Most segments and regions are covered by a single input, so this case is kept without allocation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) enum SourceCount {
+    #[default]
+    Empty,
+    One((SourceId, i32)),
+    Many(Vec<(SourceId, i32)>),
+}
+
+impl SourceCount {
+    #[inline(always)]
+    pub(crate) fn with_source(id: SourceId, count: i32) -> Self {
+        Self::One((id, count))
+    }
+
+    #[inline(always)]
+    fn items(&self) -> &[(SourceId, i32)] {
+        match self {
+            SourceCount::Empty => &[],
+            SourceCount::One(item) => std::slice::from_ref(item),
+            SourceCount::Many(items) => items,
+        }
+    }
+
+    #[inline]
+    fn with_items(items: Vec<(SourceId, i32)>) -> Self {
+        match items.len() {
+            0 => SourceCount::Empty,
+            1 => SourceCount::One(items[0]),
+            _ => SourceCount::Many(items),
+        }
+    }
+
+    #[inline]
+    fn shape_count(&self) -> ShapeCount {
+        let mut count = ShapeCount::new(0, 0);
+        for &(id, value) in self.items().iter() {
+            if id.is_clip() {
+                count.clip += value;
+            } else {
+                count.subj += value;
+            }
+        }
+        count
+    }
+
+    /// Writes the ids of the inputs which cover the region alone into `buffer`.
+    #[inline]
+    pub(crate) fn covered_into(&self, fill_rule: FillRule, buffer: &mut Vec<SourceId>) {
+        buffer.clear();
+        buffer.extend(self.items().iter()
+            .filter(|&&(_, value)| fill_rule.is_fill(value))
+            .map(|&(id, _)| id)
+        );
+    }
+
+    fn merge(a: &[(SourceId, i32)], b: &[(SourceId, i32)]) -> Vec<(SourceId, i32)> {
+        let mut items = Vec::with_capacity(a.len() + b.len());
+        let mut i = 0;
+        let mut j = 0;
+        while i < a.len() && j < b.len() {
+            let x = a[i];
+            let y = b[j];
+            if x.0 < y.0 {
+                items.push(x);
+                i += 1;
+            } else if x.0 > y.0 {
+                items.push(y);
+                j += 1;
+            } else {
+                let value = x.1 + y.1;
+                if value != 0 {
+                    items.push((x.0, value));
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+
+        items.extend_from_slice(&a[i..]);
+        items.extend_from_slice(&b[j..]);
+
+        items
+    }
+}
+
+impl WindingCount for SourceCount {
+    #[inline(always)]
+    fn is_not_empty(&self) -> bool {
+        !matches!(self, SourceCount::Empty)
+    }
+
+    fn add(self, count: Self) -> Self {
+        match (self, count) {
+            (SourceCount::Empty, count) | (count, SourceCount::Empty) => count,
+            (SourceCount::One(a), SourceCount::One(b)) => {
+                if a.0 == b.0 {
+                    let value = a.1 + b.1;
+                    if value == 0 { SourceCount::Empty } else { SourceCount::One((a.0, value)) }
+                } else if a.0 < b.0 {
+                    SourceCount::Many(vec![a, b])
+                } else {
+                    SourceCount::Many(vec![b, a])
+                }
+            }
+            (SourceCount::Many(mut items), SourceCount::One(b)) | (SourceCount::One(b), SourceCount::Many(mut items)) => {
+                // update in place, the buffer is already allocated
+                match items.binary_search_by_key(&b.0, |item| item.0) {
+                    Ok(index) => {
+                        items[index].1 += b.1;
+                        if items[index].1 == 0 {
+                            items.remove(index);
+                        }
+                    }
+                    Err(index) => items.insert(index, b),
+                }
+                Self::with_items(items)
+            }
+            (a, b) => Self::with_items(Self::merge(a.items(), b.items())),
+        }
+    }
+
+    #[inline]
+    fn apply(&mut self, count: Self) {
+        let this = std::mem::take(self);
+        *self = this.add(count);
+    }
+
+    #[inline]
+    fn invert(self) -> Self {
+        match self {
+            SourceCount::Empty => SourceCount::Empty,
+            SourceCount::One((id, value)) => SourceCount::One((id, -value)),
+            SourceCount::Many(items) => SourceCount::Many(items.into_iter().map(|(id, value)| (id, -value)).collect()),
+        }
+    }
+}
+
+/// The usual `SegmentFill` of a segment, together with the winding of every input on each of its sides.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceFill {
+    pub(crate) fill: SegmentFill,
+    pub(crate) top: SourceCount,
+    pub(crate) bottom: SourceCount,
+}
+
+impl FillStrategy<SourceCount, SourceFill> for FillRule {
+    #[inline]
+    fn add_and_fill(&self, this: &SourceCount, bot: &SourceCount) -> (SourceCount, SourceFill) {
+        let top = bot.clone().add(this.clone());
+        let (_, fill) = self.add_and_fill(&this.shape_count(), &bot.shape_count());
+        let source_fill = SourceFill {
+            fill,
+            top: top.clone(),
+            bottom: bot.clone(),
+        };
+
+        (top, source_fill)
+    }
+}
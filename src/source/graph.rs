@@ -0,0 +1,230 @@
+//! This module defines a graph which remembers the inputs covering each side of every link.
+//! It is used to report which inputs a resulting shape comes from.
+
+use std::collections::HashMap;
+use i_shape::int::shape::IntShape;
+
+use crate::core::component::DisjointSet;
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay_graph::OverlayGraph;
+use crate::core::overlay_link::OverlayLink;
+use crate::core::overlay_rule::OverlayRule;
+use crate::core::solver::Solver;
+use crate::fill::solver::FillSolver;
+use crate::id_point::IdPoint;
+use crate::segm::segment::{BOTH_BOTTOM, BOTH_TOP, Segment, ShapeEdgesMerge};
+use crate::sort::SmartSort;
+use crate::source::count::{SourceCount, SourceFill, SourceId, SourceIdExt};
+use crate::split::solver::SplitSolver;
+
+/// The inputs whose interior overlaps a shape. Indices are the order in which paths or shapes of the same type were added to the `SourceOverlay`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShapeSources {
+    pub subj: Vec<usize>,
+    pub clip: Vec<usize>,
+}
+
+/// An `OverlayGraph` which also knows the inputs covering each side of every link.
+///
+/// Create it with `SourceOverlay::into_graph`.
+pub struct SourceOverlayGraph {
+    graph: OverlayGraph,
+    sets: Vec<Vec<SourceId>>,
+    sides: Vec<[usize; 2]>,
+}
+
+impl SourceOverlayGraph {
+    const OUTSIDE: usize = usize::MAX;
+
+    pub(crate) fn build(solver: Solver, fill_rule: FillRule, segments: Vec<Segment<SourceCount>>) -> Self {
+        if segments.is_empty() {
+            return Self { graph: OverlayGraph::with_links(solver, Vec::new()), sets: Vec::new(), sides: Vec::new() };
+        }
+
+        let mut segments = segments;
+        segments.smart_sort_by(&solver, |a, b| a.x_segment.cmp(&b.x_segment));
+
+        segments.merge_if_needed();
+
+        SplitSolver::new(solver).split(&mut segments);
+
+        let fills: Vec<SourceFill> = FillSolver::fill(&fill_rule, &solver, &segments);
+
+        let mut links = Vec::with_capacity(segments.len());
+        let mut sides = Vec::with_capacity(segments.len());
+        let mut sets = Vec::new();
+        let mut set_indices = HashMap::new();
+        let mut top = Vec::new();
+        let mut bottom = Vec::new();
+
+        for (segment, source_fill) in segments.into_iter().zip(fills) {
+            source_fill.top.covered_into(fill_rule, &mut top);
+            source_fill.bottom.covered_into(fill_rule, &mut bottom);
+
+            let is_fill_boundary = (source_fill.fill & BOTH_TOP) != (source_fill.fill & BOTH_BOTTOM) >> 1;
+            if !is_fill_boundary && top == bottom {
+                continue;
+            }
+
+            let top = Self::set_index(&mut sets, &mut set_indices, &top);
+            let bottom = Self::set_index(&mut sets, &mut set_indices, &bottom);
+
+            sides.push([top, bottom]);
+            links.push(OverlayLink::new(
+                IdPoint::new(0, segment.x_segment.a),
+                IdPoint::new(0, segment.x_segment.b),
+                source_fill.fill,
+            ));
+        }
+
+        let graph = OverlayGraph::with_links(solver, links);
+
+        Self { graph, sets, sides }
+    }
+
+    fn set_index(sets: &mut Vec<Vec<SourceId>>, set_indices: &mut HashMap<Vec<SourceId>, usize>, set: &[SourceId]) -> usize {
+        if let Some(&index) = set_indices.get(set) {
+            return index;
+        }
+        let index = sets.len();
+        sets.push(set.to_vec());
+        set_indices.insert(set.to_vec(), index);
+        index
+    }
+
+    /// Extracts shapes from the graph together with the inputs whose interior overlaps each of them.
+    /// - `overlay_rule`: The boolean operation rule to apply when extracting shapes from the graph, such as union or intersection.
+    /// - Returns: A vector of shapes paired with their `ShapeSources`.
+    ///
+    /// The shapes are the same as `OverlayGraph::extract_shapes` returns for the same input.
+    /// For example, the union of two overlapping squares is one shape with both squares as its sources.
+    ///
+    /// Note: Outer boundary paths have a clockwise order, and holes have a counterclockwise order.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    #[inline(always)]
+    pub fn extract_shapes_with_sources(&self, overlay_rule: OverlayRule) -> Vec<(IntShape, ShapeSources)> {
+        self.extract_shapes_with_sources_min_area(overlay_rule, 0)
+    }
+
+    /// Extracts shapes similar to `extract_shapes_with_sources`, but with an additional constraint on the minimum area of the shapes.
+    /// - `overlay_rule`: The boolean operation rule to apply.
+    /// - `min_area`: The minimum area threshold for shapes to be included in the result.
+    ///
+    /// # Panics
+    /// If a contour can not be traced, which is only possible for an inconsistent graph.
+    pub fn extract_shapes_with_sources_min_area(&self, overlay_rule: OverlayRule, min_area: i64) -> Vec<(IntShape, ShapeSources)> {
+        let links = &self.graph.links;
+
+        // links with an empty fill are kept if their sides are covered by different paths, so `Filter` can not be used
+        let mut visited: Vec<bool> = links.iter()
+            .map(|link| overlay_rule.is_inside_top(link.fill) == overlay_rule.is_inside_bottom(link.fill))
+            .collect();
+        let (shapes, shape_links) = self.graph.extract_shapes_with_links(
+            0..links.len(),
+            &mut visited,
+            |link_index| overlay_rule.is_inside_top(links[link_index].fill),
+            min_area,
+        );
+
+        if shapes.is_empty() {
+            return Vec::new();
+        }
+
+        // every set of sources inside the result is split into faces, so every face is covered by one set
+        let labels: Vec<[usize; 2]> = links.iter().zip(self.sides.iter())
+            .map(|(link, sides)| {
+                let top = if overlay_rule.is_inside_top(link.fill) { sides[0] } else { Self::OUTSIDE };
+                let bottom = if overlay_rule.is_inside_bottom(link.fill) { sides[1] } else { Self::OUTSIDE };
+                [top, bottom]
+            }).collect();
+
+        let mut set_links = vec![Vec::new(); self.sets.len()];
+        for (link_index, label) in labels.iter().enumerate() {
+            if label[0] == label[1] {
+                continue;
+            }
+            if label[0] != Self::OUTSIDE {
+                set_links[label[0]].push(link_index);
+            }
+            if label[1] != Self::OUTSIDE {
+                set_links[label[1]].push(link_index);
+            }
+        }
+
+        let mut face_sets = Vec::new();
+        let mut link_faces = vec![[usize::MAX; 2]; links.len()];
+        visited.fill(true);
+
+        for (set_index, link_indices) in set_links.iter().enumerate() {
+            if link_indices.is_empty() {
+                continue;
+            }
+
+            for &link_index in link_indices.iter() {
+                visited[link_index] = false;
+            }
+
+            let (faces, face_links) = self.graph.extract_shapes_with_links(
+                link_indices.iter().copied(),
+                &mut visited,
+                |link_index| labels[link_index][0] == set_index,
+                0,
+            );
+
+            let first_face = face_sets.len();
+            face_sets.resize(first_face + faces.len(), set_index);
+            for (link_index, face) in face_links {
+                let side = if labels[link_index][0] == set_index { 0 } else { 1 };
+                link_faces[link_index][side] = first_face + face;
+            }
+        }
+
+        // faces on both sides of a link inside the result belong to the same shape
+        let mut groups = DisjointSet::new(face_sets.len());
+        for faces in link_faces.iter() {
+            if faces[0] != usize::MAX && faces[1] != usize::MAX {
+                groups.union(faces[0], faces[1]);
+            }
+        }
+
+        // every group of faces is bounded by the contours of one shape
+        let mut group_shapes = vec![usize::MAX; face_sets.len()];
+        for (link_index, shape_index) in shape_links {
+            let side = if overlay_rule.is_inside_top(links[link_index].fill) { 0 } else { 1 };
+            let face = link_faces[link_index][side];
+            if face != usize::MAX {
+                group_shapes[groups.find(face)] = shape_index;
+            }
+        }
+
+        let mut shape_sets: Vec<Vec<SourceId>> = vec![Vec::new(); shapes.len()];
+        for (face, &set_index) in face_sets.iter().enumerate() {
+            let shape_index = group_shapes[groups.find(face)];
+            if shape_index != usize::MAX {
+                shape_sets[shape_index].extend_from_slice(&self.sets[set_index]);
+            }
+        }
+
+        shapes.into_iter().zip(shape_sets)
+            .map(|(shape, set)| (shape, Self::sources(set)))
+            .collect()
+    }
+
+    fn sources(set: Vec<SourceId>) -> ShapeSources {
+        let mut set = set;
+        set.sort_unstable();
+        set.dedup();
+
+        let mut sources = ShapeSources::default();
+        for id in set.iter() {
+            if id.is_clip() {
+                sources.clip.push(id.index());
+            } else {
+                sources.subj.push(id.index());
+            }
+        }
+        sources
+    }
+}
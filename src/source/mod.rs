@@ -0,0 +1,3 @@
+pub mod graph;
+pub mod overlay;
+pub(crate) mod count;
@@ -0,0 +1,156 @@
+//! This module contains functionality to construct overlays which remember the input behind
+//! every segment. Keeping the sources costs memory and time on every step, so it is a separate
+//! builder and `Overlay` does not pay for it.
+
+use i_float::point::IntPoint;
+use i_shape::int::path::IntPath;
+use i_shape::int::shape::{IntShape, PointsCount};
+use i_shape::int::simple::Simple;
+
+use crate::core::fill_rule::FillRule;
+use crate::core::overlay::ShapeType;
+use crate::core::solver::Solver;
+use crate::segm::segment::Segment;
+use crate::segm::x_segment::XSegment;
+use crate::source::count::{SourceCount, SourceId, SourceIdExt};
+use crate::source::graph::SourceOverlayGraph;
+
+/// This struct describes the geometry of an overlay like `Overlay` does, but every segment keeps the input it comes from.
+/// Use it when every extracted shape must be reported together with the inputs covering it.
+///
+/// An input is a path added by `add_path` or a whole shape added by `add_shape`. The contours of a shape are one input,
+/// so a hole of the shape cuts its region out of the shape and is not reported on its own.
+#[derive(Clone)]
+pub struct SourceOverlay {
+    edges: Vec<Segment<SourceCount>>,
+    subj_count: usize,
+    clip_count: usize,
+}
+
+impl SourceOverlay {
+    /// Constructs a new `SourceOverlay` instance.
+    /// - `capacity`: The initial capacity for storing edge data. Ideally, this should be set to the sum of the edges of all shapes to be added to the overlay.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            edges: Vec::with_capacity(capacity),
+            subj_count: 0,
+            clip_count: 0,
+        }
+    }
+
+    /// Creates a new `SourceOverlay` instance and initializes it with subject and clip paths.
+    /// - `subject_paths`: An array of paths that together define the subject shape.
+    /// - `clip_paths`: An array of paths that together define the clip shape.
+    pub fn with_paths(subject_paths: &[IntPath], clip_paths: &[IntPath]) -> Self {
+        let mut overlay = Self::new(subject_paths.points_count() + clip_paths.points_count());
+        overlay.add_paths(subject_paths, ShapeType::Subject);
+        overlay.add_paths(clip_paths, ShapeType::Clip);
+        overlay
+    }
+
+    /// Creates a new `SourceOverlay` instance and initializes it with subject and clip shapes.
+    /// - `subject_shapes`: An array of shapes to be used as the subject in the overlay operation.
+    /// - `clip_shapes`: An array of shapes to be used as the clip in the overlay operation.
+    pub fn with_shapes(subject_shapes: &[IntShape], clip_shapes: &[IntShape]) -> Self {
+        let mut overlay = Self::new(subject_shapes.points_count() + clip_shapes.points_count());
+        overlay.add_shapes(subject_shapes, ShapeType::Subject);
+        overlay.add_shapes(clip_shapes, ShapeType::Clip);
+        overlay
+    }
+
+    /// Adds a single path to the overlay as either subject or clip path.
+    /// The path gets the next index among the inputs of the same type, which is reported in `ShapeSources`.
+    /// - `path`: A reference to a `IntPath` instance to be added.
+    /// - `shape_type`: Specifies the role of the added path in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_path(&mut self, path: &[IntPoint], shape_type: ShapeType) {
+        let id = self.next_source(shape_type);
+        self.add_source_path(path, id);
+    }
+
+    /// Adds multiple paths to the overlay as either subject or clip paths. Every path is a separate input.
+    /// - `paths`: An array of `IntPath` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added paths in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_paths(&mut self, paths: &[IntPath], shape_type: ShapeType) {
+        for path in paths.iter() {
+            self.add_path(path, shape_type);
+        }
+    }
+
+    /// Adds a single shape to the overlay as either a subject or clip shape.
+    /// The shape with all its contours gets the next index among the inputs of the same type, which is reported in `ShapeSources`.
+    /// - `shape`: A reference to a `IntShape` instance to be added.
+    /// - `shape_type`: Specifies the role of the added shape in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shape(&mut self, shape: &IntShape, shape_type: ShapeType) {
+        let id = self.next_source(shape_type);
+        for path in shape.iter() {
+            self.add_source_path(path, id);
+        }
+    }
+
+    /// Adds multiple shapes to the overlay as either subject or clip shapes. Every shape is a separate input.
+    /// - `shapes`: An array of `IntShape` instances to be added to the overlay.
+    /// - `shape_type`: Specifies the role of the added shapes in the overlay operation, either as `Subject` or `Clip`.
+    pub fn add_shapes(&mut self, shapes: &[IntShape], shape_type: ShapeType) {
+        for shape in shapes.iter() {
+            self.add_shape(shape, shape_type);
+        }
+    }
+
+    /// Convert into `SourceOverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes. It is also applied to every input alone to decide if the input covers a region.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph(self, fill_rule: FillRule) -> SourceOverlayGraph {
+        self.into_graph_with_solver(fill_rule, Default::default())
+    }
+
+    /// Convert into `SourceOverlayGraph` from the added paths or shapes using the specified fill rule.
+    /// - `fill_rule`: Specifies the rule for determining filled areas within the shapes.
+    /// - `solver`: Type of solver to use.
+    ///
+    /// # Panics
+    /// If a node of the graph has less than two links, which is not expected for any input.
+    pub fn into_graph_with_solver(self, fill_rule: FillRule, solver: Solver) -> SourceOverlayGraph {
+        SourceOverlayGraph::build(solver, fill_rule, self.edges)
+    }
+
+    fn next_source(&mut self, shape_type: ShapeType) -> SourceId {
+        let index = match shape_type {
+            ShapeType::Subject => {
+                self.subj_count += 1;
+                self.subj_count - 1
+            }
+            ShapeType::Clip => {
+                self.clip_count += 1;
+                self.clip_count - 1
+            }
+        };
+
+        SourceId::new_source(index, shape_type)
+    }
+
+    fn add_source_path(&mut self, path: &[IntPoint], id: SourceId) {
+        if path.is_simple() {
+            self.append_edges(path, id);
+        } else {
+            let path = path.to_simple();
+            if path.len() > 2 {
+                self.append_edges(path.as_slice(), id);
+            }
+        }
+    }
+
+    fn append_edges(&mut self, path: &[IntPoint], id: SourceId) {
+        let mut p0 = path[path.len() - 1];
+        for &p1 in path {
+            let segment = if p0 < p1 {
+                Segment { x_segment: XSegment { a: p0, b: p1 }, count: SourceCount::with_source(id, 1) }
+            } else {
+                Segment { x_segment: XSegment { a: p1, b: p0 }, count: SourceCount::with_source(id, -1) }
+            };
+            self.edges.push(segment);
+            p0 = p1
+        }
+    }
+}
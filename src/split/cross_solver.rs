@@ -4,9 +4,9 @@ use i_float::triangle::Triangle;
 use i_float::u128::UInt128;
 use crate::segm::x_segment::XSegment;
 
-pub(super) type CollinearMask = u8;
+pub(crate) type CollinearMask = u8;
 
-pub(super) trait EndMask {
+pub(crate) trait EndMask {
     fn is_target_a(&self) -> bool;
     fn is_target_b(&self) -> bool;
     fn is_other_a(&self) -> bool;
@@ -47,24 +47,24 @@ impl EndMask for CollinearMask {
     }
 }
 
-pub(super) struct CrossResult {
-    pub(super) point: IntPoint,
-    pub(super) cross_type: CrossType,
-    pub(super) is_round: bool,
+pub(crate) struct CrossResult<P = IntPoint> {
+    pub(crate) point: P,
+    pub(crate) cross_type: CrossType,
+    pub(crate) is_round: bool,
 }
 
-pub(super) enum CrossType {
+pub(crate) enum CrossType {
     Pure,
     TargetEnd,
     OtherEnd,
     Overlay,
 }
 
-pub(super) struct CrossSolver {}
+pub(crate) struct CrossSolver {}
 
 impl CrossSolver {
 
-    pub(super) fn cross(target: &XSegment, other: &XSegment, radius: i64) -> Option<CrossResult> {
+    pub(crate) fn cross(target: &XSegment, other: &XSegment, radius: i64) -> Option<CrossResult> {
         let a0b0a1 = Triangle::clock_direction_point(target.a, target.b, other.a);
         let a0b0b1 = Triangle::clock_direction_point(target.a, target.b, other.b);
 
@@ -118,7 +118,7 @@ impl CrossSolver {
         Self::middle_cross(target, other, radius)
     }
 
-    pub(super) fn collinear(target: &XSegment, other: &XSegment) -> CollinearMask {
+    pub(crate) fn collinear(target: &XSegment, other: &XSegment) -> CollinearMask {
         let a0 = FixVec::new_point(target.a);
         let b0 = FixVec::new_point(target.b);
         let a1 = FixVec::new_point(other.a);
@@ -376,7 +376,7 @@ mod tests {
 
     #[test]
     fn test_big_cross_1() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
 
         let ea = XSegment::new(IntPoint::new(-s, 0), IntPoint::new(s, 0));
         let eb = XSegment::new(IntPoint::new(0, -s), IntPoint::new(0, s));
@@ -395,7 +395,7 @@ mod tests {
 
     #[test]
     fn test_big_cross_2() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
 
         let ea = XSegment::new(IntPoint::new(-s, 0), IntPoint::new(s, 0));
         let eb = XSegment::new(IntPoint::new(1024, -s), IntPoint::new(1024, s));
@@ -414,7 +414,7 @@ mod tests {
 
     #[test]
     fn test_big_cross_3() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
         let q: i32 = s / 2;
 
         let ea = XSegment::new(IntPoint::new(-s, -s), IntPoint::new(s, s));
@@ -434,7 +434,7 @@ mod tests {
 
     #[test]
     fn test_left_end() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
 
         let ea = XSegment::new(IntPoint::new(-s, 0), IntPoint::new(s, 0));
         let eb = XSegment::new(IntPoint::new(-s, -s), IntPoint::new(-s, s));
@@ -453,7 +453,7 @@ mod tests {
 
     #[test]
     fn test_right_end() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
 
         let ea = XSegment::new(IntPoint::new(-s, 0), IntPoint::new(s, 0));
         let eb = XSegment::new(IntPoint::new(s, -s), IntPoint::new(s, s));
@@ -472,7 +472,7 @@ mod tests {
 
     #[test]
     fn test_left_top() {
-        let s: i32 = 1024_000_000;
+        let s: i32 = 1_024_000_000;
 
         let ea = XSegment::new(IntPoint::new(-s, s), IntPoint::new(s, s));
         let eb = XSegment::new(IntPoint::new(-s, s), IntPoint::new(-s, -s));
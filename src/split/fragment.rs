@@ -1,25 +1,44 @@
-use i_float::rect::IntRect;
+use i_float::point::IntPoint;
 use crate::line_range::LineRange;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::x_segment::XSegment;
 
+/// The same as `IntRect`, but for any coordinate type.
 #[derive(Debug, Clone)]
-pub(super) struct Fragment {
+pub(super) struct FragmentRect<T> {
+    pub(super) min_x: T,
+    pub(super) max_x: T,
+    pub(super) min_y: T,
+    pub(super) max_y: T,
+}
+
+impl<T: Ord> FragmentRect<T> {
+    #[inline(always)]
+    pub(super) fn is_intersect_border_include(&self, other: &Self) -> bool {
+        let x = self.min_x <= other.max_x && self.max_x >= other.min_x;
+        let y = self.min_y <= other.max_y && self.max_y >= other.min_y;
+        x && y
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Fragment<P: OverlayPoint = IntPoint> {
     pub(super) index: usize,
-    pub(super) rect: IntRect,
-    pub(super) x_segment: XSegment,
+    pub(super) rect: FragmentRect<P::Coord>,
+    pub(super) x_segment: XSegment<P>,
 }
 
-impl Fragment {
-    pub(super) fn with_index_and_segment(index: usize, x_segment: XSegment) -> Self {
-        let (min_y, max_y) = if x_segment.a.y < x_segment.b.y {
-            (x_segment.a.y, x_segment.b.y)
+impl<P: OverlayPoint> Fragment<P> {
+    pub(super) fn with_index_and_segment(index: usize, x_segment: XSegment<P>) -> Self {
+        let (min_y, max_y) = if x_segment.a.y() < x_segment.b.y() {
+            (x_segment.a.y(), x_segment.b.y())
         } else {
-            (x_segment.b.y, x_segment.a.y)
+            (x_segment.b.y(), x_segment.a.y())
         };
 
-        let rect = IntRect {
-            min_x: x_segment.a.x,
-            max_x: x_segment.b.x,
+        let rect = FragmentRect {
+            min_x: x_segment.a.x(),
+            max_x: x_segment.b.x(),
             min_y,
             max_y,
         };
@@ -31,7 +50,7 @@ impl Fragment {
         }
     }
 
-    pub(super) fn y_range(&self) -> LineRange {
+    pub(super) fn y_range(&self) -> LineRange<P::Coord> {
         LineRange { min: self.rect.min_y, max: self.rect.max_y }
     }
 }
\ No newline at end of file
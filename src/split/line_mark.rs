@@ -1,14 +1,15 @@
 use i_float::point::IntPoint;
 use i_key_sort::index::{BinKey, BinLayout};
+use crate::overlay_point::OverlayPoint;
 
 #[derive(Clone)]
-pub(super) struct LineMark {
+pub(super) struct LineMark<P: OverlayPoint = IntPoint> {
     pub(super) index: usize,
-    pub(super) length: i64,
-    pub(super) point: IntPoint,
+    pub(super) length: P::Product,
+    pub(super) point: P,
 }
 
-impl BinKey for LineMark {
+impl<P: OverlayPoint> BinKey for LineMark<P> {
     #[inline(always)]
     fn key(&self) -> i64 {
         self.index as i64
@@ -1,6 +1,6 @@
 pub(crate) mod solver;
 
-mod cross_solver;
+pub(crate) mod cross_solver;
 mod fragment;
 mod segment_tree;
 mod space_layout;
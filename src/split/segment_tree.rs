@@ -1,51 +1,54 @@
+use i_float::point::IntPoint;
 use crate::util::SwapRemoveIndex;
 use crate::line_range::LineRange;
+use crate::overlay_point::{OverlayCoord, OverlayPoint};
 use crate::split::fragment::Fragment;
 use crate::split::line_mark::LineMark;
 use crate::split::solver::SplitSolver;
 
 #[derive(Debug, Clone)]
-struct IntervalNode {
-    range: LineRange,
-    fragments: Vec<Fragment>,
+struct IntervalNode<P: OverlayPoint> {
+    range: LineRange<P::Coord>,
+    fragments: Vec<Fragment<P>>,
 }
 
-impl IntervalNode {
-    fn new(range: LineRange) -> Self {
+impl<P: OverlayPoint> IntervalNode<P> {
+    fn new(range: LineRange<P::Coord>) -> Self {
         Self { range, fragments: Vec::with_capacity(4) }
     }
 }
 
-pub(super) struct SegmentTree {
+pub(super) struct SegmentTree<P: OverlayPoint = IntPoint> {
     power: usize,
-    nodes: Vec<IntervalNode>,
-    pub(super) radius: i64
+    nodes: Vec<IntervalNode<P>>,
+    pub(super) radius: P::Product
 }
 
-impl SegmentTree {
+impl<P: OverlayPoint> SegmentTree<P> {
     #[inline]
-    pub(super) fn new(range: LineRange, power: usize, radius: i64) -> Self {
+    pub(super) fn new(range: LineRange<P::Coord>, power: usize, radius: P::Product) -> Self {
         let nodes = Self::create_nodes(range, power);
         Self { power, nodes, radius }
     }
 
-    fn create_nodes(range: LineRange, power: usize) -> Vec<IntervalNode> {
+    fn create_nodes(range: LineRange<P::Coord>, power: usize) -> Vec<IntervalNode<P>> {
         let n = 1 << power;
 
         // to make round more precise we use upscale/downscale
+        // i128 keeps the upscaled values of `i64` coordinates
         let scale = 4;
-        let len = range.width() as usize;
-        let step = (((len << scale) as f64) / (n as f64)) as i64;
+        let len = range.width() as i128;
+        let step = (((len << scale) as f64) / (n as f64)) as i128;
 
         let capacity = (n << 1) - 1;
-        let mut nodes = vec![IntervalNode::new(LineRange { min: 0, max: 0 }); capacity];
+        let mut nodes = vec![IntervalNode::new(LineRange { min: P::ZERO.y(), max: P::ZERO.y() }); capacity];
 
         let mut i = 0;
         let mut a0 = range.min;
-        let mut s = (range.min as i64) << scale;
+        let mut s = (range.min.to_i64() as i128) << scale;
         while i < capacity - 1 {
             s += step;
-            let a = (s >> scale) as i32;
+            let a = P::Coord::from_i64((s >> scale) as i64);
             nodes[i] = IntervalNode::new(LineRange { min: a0, max: a });
             i += 2;
             a0 = a;
@@ -72,7 +75,7 @@ impl SegmentTree {
         nodes
     }
 
-    pub(super) fn insert(&mut self, fragment: Fragment) {
+    pub(super) fn insert(&mut self, fragment: Fragment<P>) {
         let mut s = 1 << self.power;
         let mut i = s - 1;
         let range = fragment.y_range();
@@ -172,7 +175,7 @@ impl SegmentTree {
         }
     }
 
-    pub fn intersect(&mut self, this: &Fragment, marks: &mut Vec<LineMark>) -> bool {
+    pub fn intersect(&mut self, this: &Fragment<P>, marks: &mut Vec<LineMark<P>>) -> bool {
         let mut s = 1 << self.power;
         let mut i = s - 1;
         let range = this.y_range();
@@ -269,7 +272,7 @@ impl SegmentTree {
         }
     }
 
-    fn cross_node(&mut self, index: usize, this: &Fragment, marks: &mut Vec<LineMark>) -> bool {
+    fn cross_node(&mut self, index: usize, this: &Fragment<P>, marks: &mut Vec<LineMark<P>>) -> bool {
         let swipe_line = this.rect.min_x;
         let mut any_round = false;
 
@@ -318,9 +321,9 @@ impl SegmentTree {
     }
 }
 
-impl LineRange {
-    fn middle(&self) -> i32 {
-        ((self.max as i64 + self.min as i64) >> 1) as i32
+impl<T: OverlayCoord> LineRange<T> {
+    fn middle(&self) -> T {
+        T::from_i64((self.max.to_i64() + self.min.to_i64()) >> 1)
     }
 }
 
@@ -342,13 +345,13 @@ mod tests {
 
     #[test]
     fn test_0() {
-        let nodes = SegmentTree::create_nodes(LineRange { min: 0, max: 128 }, 4);
+        let nodes = SegmentTree::<IntPoint>::create_nodes(LineRange { min: 0, max: 128 }, 4);
         assert_eq!(31, nodes.len());
     }
 
     #[test]
     fn test_1() {
-        let nodes = SegmentTree::create_nodes(LineRange { min: 0, max: 128 }, 5);
+        let nodes = SegmentTree::<IntPoint>::create_nodes(LineRange { min: 0, max: 128 }, 5);
         assert_eq!(63, nodes.len());
     }
 
@@ -359,27 +362,27 @@ mod tests {
         tree.insert(Fragment::with_index_and_segment(0, x_segment));
 
 
-        assert_eq!(true, !tree.nodes[0].fragments.is_empty());
-        assert_eq!(true, tree.nodes[1].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[2].fragments.is_empty());
+        assert!(!tree.nodes[0].fragments.is_empty());
+        assert!(tree.nodes[1].fragments.is_empty());
+        assert!(!tree.nodes[2].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[3].fragments.is_empty());
+        assert!(tree.nodes[3].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[4].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[5].fragments.is_empty());
-        assert_eq!(true, tree.nodes[6].fragments.is_empty());
+        assert!(tree.nodes[4].fragments.is_empty());
+        assert!(!tree.nodes[5].fragments.is_empty());
+        assert!(tree.nodes[6].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[7].fragments.is_empty());
+        assert!(tree.nodes[7].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[8].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[9].fragments.is_empty());
-        assert_eq!(true, tree.nodes[10].fragments.is_empty());
+        assert!(tree.nodes[8].fragments.is_empty());
+        assert!(!tree.nodes[9].fragments.is_empty());
+        assert!(tree.nodes[10].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[11].fragments.is_empty());
+        assert!(tree.nodes[11].fragments.is_empty());
 
-        assert_eq!(true, !tree.nodes[12].fragments.is_empty());
-        assert_eq!(true, tree.nodes[13].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[14].fragments.is_empty());
+        assert!(!tree.nodes[12].fragments.is_empty());
+        assert!(tree.nodes[13].fragments.is_empty());
+        assert!(!tree.nodes[14].fragments.is_empty());
     }
 
     #[test]
@@ -389,27 +392,27 @@ mod tests {
         tree.insert(Fragment::with_index_and_segment(0, x_segment));
 
 
-        assert_eq!(true, tree.nodes[0].fragments.is_empty());
-        assert_eq!(true, tree.nodes[1].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[2].fragments.is_empty());
+        assert!(tree.nodes[0].fragments.is_empty());
+        assert!(tree.nodes[1].fragments.is_empty());
+        assert!(!tree.nodes[2].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[3].fragments.is_empty());
+        assert!(tree.nodes[3].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[4].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[5].fragments.is_empty());
-        assert_eq!(true, tree.nodes[6].fragments.is_empty());
+        assert!(tree.nodes[4].fragments.is_empty());
+        assert!(!tree.nodes[5].fragments.is_empty());
+        assert!(tree.nodes[6].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[7].fragments.is_empty());
+        assert!(tree.nodes[7].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[8].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[9].fragments.is_empty());
-        assert_eq!(true, tree.nodes[10].fragments.is_empty());
+        assert!(tree.nodes[8].fragments.is_empty());
+        assert!(!tree.nodes[9].fragments.is_empty());
+        assert!(tree.nodes[10].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[11].fragments.is_empty());
+        assert!(tree.nodes[11].fragments.is_empty());
 
-        assert_eq!(true, !tree.nodes[12].fragments.is_empty());
-        assert_eq!(true, tree.nodes[13].fragments.is_empty());
-        assert_eq!(true, tree.nodes[14].fragments.is_empty());
+        assert!(!tree.nodes[12].fragments.is_empty());
+        assert!(tree.nodes[13].fragments.is_empty());
+        assert!(tree.nodes[14].fragments.is_empty());
     }
 
     #[test]
@@ -419,27 +422,27 @@ mod tests {
         tree.insert(Fragment::with_index_and_segment(0, x_segment));
 
 
-        assert_eq!(true, tree.nodes[0].fragments.is_empty());
-        assert_eq!(true, tree.nodes[1].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[2].fragments.is_empty());
+        assert!(tree.nodes[0].fragments.is_empty());
+        assert!(tree.nodes[1].fragments.is_empty());
+        assert!(!tree.nodes[2].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[3].fragments.is_empty());
+        assert!(tree.nodes[3].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[4].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[5].fragments.is_empty());
-        assert_eq!(true, tree.nodes[6].fragments.is_empty());
+        assert!(tree.nodes[4].fragments.is_empty());
+        assert!(!tree.nodes[5].fragments.is_empty());
+        assert!(tree.nodes[6].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[7].fragments.is_empty());
+        assert!(tree.nodes[7].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[8].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[9].fragments.is_empty());
-        assert_eq!(true, tree.nodes[10].fragments.is_empty());
+        assert!(tree.nodes[8].fragments.is_empty());
+        assert!(!tree.nodes[9].fragments.is_empty());
+        assert!(tree.nodes[10].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[11].fragments.is_empty());
+        assert!(tree.nodes[11].fragments.is_empty());
 
-        assert_eq!(true, !tree.nodes[12].fragments.is_empty());
-        assert_eq!(true, tree.nodes[13].fragments.is_empty());
-        assert_eq!(true, tree.nodes[14].fragments.is_empty());
+        assert!(!tree.nodes[12].fragments.is_empty());
+        assert!(tree.nodes[13].fragments.is_empty());
+        assert!(tree.nodes[14].fragments.is_empty());
     }
 
     #[test]
@@ -449,27 +452,27 @@ mod tests {
         tree.insert(Fragment::with_index_and_segment(0, x_segment));
 
 
-        assert_eq!(true, tree.nodes[0].fragments.is_empty());
-        assert_eq!(true, tree.nodes[1].fragments.is_empty());
-        assert_eq!(true, tree.nodes[2].fragments.is_empty());
+        assert!(tree.nodes[0].fragments.is_empty());
+        assert!(tree.nodes[1].fragments.is_empty());
+        assert!(tree.nodes[2].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[3].fragments.is_empty());
+        assert!(tree.nodes[3].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[4].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[5].fragments.is_empty());
-        assert_eq!(true, tree.nodes[6].fragments.is_empty());
+        assert!(tree.nodes[4].fragments.is_empty());
+        assert!(!tree.nodes[5].fragments.is_empty());
+        assert!(tree.nodes[6].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[7].fragments.is_empty());
+        assert!(tree.nodes[7].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[8].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[9].fragments.is_empty());
-        assert_eq!(true, tree.nodes[10].fragments.is_empty());
+        assert!(tree.nodes[8].fragments.is_empty());
+        assert!(!tree.nodes[9].fragments.is_empty());
+        assert!(tree.nodes[10].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[11].fragments.is_empty());
+        assert!(tree.nodes[11].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[12].fragments.is_empty());
-        assert_eq!(true, tree.nodes[13].fragments.is_empty());
-        assert_eq!(true, tree.nodes[14].fragments.is_empty());
+        assert!(tree.nodes[12].fragments.is_empty());
+        assert!(tree.nodes[13].fragments.is_empty());
+        assert!(tree.nodes[14].fragments.is_empty());
     }
 
     #[test]
@@ -479,27 +482,27 @@ mod tests {
         tree.insert(Fragment::with_index_and_segment(0, x_segment));
 
 
-        assert_eq!(true, tree.nodes[0].fragments.is_empty());
-        assert_eq!(true, tree.nodes[1].fragments.is_empty());
-        assert_eq!(true, tree.nodes[2].fragments.is_empty());
+        assert!(tree.nodes[0].fragments.is_empty());
+        assert!(tree.nodes[1].fragments.is_empty());
+        assert!(tree.nodes[2].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[3].fragments.is_empty());
+        assert!(tree.nodes[3].fragments.is_empty());
 
-        assert_eq!(true, !tree.nodes[4].fragments.is_empty());
-        assert_eq!(true, tree.nodes[5].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[6].fragments.is_empty());
+        assert!(!tree.nodes[4].fragments.is_empty());
+        assert!(tree.nodes[5].fragments.is_empty());
+        assert!(!tree.nodes[6].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[7].fragments.is_empty());
+        assert!(tree.nodes[7].fragments.is_empty());
 
-        assert_eq!(true, !tree.nodes[8].fragments.is_empty());
-        assert_eq!(true, tree.nodes[9].fragments.is_empty());
-        assert_eq!(true, !tree.nodes[10].fragments.is_empty());
+        assert!(!tree.nodes[8].fragments.is_empty());
+        assert!(tree.nodes[9].fragments.is_empty());
+        assert!(!tree.nodes[10].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[11].fragments.is_empty());
+        assert!(tree.nodes[11].fragments.is_empty());
 
-        assert_eq!(true, tree.nodes[12].fragments.is_empty());
-        assert_eq!(true, tree.nodes[13].fragments.is_empty());
-        assert_eq!(true, tree.nodes[14].fragments.is_empty());
+        assert!(tree.nodes[12].fragments.is_empty());
+        assert!(tree.nodes[13].fragments.is_empty());
+        assert!(tree.nodes[14].fragments.is_empty());
     }
 
     #[test]
@@ -517,7 +520,7 @@ mod tests {
 
         tree.intersect(&Fragment::with_index_and_segment(0, XSegment { a: a1, b: b1 }), &mut marks);
 
-        assert_eq!(true, marks.is_empty());
+        assert!(marks.is_empty());
     }
 
     #[test]
@@ -570,7 +573,7 @@ mod tests {
         let mut marks = Vec::new();
         for s in test_set.iter() {
             marks.clear();
-            let fragment = Fragment::with_index_and_segment(0, s.clone());
+            let fragment = Fragment::with_index_and_segment(0, *s);
             tree.intersect(&fragment, &mut marks);
 
             if marks.is_empty() {
@@ -583,7 +586,7 @@ mod tests {
         result
     }
 
-    fn range(list: &Vec<XSegment>) -> LineRange {
+    fn range(list: &[XSegment]) -> LineRange {
         let mut min = i32::MAX;
         let mut max = i32::MIN;
 
@@ -1,10 +1,12 @@
 use std::cmp::Ordering;
 use crate::core::solver::Solver;
+use crate::overlay_point::OverlayPoint;
 use crate::segm::segment::{Segment, ShapeEdgesMerge};
 use crate::sort::SmartSort;
-use crate::split::cross_solver::{CrossType, CrossSolver, EndMask};
+use crate::split::cross_solver::{CrossType, EndMask};
 use crate::split::line_mark::LineMark;
 use crate::segm::x_segment::XSegment;
+use crate::segm::winding_count::WindingCount;
 
 pub(crate) struct SplitSolver {
     pub(super) solver: Solver
@@ -15,7 +17,7 @@ impl SplitSolver {
         Self { solver }
     }
 
-    pub(crate) fn split(&mut self, edges: &mut Vec<Segment>) {
+    pub(crate) fn split<C: WindingCount, P: OverlayPoint>(&mut self, edges: &mut Vec<Segment<C, P>>) {
         let is_list = self.solver.is_list_split(edges);
 
         if is_list {
@@ -25,8 +27,8 @@ impl SplitSolver {
         }
     }
 
-    pub(super) fn cross(i: usize, j: usize, ei: &XSegment, ej: &XSegment, marks: &mut Vec<LineMark>, radius: i64) -> bool {
-        let cross = if let Some(cross) = CrossSolver::cross(ei, ej, radius) {
+    pub(super) fn cross<P: OverlayPoint>(i: usize, j: usize, ei: &XSegment<P>, ej: &XSegment<P>, marks: &mut Vec<LineMark<P>>, radius: P::Product) -> bool {
+        let cross = if let Some(cross) = P::cross(ei, ej, radius) {
             cross
         } else {
             return false;
@@ -50,7 +52,7 @@ impl SplitSolver {
                 marks.push(LineMark { index: i, length: li, point: cross.point });
             }
             CrossType::Overlay => {
-                let mask = CrossSolver::collinear(ei, ej);
+                let mask = P::collinear(ei, ej);
                 if mask == 0 { return false; }
 
                 if mask.is_target_a() {
@@ -78,7 +80,7 @@ impl SplitSolver {
         cross.is_round
     }
 
-    pub(super) fn apply(&self, marks: &mut Vec<LineMark>, edges: &mut Vec<Segment>) {
+    pub(super) fn apply<C: WindingCount, P: OverlayPoint>(&self, marks: &mut [LineMark<P>], edges: &mut Vec<Segment<C, P>>) {
         marks.smart_sort_by(&self.solver, |a, b|
         if a.index < b.index || a.index == b.index && (a.length < b.length || a.length == b.length && a.point < b.point) {
             Ordering::Less
@@ -101,8 +103,8 @@ impl SplitSolver {
                 let e0 = unsafe { edges.get_unchecked_mut(index) };
                 let p = marks[i0].point;
                 let b = e0.x_segment.b;
-                let count = e0.count;
-                *e0 = Segment::create_and_validate(e0.x_segment.a, p, count);
+                let count = e0.count.clone();
+                *e0 = Segment::create_and_validate(e0.x_segment.a, p, count.clone());
                 edges.push(Segment::create_and_validate(p, b, count));
             } else {
                 Self::multi_split_edge(&marks[i0..i], edges);
@@ -114,7 +116,7 @@ impl SplitSolver {
         edges.merge_if_needed();
     }
 
-    fn multi_split_edge(marks: &[LineMark], edges: &mut Vec<Segment>) {
+    fn multi_split_edge<C: WindingCount, P: OverlayPoint>(marks: &[LineMark<P>], edges: &mut Vec<Segment<C, P>>) {
         let mut iter = marks.iter();
         let m0 = iter.next().unwrap();
 
@@ -124,12 +126,12 @@ impl SplitSolver {
         let e0 = unsafe { edges.get_unchecked_mut(m0.index) };
 
         let b = e0.x_segment.b;
-        let count = e0.count;
-        *e0 = Segment::create_and_validate(e0.x_segment.a, p, count);
+        let count = e0.count.clone();
+        *e0 = Segment::create_and_validate(e0.x_segment.a, p, count.clone());
 
         for mj in iter {
             if l != mj.length || p != mj.point {
-                edges.push(Segment::create_and_validate(p, mj.point, count));
+                edges.push(Segment::create_and_validate(p, mj.point, count.clone()));
                 p = mj.point;
                 l = mj.length;
             }
@@ -1,8 +1,10 @@
+use crate::overlay_point::OverlayPoint;
 use crate::segm::segment::Segment;
 use crate::split::solver::SplitSolver;
+use crate::segm::winding_count::WindingCount;
 
 impl SplitSolver {
-    pub(super) fn list_split(&mut self, edges: &mut Vec<Segment>) {
+    pub(super) fn list_split<C: WindingCount, P: OverlayPoint>(&mut self, edges: &mut Vec<Segment<C, P>>) {
         let mut marks = Vec::new();
         let mut need_to_fix = true;
 
@@ -12,14 +14,14 @@ impl SplitSolver {
             need_to_fix = false;
             marks.clear();
 
-            let radius: i64 = self.solver.radius(iter);
+            let radius = P::Product::from(self.solver.radius(iter));
 
             for i in 0..edges.len() - 1 {
                 let ei = &edges[i].x_segment;
                 let ri = ei.y_range();
                 for (j, s) in edges.iter().enumerate().skip(i + 1) {
                     let ej = &s.x_segment;
-                    if ei.b.x < ej.a.x {
+                    if ei.b.x() < ej.a.x() {
                         break;
                     }
 
@@ -1,13 +1,19 @@
 use crate::line_range::LineRange;
+use crate::overlay_point::{OverlayCoord, OverlayPoint};
 use crate::segm::segment::Segment;
 use crate::split::fragment::Fragment;
+use crate::split::line_mark::LineMark;
 use crate::split::segment_tree::SegmentTree;
 use crate::split::solver::SplitSolver;
 use crate::split::space_layout::SpaceLayout;
+use crate::segm::winding_count::WindingCount;
 
 
 impl SplitSolver {
-    pub(super) fn tree_split(&mut self, edges: &mut Vec<Segment>) {
+    #[cfg(feature = "allow_multithreading")]
+    const MIN_BAND_SIZE: usize = 4096;
+
+    pub(super) fn tree_split<C: WindingCount, P: OverlayPoint>(&mut self, edges: &mut Vec<Segment<C, P>>) {
         let ver_range = edges.ver_range();
         let height = ver_range.width() as usize;
 
@@ -15,7 +21,7 @@ impl SplitSolver {
             self.list_split(edges);
         }
 
-        let layout = SpaceLayout::new(height, edges.len());
+        let layout = SpaceLayout::new(height, edges.len(), P::Coord::BITS);
 
         if layout.is_fragmentation_required_for_edges(edges) {
             self.simple(ver_range, &layout, edges);
@@ -24,30 +30,28 @@ impl SplitSolver {
         }
     }
 
-    fn simple(&self, ver_range: LineRange, layout: &SpaceLayout, edges: &mut Vec<Segment>) {
-        let mut tree = SegmentTree::new(ver_range, layout.power, 0);
+    fn simple<C: WindingCount, P: OverlayPoint>(&self, ver_range: LineRange<P::Coord>, layout: &SpaceLayout, edges: &mut Vec<Segment<C, P>>) {
+        let mut tree = SegmentTree::new(ver_range, layout.power, P::Product::default());
         let mut marks = Vec::new();
         let mut need_to_fix = true;
 
+        let mut fragments = Vec::with_capacity(edges.len());
+
         let mut iter = 0;
 
         while need_to_fix && edges.len() > 2 {
-            need_to_fix = false;
+            tree.radius = P::Product::from(self.solver.radius(iter));
 
-            tree.radius = self.solver.radius(iter);
+            // edges are sorted by x, so fragments are sorted by min_x too
+            fragments.extend(edges.iter().enumerate().map(|(i, e)| Fragment::with_index_and_segment(i, e.x_segment)));
 
-            for (i, e) in edges.iter().enumerate() {
-                let fragment = Fragment::with_index_and_segment(i, e.x_segment);
-                let any_round = tree.intersect(&fragment, &mut marks);
-                need_to_fix = any_round || need_to_fix;
-
-                tree.insert(fragment);
-            }
+            need_to_fix = self.intersect(&mut tree, ver_range, layout, &fragments, &mut marks);
 
             if marks.is_empty() {
                 return;
             }
 
+            fragments.clear();
             tree.clear();
 
             self.apply(&mut marks, edges);
@@ -58,8 +62,8 @@ impl SplitSolver {
         }
     }
 
-    fn complex(&self, ver_range: LineRange, layout: &SpaceLayout, edges: &mut Vec<Segment>) {
-        let mut tree = SegmentTree::new(ver_range, layout.power, 0);
+    fn complex<C: WindingCount, P: OverlayPoint>(&self, ver_range: LineRange<P::Coord>, layout: &SpaceLayout, edges: &mut Vec<Segment<C, P>>) {
+        let mut tree = SegmentTree::new(ver_range, layout.power, P::Product::default());
         let mut marks = Vec::new();
         let mut need_to_fix = true;
 
@@ -68,9 +72,7 @@ impl SplitSolver {
         let mut iter = 0;
 
         while need_to_fix && edges.len() > 2 {
-            need_to_fix = false;
-
-            for (i, &e) in edges.iter().enumerate() {
+            for (i, e) in edges.iter().enumerate() {
                 layout.break_into_fragments(i, e.x_segment, &mut fragments);
             }
 
@@ -80,14 +82,12 @@ impl SplitSolver {
                 return;
             }
 
-            tree.radius = self.solver.radius(iter);
+            tree.radius = P::Product::from(self.solver.radius(iter));
 
-            for fragment in fragments.iter() {
-                let any_round = tree.intersect(fragment, &mut marks);
-                need_to_fix = any_round || need_to_fix;
+            // fragments of a long edge can be to the right of the next edge start, the sweep needs them ordered by min_x
+            fragments.sort_by_key(|fragment| fragment.rect.min_x);
 
-                tree.insert(fragment.clone());
-            }
+            need_to_fix = self.intersect(&mut tree, ver_range, layout, &fragments, &mut marks);
 
             if marks.is_empty() {
                 return;
@@ -103,22 +103,92 @@ impl SplitSolver {
             iter += 1;
         }
     }
+
+    /// Finds crosses of all fragments, which must be sorted by `rect.min_x`.
+    /// - Returns: `true` if any cross point was rounded.
+    fn intersect<P: OverlayPoint>(&self, tree: &mut SegmentTree<P>, _ver_range: LineRange<P::Coord>, _layout: &SpaceLayout, fragments: &[Fragment<P>], marks: &mut Vec<LineMark<P>>) -> bool {
+        #[cfg(feature = "allow_multithreading")]
+        {
+            if self.solver.multithreading.is_some() {
+                let bands_count = (rayon::current_num_threads() << 2).min(fragments.len() / Self::MIN_BAND_SIZE);
+                if bands_count > 1 {
+                    return Self::par_intersect(bands_count, tree.radius, _ver_range, _layout, fragments, marks);
+                }
+            }
+        }
+
+        let mut any_round = false;
+        for fragment in fragments.iter() {
+            let is_round = tree.intersect(fragment, marks);
+            any_round = is_round || any_round;
+
+            tree.insert(fragment.clone());
+        }
+
+        any_round
+    }
+
+    /// Splits fragments into x bands and sweeps every band with its own tree. Fragments from the left of a band which reach into it
+    /// are inserted into its tree first, so every pair of fragments is checked exactly in one band, and the marks are the same as after a single sweep.
+    #[cfg(feature = "allow_multithreading")]
+    fn par_intersect<P: OverlayPoint>(bands_count: usize, radius: P::Product, ver_range: LineRange<P::Coord>, layout: &SpaceLayout, fragments: &[Fragment<P>], marks: &mut Vec<LineMark<P>>) -> bool {
+        use rayon::prelude::*;
+
+        let band_size = fragments.len().div_ceil(bands_count);
+
+        let results: Vec<(Vec<LineMark<P>>, bool)> = (0..bands_count).into_par_iter().map(|band| {
+            let start = band * band_size;
+            let end = (start + band_size).min(fragments.len());
+
+            let mut band_marks = Vec::new();
+            if start >= end {
+                return (band_marks, false);
+            }
+
+            let mut tree = SegmentTree::new(ver_range, layout.power, radius);
+
+            let min_x = fragments[start].rect.min_x;
+            for fragment in fragments[..start].iter() {
+                if fragment.rect.max_x >= min_x {
+                    tree.insert(fragment.clone());
+                }
+            }
+
+            let mut any_round = false;
+            for fragment in fragments[start..end].iter() {
+                let is_round = tree.intersect(fragment, &mut band_marks);
+                any_round = is_round || any_round;
+
+                tree.insert(fragment.clone());
+            }
+
+            (band_marks, any_round)
+        }).collect();
+
+        let mut any_round = false;
+        for (band_marks, is_round) in results {
+            marks.extend(band_marks);
+            any_round = is_round || any_round;
+        }
+
+        any_round
+    }
 }
 
-trait VerticalRange {
-    fn ver_range(&self) -> LineRange;
+trait VerticalRange<T> {
+    fn ver_range(&self) -> LineRange<T>;
 }
 
-impl VerticalRange for Vec<Segment> {
-    fn ver_range(&self) -> LineRange {
-        let mut min_y = self[0].x_segment.a.y;
+impl<C, P: OverlayPoint> VerticalRange<P::Coord> for Vec<Segment<C, P>> {
+    fn ver_range(&self) -> LineRange<P::Coord> {
+        let mut min_y = self[0].x_segment.a.y();
         let mut max_y = min_y;
 
         for edge in self.iter() {
-            min_y = min_y.min(edge.x_segment.a.y);
-            max_y = max_y.max(edge.x_segment.a.y);
-            min_y = min_y.min(edge.x_segment.b.y);
-            max_y = max_y.max(edge.x_segment.b.y);
+            min_y = min_y.min(edge.x_segment.a.y());
+            max_y = max_y.max(edge.x_segment.a.y());
+            min_y = min_y.min(edge.x_segment.b.y());
+            max_y = max_y.max(edge.x_segment.b.y());
         }
 
         LineRange { min: min_y, max: max_y }
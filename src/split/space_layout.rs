@@ -1,11 +1,11 @@
-use i_float::rect::IntRect;
+use crate::overlay_point::{OverlayCoord, OverlayPoint};
 use crate::segm::segment::Segment;
-use crate::split::fragment::Fragment;
+use crate::split::fragment::{Fragment, FragmentRect};
 use crate::segm::x_segment::XSegment;
 
 pub(super) struct SpaceLayout {
     pub(super) power: usize,
-    min_size: u64,
+    min_size: u128,
     scale: usize
 }
 
@@ -14,31 +14,32 @@ impl SpaceLayout {
     const MAX_POWER: usize = 12;
     pub(super) const MIN_HEIGHT: usize = 1 << Self::MIN_POWER;
 
-    pub(super) fn new(height: usize, count: usize) -> Self {
+    /// - `bits`: The number of bits of a coordinate, the fragments are calculated with twice as many bits.
+    pub(super) fn new(height: usize, count: usize, bits: u32) -> Self {
         let max_power_range = height.log2() - 1;
         let max_power_count = count.log2() >> 1;
         let original_power = max_power_range.min(max_power_count);
         let power = original_power.clamp(Self::MIN_POWER, Self::MAX_POWER);
-        let min_size = (height >> power) as u64;
+        let min_size = (height >> power) as u128;
         let m = (min_size as usize).log2();
-        let scale = u32::BITS as usize - m;
+        let scale = bits as usize - m;
         Self { power, min_size, scale }
     }
 
-    pub(super) fn break_into_fragments(&self, index: usize, x_segment: XSegment, buffer: &mut Vec<Fragment>) {
-        let min_x = x_segment.a.x;
-        let max_x = x_segment.b.x;
+    pub(super) fn break_into_fragments<P: OverlayPoint>(&self, index: usize, x_segment: XSegment<P>, buffer: &mut Vec<Fragment<P>>) {
+        let min_x = x_segment.a.x();
+        let max_x = x_segment.b.x();
 
-        let is_up = x_segment.a.y < x_segment.b.y;
+        let is_up = x_segment.a.y() < x_segment.b.y();
 
         let (min_y, max_y) = if is_up {
-            (x_segment.a.y, x_segment.b.y)
+            (x_segment.a.y(), x_segment.b.y())
         } else {
-            (x_segment.b.y, x_segment.a.y)
+            (x_segment.b.y(), x_segment.a.y())
         };
 
-        let dx = (max_x as i64 - min_x as i64) as u64;
-        let dy = (max_y as i64 - min_y as i64) as u64;
+        let dx = (max_x.to_i64() - min_x.to_i64()) as u128;
+        let dy = (max_y.to_i64() - min_y.to_i64()) as u128;
 
         let is_fragmentation_required = dx > self.min_size && dy > self.min_size;
 
@@ -55,7 +56,7 @@ impl SpaceLayout {
             (self.min_size << self.scale) * dx / dy
         };
 
-        let mut x0: u64 = 0;
+        let mut x0: u128 = 0;
 
         let mut ix0 = min_x;
         let mut iy0 = if is_up { min_y } else { max_y };
@@ -77,17 +78,17 @@ impl SpaceLayout {
             let is_same_line = x * dy == y * dx;
             let extra = if is_same_line { 0 } else { 1 };
 
-            let ix1 = min_x + x as i32;
+            let ix1 = P::Coord::from_i64(min_x.to_i64() + x as i64);
 
 
             let (iy1, rect) = if is_up {
-                let iy1 = min_y + y as i32;
-                let rect = IntRect { min_x: ix0, max_x: ix1, min_y: iy0, max_y: iy1 + extra };
-                (iy1, rect)
+                let iy1 = min_y.to_i64() + y as i64;
+                let rect = FragmentRect { min_x: ix0, max_x: ix1, min_y: iy0, max_y: P::Coord::from_i64(iy1 + extra) };
+                (P::Coord::from_i64(iy1), rect)
             } else {
-                let iy1 = max_y - y as i32;
-                let rect = IntRect { min_x: ix0, max_x: ix1, min_y: iy1 - extra, max_y: iy0 };
-                (iy1, rect)
+                let iy1 = max_y.to_i64() - y as i64;
+                let rect = FragmentRect { min_x: ix0, max_x: ix1, min_y: P::Coord::from_i64(iy1 - extra), max_y: iy0 };
+                (P::Coord::from_i64(iy1), rect)
             };
 
             buffer.push(Fragment { index, rect, x_segment });
@@ -100,15 +101,15 @@ impl SpaceLayout {
 
 
         let rect = if is_up {
-            IntRect { min_x: ix0, max_x, min_y: iy0, max_y }
+            FragmentRect { min_x: ix0, max_x, min_y: iy0, max_y }
         } else {
-            IntRect { min_x: ix0, max_x, min_y, max_y: iy0 }
+            FragmentRect { min_x: ix0, max_x, min_y, max_y: iy0 }
         };
 
         buffer.push(Fragment { index, rect, x_segment });
     }
 
-    pub(super) fn is_fragmentation_required_for_edges(&self, edges: &[Segment]) -> bool {
+    pub(super) fn is_fragmentation_required_for_edges<C, P: OverlayPoint>(&self, edges: &[Segment<C, P>]) -> bool {
         let mut i = 0;
         for edge in edges.iter() {
             if self.is_fragmentation_required(edge.x_segment) {
@@ -119,9 +120,9 @@ impl SpaceLayout {
         i * 20 > edges.len()
     }
 
-    fn is_fragmentation_required(&self, x_segment: XSegment) -> bool {
-        let dx = (x_segment.b.x as i64 - x_segment.a.x as i64) as u64;
-        let dy = (x_segment.b.x as i64 - x_segment.a.x as i64).unsigned_abs();
+    fn is_fragmentation_required<P: OverlayPoint>(&self, x_segment: XSegment<P>) -> bool {
+        let dx = (x_segment.b.x().to_i64() - x_segment.a.x().to_i64()) as u128;
+        let dy = (x_segment.b.x().to_i64() - x_segment.a.x().to_i64()).unsigned_abs() as u128;
 
         dx > self.min_size && dy > self.min_size
     }
@@ -2,6 +2,7 @@ use i_float::point::IntPoint;
 use crate::bind::segment::IdSegments;
 use crate::bind::solver::ShapeBinder;
 use crate::id_point::IdPoint;
+use crate::core::error::OverlayError;
 use crate::core::overlay_graph::OverlayGraph;
 use crate::core::overlay_rule::OverlayRule;
 use crate::core::filter::Filter;
@@ -20,7 +21,16 @@ impl OverlayGraph {
         }).collect()
     }
 
+    /// Extracts shapes like `extract_shapes`, but every path is a sequence of `VectorEdge`, which keeps the fill of the edge.
+    ///
+    /// # Panics
+    /// If a contour can not be closed, see `try_extract_shape_vectors`.
     pub fn extract_shape_vectors(&self, overlay_rule: OverlayRule) -> Vec<VectorShape> {
+        self.try_extract_shape_vectors(overlay_rule).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Same as `extract_shape_vectors`, but returns `OverlayError::TopologyFailure` if a contour can not be closed, which is only possible for an inconsistent graph.
+    pub fn try_extract_shape_vectors(&self, overlay_rule: OverlayRule) -> Result<Vec<VectorShape>, OverlayError> {
         let mut visited = self.links.filter(overlay_rule);
 
         let mut holes = Vec::new();
@@ -47,7 +57,7 @@ impl OverlayGraph {
                     last_node_id: link.b.id,
                     fill: link.fill,
                 };
-                let path = self.get_vector_path(start_data, &mut visited);
+                let path = self.get_vector_path(start_data, &mut visited).ok_or(OverlayError::TopologyFailure)?;
                 holes.push(path);
             } else {
                 let start_data = StartVectorPathData {
@@ -58,7 +68,7 @@ impl OverlayGraph {
                     last_node_id: link.a.id,
                     fill: link.fill,
                 };
-                let path = self.get_vector_path(start_data, &mut visited);
+                let path = self.get_vector_path(start_data, &mut visited).ok_or(OverlayError::TopologyFailure)?;
                 shapes.push(vec![path]);
             };
 
@@ -67,10 +77,11 @@ impl OverlayGraph {
 
         shapes.join(&self.solver, holes);
 
-        shapes
+        Ok(shapes)
     }
 
-    fn get_vector_path(&self, start_data: StartVectorPathData, visited: &mut [bool]) -> VectorPath {
+    /// Returns `None` if the tour is broken, which is only possible for an inconsistent graph.
+    fn get_vector_path(&self, start_data: StartVectorPathData, visited: &mut [bool]) -> Option<VectorPath> {
         let mut link_id = start_data.link_id;
         let mut node_id = start_data.node_id;
         let last_node_id = start_data.last_node_id;
@@ -88,7 +99,7 @@ impl OverlayGraph {
                     if bridge[0] == link_id { bridge[1] } else { bridge[0] }
                 }
                 OverlayNode::Cross(indices) => {
-                    self.find_nearest_counter_wise_link_to(link_id, node_id, indices, visited)
+                    self.find_nearest_counter_wise_link_to(link_id, node_id, indices, visited)?
                 }
             };
 
@@ -104,7 +115,7 @@ impl OverlayGraph {
             *unsafe { visited.get_unchecked_mut(link_id) } = true;
         }
 
-        path
+        Some(path)
     }
 }
 
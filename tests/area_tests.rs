@@ -0,0 +1,80 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::{rect, shapes_area};
+
+    #[test]
+    fn test_data() {
+        for (index, test) in Test::load_all() {
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+
+            for (rule, bank) in test.rule_banks() {
+                let area = graph.area(rule);
+                assert_eq!(area, shapes_area(&graph.extract_shapes(rule)), "test: {}, rule: {:?}", index, rule);
+                assert!(bank.iter().any(|shapes| shapes_area(shapes) == area), "test: {}, rule: {:?}", index, rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(5, 5, 10, 10)]).into_graph(FillRule::NonZero);
+
+        assert_eq!(graph.area(OverlayRule::Subject), 100.0);
+        assert_eq!(graph.area(OverlayRule::Intersect), 25.0);
+        assert_eq!(graph.area(OverlayRule::Union), 175.0);
+        assert_eq!(graph.area(OverlayRule::Difference), 75.0);
+        assert_eq!(graph.area(OverlayRule::Xor), 150.0);
+
+        // a frame
+        let mut hole = rect(2, 2, 6, 6);
+        hole.reverse();
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10), hole], &empty).into_graph(FillRule::NonZero);
+        assert_eq!(graph.area(OverlayRule::Subject), 64.0);
+        assert_eq!(graph.area(OverlayRule::Clip), 0.0);
+
+        // half units
+        let triangle = vec![IntPoint::new(0, 0), IntPoint::new(0, 3), IntPoint::new(3, 0)];
+        let graph = Overlay::with_paths(&[triangle], &empty).into_graph(FillRule::NonZero);
+        assert_eq!(graph.area(OverlayRule::Subject), 4.5);
+    }
+
+    #[test]
+    fn test_1() {
+        // the largest coordinates must not overflow
+        let max = (1 << 30) - 1;
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&[rect(-max, -max, 2 * max, 2 * max)], &empty).into_graph(FillRule::NonZero);
+        let side = 2.0 * max as f64;
+        assert_eq!(graph.area(OverlayRule::Subject), side * side);
+    }
+
+    #[test]
+    fn test_2() {
+        let square = |x: f64, y: f64, size: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0, 1.0)], vec![square(0.5, 0.5, 1.0)]).into_graph(FillRule::NonZero);
+        assert_eq!(graph.area(OverlayRule::Intersect), 0.25);
+        assert_eq!(graph.area(OverlayRule::Union), 1.75);
+
+        let graph = F64Overlay::with_paths(vec![square(-100.0, -100.0, 200.0)], vec![]).into_graph(FillRule::NonZero);
+        assert_eq!(graph.area(OverlayRule::Subject), 40000.0);
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison, clippy::unnecessary_to_owned, clippy::useless_vec)]
+
 #[cfg(test)]
 mod tests {
     use i_float::point::IntPoint;
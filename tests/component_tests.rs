@@ -0,0 +1,108 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_shape::int::path::IntPath;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_graph::OverlayGraph;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::{rect, CircleCompare, RULES};
+
+    fn extract_by_components(graph: &OverlayGraph, rule: OverlayRule) -> Vec<IntShape> {
+        let mut shapes: Vec<IntShape> = graph.components().iter().flat_map(|component| component.extract_shapes(rule)).collect();
+        shapes.sort_by_key(|shape| shape[0][0]);
+        shapes
+    }
+
+    fn test_result(bank: &[Vec<IntShape>], result: &Vec<IntShape>) -> bool {
+        bank.iter().any(|item| {
+            let mut item = item.clone();
+            item.sort_by_key(|shape| shape[0][0]);
+            item.are_equal(result)
+        })
+    }
+
+    #[test]
+    fn test_data() {
+        for (index, test) in Test::load_all() {
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+
+            for (rule, bank) in test.rule_banks() {
+                assert!(test_result(bank, &extract_by_components(&graph, rule)), "test: {}, rule: {:?}", index, rule);
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        // two separate squares and a frame with an island inside its hole
+        let subj = vec![
+            rect(0, 0, 10, 10),
+            rect(20, 0, 10, 10),
+            rect(40, 0, 30, 30),
+            rect(50, 10, 10, 10),
+        ];
+        let mut hole = rect(45, 5, 20, 20);
+        hole.reverse();
+        let clip = vec![rect(5, 5, 10, 10), hole];
+
+        let graph = Overlay::with_paths(&subj, &clip).into_graph(FillRule::EvenOdd);
+        let components = graph.components();
+        assert_eq!(components.len(), 3);
+
+        // the frame keeps its hole
+        let xor = components[2].extract_shapes(OverlayRule::Xor);
+        assert_eq!(xor.len(), 2);
+        assert_eq!(xor[0].len(), 2);
+
+        // components can be extracted in parallel
+        let results: Vec<Vec<IntShape>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = components.iter().map(|component| scope.spawn(move || component.extract_shapes(OverlayRule::Union))).collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+
+        let mut union: Vec<IntShape> = results.into_iter().flatten().collect();
+        union.sort_by_key(|shape| shape[0][0]);
+        assert_eq!(union, graph.extract_shapes(OverlayRule::Union));
+    }
+
+    #[test]
+    fn test_1() {
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&empty, &empty).into_graph(FillRule::NonZero);
+        assert!(graph.components().is_empty());
+
+        for rule in RULES {
+            let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(10, 0, 10, 10)]).into_graph(FillRule::NonZero);
+            let components = graph.components();
+            assert_eq!(components.len(), 1);
+            assert_eq!(components[0].extract_shapes(rule), graph.extract_shapes(rule));
+        }
+    }
+
+    #[test]
+    fn test_2() {
+        let square = |x: f64| vec![
+            F64Point::new(x, 0.0),
+            F64Point::new(x, 1.0),
+            F64Point::new(x + 1.0, 1.0),
+            F64Point::new(x + 1.0, 0.0),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![square(0.0), square(2.0), square(4.0)], vec![square(4.5)]).into_graph(FillRule::NonZero);
+        let components = graph.components();
+        assert_eq!(components.len(), 3);
+
+        let union = components[2].extract_shapes(OverlayRule::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0][0].len(), 4);
+        assert!(union[0][0].iter().all(|p| p.x >= 4.0 && p.x <= 5.5));
+    }
+}
@@ -0,0 +1,86 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::context::OverlayContext;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn test_result(bank: &[Vec<IntShape>], result: &Vec<IntShape>) -> bool {
+        bank.iter().any(|item| item.are_equal(result))
+    }
+
+    #[test]
+    fn test_data() {
+        for solver in [Solver::LIST, Solver::TREE] {
+            let mut context = OverlayContext::new(solver);
+            let mut overlay = Overlay::new(0);
+
+            for index in 0..134 {
+                let test = Test::load(index);
+                let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+
+                overlay.clear();
+                overlay.add_paths(&test.subj_paths, ShapeType::Subject);
+                overlay.add_paths(&test.clip_paths, ShapeType::Clip);
+                overlay.build_graph(&mut context, fill_rule).unwrap();
+
+                assert!(test_result(&test.subject, &context.extract_shapes(OverlayRule::Subject)), "test: {}", index);
+                assert!(test_result(&test.clip, &context.extract_shapes(OverlayRule::Clip)), "test: {}", index);
+                assert!(test_result(&test.intersect, &context.extract_shapes(OverlayRule::Intersect)), "test: {}", index);
+                assert!(test_result(&test.union, &context.extract_shapes(OverlayRule::Union)), "test: {}", index);
+                assert!(test_result(&test.difference, &context.extract_shapes(OverlayRule::Difference)), "test: {}", index);
+                assert!(test_result(&test.inverse_difference, &context.extract_shapes(OverlayRule::InverseDifference)), "test: {}", index);
+                assert!(test_result(&test.xor, &context.extract_shapes(OverlayRule::Xor)), "test: {}", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        let mut context = OverlayContext::default();
+        let mut overlay = Overlay::new(8);
+
+        for i in 0..10 {
+            overlay.clear();
+            overlay.add_path(&square(0, 0, 10), ShapeType::Subject);
+            overlay.add_path(&square(i, i, 10), ShapeType::Clip);
+
+            let expected = overlay.clone().into_graph(FillRule::NonZero);
+            let graph = overlay.build_graph(&mut context, FillRule::NonZero).unwrap();
+
+            assert_eq!(graph.extract_shapes(OverlayRule::Union), expected.extract_shapes(OverlayRule::Union));
+            assert_eq!(context.extract_shapes(OverlayRule::Difference), expected.extract_shapes(OverlayRule::Difference));
+            assert_eq!(context.extract_shapes_min_area(OverlayRule::Xor, 10), expected.extract_shapes_min_area(OverlayRule::Xor, 10));
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let mut context = OverlayContext::default();
+        let overlay = Overlay::with_paths(&[square(0, 0, 10)], &[square(5, 5, 10)]);
+        overlay.build_graph(&mut context, FillRule::NonZero).unwrap();
+        assert_eq!(context.extract_shapes(OverlayRule::Intersect), vec![vec![square(5, 5, 5)]]);
+
+        // an empty overlay leaves nothing from the previous one
+        Overlay::new(0).build_graph(&mut context, FillRule::NonZero).unwrap();
+        assert!(context.graph().extract_shapes(OverlayRule::Union).is_empty());
+        assert!(context.extract_shapes(OverlayRule::Subject).is_empty());
+    }
+}
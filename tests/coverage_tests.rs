@@ -0,0 +1,128 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::path::PointPathExtension;
+    use i_shape::int::shape::IntShapes;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::solver::Solver;
+    use crate::util::overlay::CircleCompare;
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + height),
+            IntPoint::new(x + width, y + height),
+            IntPoint::new(x + width, y),
+        ]
+    }
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        rect(x, y, size, size)
+    }
+
+    fn reversed(path: Vec<IntPoint>) -> Vec<IntPoint> {
+        let mut path = path;
+        path.reverse();
+        path
+    }
+
+    fn area(shapes: &IntShapes) -> i64 {
+        shapes.iter().flatten().map(|path| path.unsafe_area()).sum()
+    }
+
+    #[test]
+    fn test_0() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(12);
+            overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+            overlay.add_path(&square(10, 0, 20), ShapeType::Subject);
+            overlay.add_path(&square(20, 0, 20), ShapeType::Subject);
+
+            let graph = overlay.into_coverage_graph_with_solver(solver);
+            assert_eq!(graph.max_level(), 2);
+
+            let levels = graph.extract_levels();
+            assert_eq!(levels.len(), 2);
+            assert!(levels[0].are_equal(&vec![vec![rect(0, 0, 10, 20)], vec![rect(30, 0, 10, 20)]]));
+            assert!(levels[1].are_equal(&vec![vec![rect(10, 0, 20, 20)]]));
+
+            let at_least_one = graph.extract_shapes(|level| level >= 1);
+            assert!(at_least_one.are_equal(&vec![vec![rect(0, 0, 40, 20)]]));
+
+            let exactly_two = graph.extract_shapes(|level| level == 2);
+            assert!(exactly_two.are_equal(&levels[1]));
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let mut overlay = Overlay::new(8);
+        overlay.add_path(&square(0, 0, 30), ShapeType::Subject);
+        overlay.add_path(&square(10, 10, 10), ShapeType::Subject);
+
+        let levels = overlay.into_coverage_graph().extract_levels();
+        assert_eq!(levels.len(), 2);
+        assert!(levels[0].are_equal(&vec![vec![square(0, 0, 30), reversed(square(10, 10, 10))]]));
+        assert!(levels[1].are_equal(&vec![vec![square(10, 10, 10)]]));
+    }
+
+    #[test]
+    fn test_2() {
+        // a hole lowers the level, clip paths are ignored
+        let mut overlay = Overlay::new(16);
+        overlay.add_path(&square(0, 0, 30), ShapeType::Subject);
+        overlay.add_path(&reversed(square(10, 10, 10)), ShapeType::Subject);
+        overlay.add_path(&square(5, 5, 20), ShapeType::Subject);
+        overlay.add_path(&square(-50, -50, 100), ShapeType::Clip);
+
+        let levels = overlay.into_coverage_graph().extract_levels();
+        assert_eq!(levels.len(), 2);
+        assert!(levels[0].are_equal(&vec![
+            vec![square(0, 0, 30), reversed(square(5, 5, 20))],
+            vec![square(10, 10, 10)],
+        ]));
+        assert!(levels[1].are_equal(&vec![vec![square(5, 5, 20), reversed(square(10, 10, 10))]]));
+    }
+
+    #[test]
+    fn test_3() {
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Clip);
+
+        let graph = overlay.into_coverage_graph();
+        assert_eq!(graph.max_level(), 0);
+        assert!(graph.extract_levels().is_empty());
+        assert!(graph.extract_shapes(|level| level > 0).is_empty());
+    }
+
+    #[test]
+    fn test_4() {
+        // n squares of size 30 shifted by 10, the level of every 10 wide column is known
+        for n in 1..8 {
+            for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+                let mut overlay = Overlay::new(4 * n);
+                for i in 0..n {
+                    overlay.add_path(&square(10 * i as i32, 0, 30), ShapeType::Subject);
+                }
+
+                let graph = overlay.into_coverage_graph_with_solver(solver);
+                let levels = graph.extract_levels();
+
+                let mut expected = vec![0; graph.max_level()];
+                for column in 0..n + 2 {
+                    let level = (0..n).filter(|&i| i <= column && column < i + 3).count();
+                    expected[level - 1] += 2 * 10 * 30;
+                }
+
+                for (k, shapes) in levels.iter().enumerate() {
+                    assert_eq!(area(shapes), expected[k]);
+
+                    let at_least: i64 = expected[k..].iter().sum();
+                    assert_eq!(area(&graph.extract_shapes(|level| level > k)), at_least);
+                }
+            }
+        }
+    }
+}
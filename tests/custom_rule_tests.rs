@@ -0,0 +1,74 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    fn test_result(result: &Vec<IntShape>, bank: &[Vec<IntShape>]) -> bool {
+        bank.iter().any(|item| item.are_equal(result))
+    }
+
+    #[test]
+    fn test_0() {
+        let mut overlay = Overlay::new(8);
+        overlay.add_path(&[
+            IntPoint::new(0, 0),
+            IntPoint::new(0, 20),
+            IntPoint::new(20, 20),
+            IntPoint::new(20, 0),
+        ], ShapeType::Subject);
+        overlay.add_path(&[
+            IntPoint::new(10, 0),
+            IntPoint::new(10, 20),
+            IntPoint::new(30, 20),
+            IntPoint::new(30, 0),
+        ], ShapeType::Clip);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let subject_only = graph.extract_shapes_custom(|subj, clip| subj && !clip);
+        assert!(subject_only.are_equal(&graph.extract_shapes(OverlayRule::Difference)));
+
+        let any = graph.extract_shapes_custom(|subj, clip| subj || clip);
+        assert!(any.are_equal(&graph.extract_shapes(OverlayRule::Union)));
+
+        let nothing = graph.extract_shapes_custom(|_, _| false);
+        assert!(nothing.is_empty());
+    }
+
+    #[test]
+    fn test_data() {
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+                let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths)
+                    .into_graph_with_solver(fill_rule, solver);
+
+                let subject = graph.extract_shapes_custom(|subj, _| subj);
+                let clip = graph.extract_shapes_custom(|_, clip| clip);
+                let intersect = graph.extract_shapes_custom(|subj, clip| subj && clip);
+                let union = graph.extract_shapes_custom(|subj, clip| subj || clip);
+                let difference = graph.extract_shapes_custom(|subj, clip| subj && !clip);
+                let inverse_difference = graph.extract_shapes_custom(|subj, clip| !subj && clip);
+                let xor = graph.extract_shapes_custom(|subj, clip| subj != clip);
+
+                assert!(test_result(&subject, &test.subject), "test: {}", index);
+                assert!(test_result(&clip, &test.clip), "test: {}", index);
+                assert!(test_result(&intersect, &test.intersect), "test: {}", index);
+                assert!(test_result(&union, &test.union), "test: {}", index);
+                assert!(test_result(&difference, &test.difference), "test: {}", index);
+                assert!(test_result(&inverse_difference, &test.inverse_difference), "test: {}", index);
+                assert!(test_result(&xor, &test.xor), "test: {}", index);
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod overlay {
     use i_shape::int::shape::IntShape;
     use serde::{Deserialize, Deserializer};
     use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay_rule::OverlayRule;
 
     #[derive(Debug, Deserialize)]
     pub struct Test {
@@ -33,6 +34,8 @@ pub mod overlay {
         match val {
             Some(0) => Ok(Some(FillRule::EvenOdd)),
             Some(1) => Ok(Some(FillRule::NonZero)),
+            Some(2) => Ok(Some(FillRule::Positive)),
+            Some(3) => Ok(Some(FillRule::Negative)),
             None => Ok(None), // This covers the case where the field is missing
             _ => Err(serde::de::Error::custom("Invalid value for FillRule")),
         }
@@ -62,5 +65,25 @@ pub mod overlay {
                 }
             }
         }
+
+        /// Loads every test of `tests/data` with its index.
+        #[allow(dead_code)]
+        pub fn load_all() -> impl Iterator<Item = (usize, Test)> {
+            (0..134).map(|index| (index, Test::load(index)))
+        }
+
+        /// Pairs every overlay rule with the expected results of the test for this rule.
+        #[allow(dead_code)]
+        pub fn rule_banks(&self) -> [(OverlayRule, &[Vec<IntShape>]); 7] {
+            [
+                (OverlayRule::Subject, &self.subject),
+                (OverlayRule::Clip, &self.clip),
+                (OverlayRule::Intersect, &self.intersect),
+                (OverlayRule::Union, &self.union),
+                (OverlayRule::Difference, &self.difference),
+                (OverlayRule::InverseDifference, &self.inverse_difference),
+                (OverlayRule::Xor, &self.xor),
+            ]
+        }
     }
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision, clippy::inconsistent_digit_grouping, clippy::len_zero)]
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::PI;
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use i_float::f32_point::F32Point;
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_overlay::core::error::OverlayError;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::f32::overlay::F32Overlay;
+    use i_overlay::f64::overlay::F64Overlay;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn f64_square(x: f64, y: f64, size: f64) -> Vec<F64Point> {
+        vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ]
+    }
+
+    #[test]
+    fn test_0() {
+        let overlay = Overlay::with_paths(&[square(0, 0, 10)], &[square(5, 5, 10)]);
+
+        let expected = overlay.clone().into_graph(FillRule::NonZero);
+        let graph = overlay.try_into_graph(FillRule::NonZero).unwrap();
+
+        for rule in [OverlayRule::Subject, OverlayRule::Clip, OverlayRule::Intersect, OverlayRule::Union, OverlayRule::Difference, OverlayRule::Xor] {
+            assert_eq!(graph.try_extract_shapes(rule).unwrap(), expected.extract_shapes(rule));
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let big = 1 << 30;
+        let overlay = Overlay::with_paths(&[square(0, 0, 10)], &[square(big - 5, 0, 10)]);
+
+        let result = overlay.try_into_graph(FillRule::NonZero);
+
+        assert!(matches!(result, Err(OverlayError::CoordinateOverflow(p)) if p.x == big + 5));
+    }
+
+    #[test]
+    fn test_2() {
+        let mut overlay = Overlay::with_paths(&[square(0, 0, 10)], &[]);
+        overlay.add_polyline(&[IntPoint::new(5, -5), IntPoint::new(5, i32::MIN)]);
+
+        let result = overlay.try_into_graph(FillRule::NonZero);
+
+        assert_eq!(result.err(), Some(OverlayError::CoordinateOverflow(IntPoint::new(5, i32::MIN))));
+    }
+
+    #[test]
+    fn test_3() {
+        // the largest safe coordinates still work
+        let max = (1 << 30) - 1;
+        let overlay = Overlay::with_paths(&[square(-max, -max, max)], &[square(0, 0, max)]);
+
+        let graph = overlay.try_into_graph(FillRule::NonZero).unwrap();
+        let union = graph.try_extract_shapes(OverlayRule::Union).unwrap();
+
+        assert_eq!(union.len(), 2);
+    }
+
+    #[test]
+    fn test_4() {
+        let mut overlay = F64Overlay::new();
+        overlay.add_path(f64_square(0.0, 0.0, 1.0), ShapeType::Subject);
+        overlay.add_path(vec![F64Point::new(0.0, 0.0), F64Point::new(f64::NAN, 1.0), F64Point::new(1.0, 0.0)], ShapeType::Clip);
+
+        let result = overlay.try_into_graph(FillRule::NonZero);
+
+        assert!(matches!(result, Err(OverlayError::NonFiniteInput)));
+    }
+
+    #[test]
+    fn test_5() {
+        let overlay = F64Overlay::with_path(f64_square(0.0, 0.0, 1.0), f64_square(0.5, 0.5, 1.0));
+
+        let expected = overlay.clone().into_graph(FillRule::EvenOdd).extract_shapes(OverlayRule::Xor);
+        let result = overlay.try_into_graph(FillRule::EvenOdd).unwrap().try_extract_shapes(OverlayRule::Xor).unwrap();
+
+        let points = |shapes: Vec<Vec<Vec<F64Point>>>| -> Vec<(f64, f64)> {
+            shapes.into_iter().flatten().flatten().map(|p| (p.x, p.y)).collect()
+        };
+
+        assert!(!result.is_empty());
+        assert_eq!(points(result), points(expected));
+    }
+
+    #[test]
+    fn test_6() {
+        let mut overlay = F32Overlay::new();
+        overlay.add_path(vec![F32Point::new(0.0, 0.0), F32Point::new(0.0, 1.0), F32Point::new(1.0, 1.0)], ShapeType::Subject);
+        overlay.add_polyline(vec![F32Point::new(0.0, 0.0), F32Point::new(f32::INFINITY, 0.5)]);
+
+        let result = overlay.try_into_graph(FillRule::NonZero);
+
+        assert!(matches!(result, Err(OverlayError::NonFiniteInput)));
+    }
+
+    #[test]
+    fn test_7() {
+        let mut overlay = Overlay::with_paths(&[square(0, 0, 10)], &[square(5, 5, 10)]);
+        overlay.add_polyline(&[IntPoint::new(-5, 3), IntPoint::new(20, 3)]);
+
+        let graph = overlay.try_into_graph(FillRule::NonZero).unwrap();
+
+        let expected = graph.extract_slices(OverlayRule::Union);
+        let slices = graph.try_extract_slices(OverlayRule::Union).unwrap();
+
+        assert_eq!(slices.left.len(), 1);
+        assert_eq!(slices.right.len(), 1);
+        assert_eq!(slices.left, expected.left);
+        assert_eq!(slices.right, expected.right);
+        assert_eq!(slices.rest, expected.rest);
+
+        let vectors = graph.try_extract_shape_vectors(OverlayRule::Xor).unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors, graph.extract_shape_vectors(OverlayRule::Xor));
+    }
+}
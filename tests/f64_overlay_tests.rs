@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison, clippy::needless_range_loop)]
+
 #[cfg(test)]
 mod tests {
     use i_float::f64_point::F64Point;
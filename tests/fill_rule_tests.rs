@@ -0,0 +1,179 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f32_point::F32Point;
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_overlay::core::fill_rule::{FillRule, ShapeCount};
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::f32::overlay::F32Overlay;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::util::overlay::CircleCompare;
+
+    fn clockwise(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn counter_clockwise(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        let mut path = clockwise(x, y, size);
+        path.reverse();
+        path
+    }
+
+    #[test]
+    fn test_0() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(8);
+            overlay.add_path(&counter_clockwise(0, 0, 10), ShapeType::Subject);
+            overlay.add_path(&clockwise(20, 0, 10), ShapeType::Subject);
+
+            let positive = overlay.clone().into_graph_with_solver(FillRule::Positive, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(positive.are_equal(&vec![vec![clockwise(0, 0, 10)]]));
+
+            let negative = overlay.clone().into_graph_with_solver(FillRule::Negative, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(negative.are_equal(&vec![vec![clockwise(20, 0, 10)]]));
+
+            let non_zero = overlay.into_graph_with_solver(FillRule::NonZero, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert_eq!(non_zero.len(), 2);
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        // an inner path with the opposite direction cancels the winding of the outer one
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(8);
+            overlay.add_path(&counter_clockwise(0, 0, 30), ShapeType::Subject);
+            overlay.add_path(&clockwise(10, 10, 10), ShapeType::Subject);
+
+            let positive = overlay.clone().into_graph_with_solver(FillRule::Positive, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(positive.are_equal(&vec![vec![clockwise(0, 0, 30), counter_clockwise(10, 10, 10)]]));
+
+            let negative = overlay.into_graph_with_solver(FillRule::Negative, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(negative.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_2() {
+        // the same direction accumulates the winding, unlike EvenOdd
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(8);
+            overlay.add_path(&clockwise(0, 0, 30), ShapeType::Subject);
+            overlay.add_path(&clockwise(10, 10, 10), ShapeType::Subject);
+            overlay.add_path(&counter_clockwise(5, 5, 20), ShapeType::Clip);
+
+            let negative = overlay.clone().into_graph_with_solver(FillRule::Negative, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(negative.are_equal(&vec![vec![clockwise(0, 0, 30)]]));
+
+            // the counterclockwise clip is not filled
+            let negative_difference = overlay.clone().into_graph_with_solver(FillRule::Negative, solver)
+                .extract_shapes(OverlayRule::Difference);
+            assert!(negative_difference.are_equal(&vec![vec![clockwise(0, 0, 30)]]));
+
+            let even_odd = overlay.into_graph_with_solver(FillRule::EvenOdd, solver)
+                .extract_shapes(OverlayRule::Subject);
+            assert!(even_odd.are_equal(&vec![vec![clockwise(0, 0, 30), counter_clockwise(10, 10, 10)]]));
+        }
+    }
+
+    #[test]
+    fn test_3() {
+        let subj = vec![
+            F64Point::new(0.0, 0.0),
+            F64Point::new(1.0, 0.0),
+            F64Point::new(1.0, 1.0),
+            F64Point::new(0.0, 1.0),
+        ];
+        let clip = vec![
+            F64Point::new(0.5, 0.0),
+            F64Point::new(0.5, 1.0),
+            F64Point::new(1.5, 1.0),
+            F64Point::new(1.5, 0.0),
+        ];
+
+        let overlay = F64Overlay::with_paths(vec![subj.clone()], vec![clip.clone()]);
+        let positive = overlay.into_graph(FillRule::Positive).extract_shapes(OverlayRule::Union);
+        // only the subject goes counterclockwise, so it is the only positive path
+        assert_eq!(positive.len(), 1);
+        assert_eq!(positive[0].len(), 1);
+        assert_eq!(xy(&positive[0][0]), vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)]);
+
+        let overlay = F64Overlay::with_paths(vec![subj], vec![clip]);
+        let negative = overlay.into_graph(FillRule::Negative).extract_shapes(OverlayRule::Union);
+        assert_eq!(negative.len(), 1);
+        assert_eq!(negative[0].len(), 1);
+        assert_eq!(xy(&negative[0][0]), vec![(0.5, 0.0), (0.5, 1.0), (1.5, 1.0), (1.5, 0.0)]);
+
+        let f32_subj = vec![
+            F32Point::new(0.0, 0.0),
+            F32Point::new(1.0, 0.0),
+            F32Point::new(1.0, 1.0),
+            F32Point::new(0.0, 1.0),
+        ];
+        let overlay = F32Overlay::with_paths(vec![f32_subj], vec![]);
+        assert_eq!(overlay.into_graph(FillRule::Positive).extract_shapes(OverlayRule::Subject).len(), 1);
+    }
+
+    fn xy(path: &[F64Point]) -> Vec<(f64, f64)> {
+        path.iter().map(|p| (p.x, p.y)).collect()
+    }
+
+    fn at_least_three(count: ShapeCount) -> (bool, bool) {
+        (count.subj().abs() >= 3, count.clip() != 0)
+    }
+
+    #[test]
+    fn test_4() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(16);
+            overlay.add_path(&clockwise(0, 0, 30), ShapeType::Subject);
+            overlay.add_path(&clockwise(10, 0, 30), ShapeType::Subject);
+            overlay.add_path(&clockwise(20, 0, 30), ShapeType::Subject);
+            overlay.add_path(&clockwise(0, 10, 50), ShapeType::Clip);
+
+            let graph = overlay.into_graph_with_solver(FillRule::Custom(at_least_three), solver);
+
+            let subject = graph.extract_shapes(OverlayRule::Subject);
+            assert!(subject.are_equal(&vec![vec![vec![
+                IntPoint::new(20, 0),
+                IntPoint::new(20, 30),
+                IntPoint::new(30, 30),
+                IntPoint::new(30, 0),
+            ]]]));
+
+            let intersect = graph.extract_shapes(OverlayRule::Intersect);
+            assert!(intersect.are_equal(&vec![vec![vec![
+                IntPoint::new(20, 10),
+                IntPoint::new(20, 30),
+                IntPoint::new(30, 30),
+                IntPoint::new(30, 10),
+            ]]]));
+        }
+    }
+
+    #[test]
+    fn test_5() {
+        assert_eq!(FillRule::NonZero, FillRule::NonZero);
+        assert_ne!(FillRule::NonZero, FillRule::EvenOdd);
+
+        // function addresses are not a reliable identity, so custom rules are never equal
+        let custom = FillRule::Custom(at_least_three);
+        assert_ne!(custom, custom);
+        assert_ne!(custom, FillRule::NonZero);
+    }
+}
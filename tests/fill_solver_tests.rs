@@ -0,0 +1,115 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use i_shape::int::shape::IntShape;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::{MultithreadOptions, Solver, Strategy};
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    const RULES: [OverlayRule; 7] = [
+        OverlayRule::Subject,
+        OverlayRule::Clip,
+        OverlayRule::Intersect,
+        OverlayRule::Union,
+        OverlayRule::Difference,
+        OverlayRule::InverseDifference,
+        OverlayRule::Xor,
+    ];
+
+    // every graph is filled by parallel bands
+    fn par_solver(strategy: Strategy) -> Solver {
+        Solver { strategy, multithreading: Some(MultithreadOptions { par_fill_min_size: 0, ..Default::default() }), ..Solver::AUTO }
+    }
+
+    fn random_rects(rng: &mut impl Rng, count: usize, size: i32) -> Vec<IntPath> {
+        (0..count).map(|_| {
+            let x = 2 * rng.gen_range(-size..size);
+            let y = 2 * rng.gen_range(-size..size);
+            let w = 2 * rng.gen_range(1..50);
+            let h = 2 * rng.gen_range(1..50);
+            vec![
+                IntPoint::new(x, y),
+                IntPoint::new(x, y + h),
+                IntPoint::new(x + w, y + h),
+                IntPoint::new(x + w, y),
+            ]
+        }).collect()
+    }
+
+    // strips with 45 degree edges, all cross points with even coordinates are integer
+    fn random_strips(rng: &mut impl Rng, count: usize, size: i32) -> Vec<IntPath> {
+        (0..count).map(|_| {
+            let x = 2 * rng.gen_range(-size..size);
+            let y = 2 * rng.gen_range(-size..size);
+            let l = 2 * rng.gen_range(1..size);
+            let w = 2 * rng.gen_range(1..20);
+            let dy = if rng.gen_bool(0.5) { l } else { -l };
+            vec![
+                IntPoint::new(x, y),
+                IntPoint::new(x, y + w),
+                IntPoint::new(x + l, y + dy + w),
+                IntPoint::new(x + l, y + dy),
+            ]
+        }).collect()
+    }
+
+    fn test_result(bank: &[Vec<IntShape>], result: &Vec<IntShape>) -> bool {
+        bank.iter().any(|item| item.are_equal(result))
+    }
+
+    #[test]
+    fn test_data() {
+        for strategy in [Strategy::List, Strategy::Tree] {
+            let solver = par_solver(strategy);
+            for index in 0..134 {
+                let test = Test::load(index);
+                let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+                let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths).into_graph_with_solver(fill_rule, solver);
+
+                assert!(test_result(&test.subject, &graph.extract_shapes(OverlayRule::Subject)), "test: {}", index);
+                assert!(test_result(&test.clip, &graph.extract_shapes(OverlayRule::Clip)), "test: {}", index);
+                assert!(test_result(&test.intersect, &graph.extract_shapes(OverlayRule::Intersect)), "test: {}", index);
+                assert!(test_result(&test.union, &graph.extract_shapes(OverlayRule::Union)), "test: {}", index);
+                assert!(test_result(&test.difference, &graph.extract_shapes(OverlayRule::Difference)), "test: {}", index);
+                assert!(test_result(&test.inverse_difference, &graph.extract_shapes(OverlayRule::InverseDifference)), "test: {}", index);
+                assert!(test_result(&test.xor, &graph.extract_shapes(OverlayRule::Xor)), "test: {}", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut subj = random_rects(&mut rng, 1500, 500);
+        subj.extend(random_strips(&mut rng, 20, 500));
+        let clip = random_rects(&mut rng, 1500, 500);
+        let overlay = Overlay::with_paths(&subj, &clip);
+
+        for strategy in [Strategy::List, Strategy::Tree] {
+            let single = Solver { strategy, multithreading: None, ..Solver::AUTO };
+            for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                let expected = overlay.clone().into_graph_with_solver(fill_rule, single);
+                let result = overlay.clone().into_graph_with_solver(fill_rule, par_solver(strategy));
+                for rule in RULES {
+                    assert_eq!(result.extract_shapes(rule), expected.extract_shapes(rule), "rule: {:?}", rule);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&empty, &empty).into_graph_with_solver(FillRule::NonZero, par_solver(Strategy::Auto));
+        assert!(graph.extract_shapes(OverlayRule::Union).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::group::overlay::GroupOverlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + height),
+            IntPoint::new(x + width, y + height),
+            IntPoint::new(x + width, y),
+        ]
+    }
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        rect(x, y, size, size)
+    }
+
+    #[test]
+    fn test_0() {
+        let mut overlay = GroupOverlay::new(12);
+        overlay.add_path(&square(0, 0, 20), 0);
+        overlay.add_path(&square(10, 0, 20), 1);
+        overlay.add_path(&square(20, 0, 20), 2);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let any = graph.extract_shapes(|mask| mask != 0);
+        assert!(any.are_equal(&vec![vec![rect(0, 0, 40, 20)]]));
+
+        let at_least_two = graph.extract_shapes(|mask| mask.count_ones() >= 2);
+        assert!(at_least_two.are_equal(&vec![vec![rect(10, 0, 20, 20)]]));
+
+        let all = graph.extract_shapes(|mask| mask == 0b111);
+        assert!(all.is_empty());
+
+        let only_middle = graph.extract_shapes(|mask| mask == 0b010);
+        assert!(only_middle.is_empty());
+    }
+
+    #[test]
+    fn test_1() {
+        let mut overlay = GroupOverlay::new(16);
+        overlay.add_path(&square(0, 0, 30), 0);
+        overlay.add_path(&square(10, 10, 30), 1);
+        overlay.add_path(&square(5, 5, 10), 15);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let hole = graph.extract_shapes(|mask| mask & 0b11 != 0 && mask & (1 << 15) == 0);
+        assert_eq!(hole.len(), 1);
+        assert_eq!(hole[0].len(), 2);
+        assert!(hole[0][1].are_equal(&vec![
+            IntPoint::new(5, 5),
+            IntPoint::new(15, 5),
+            IntPoint::new(15, 15),
+            IntPoint::new(5, 15),
+        ]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_2() {
+        let mut overlay = GroupOverlay::new(4);
+        overlay.add_path(&square(0, 0, 10), 16);
+    }
+
+    #[test]
+    fn test_two_groups() {
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+                let mut overlay = GroupOverlay::new(0);
+                overlay.add_paths(&test.subj_paths, 0);
+                overlay.add_paths(&test.clip_paths, 1);
+                let graph = overlay.into_graph_with_solver(fill_rule, solver);
+
+                let subject = graph.extract_shapes(|mask| mask & 0b01 != 0);
+                let clip = graph.extract_shapes(|mask| mask & 0b10 != 0);
+                let difference = graph.extract_shapes(|mask| mask == 0b01);
+                let inverse_difference = graph.extract_shapes(|mask| mask == 0b10);
+                let intersect = graph.extract_shapes(|mask| mask == 0b11);
+                let union = graph.extract_shapes(|mask| mask != 0);
+                let xor = graph.extract_shapes(|mask| mask == 0b01 || mask == 0b10);
+
+                assert!(test_result(&subject, &test.subject), "test: {}", index);
+                assert!(test_result(&clip, &test.clip), "test: {}", index);
+                assert!(test_result(&difference, &test.difference), "test: {}", index);
+                assert!(test_result(&inverse_difference, &test.inverse_difference), "test: {}", index);
+                assert!(test_result(&intersect, &test.intersect), "test: {}", index);
+                assert!(test_result(&union, &test.union), "test: {}", index);
+                assert!(test_result(&xor, &test.xor), "test: {}", index);
+            }
+        }
+    }
+
+    fn test_result(result: &Vec<IntShape>, bank: &[Vec<IntShape>]) -> bool {
+        bank.iter().any(|item| item.are_equal(result))
+    }
+}
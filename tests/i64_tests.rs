@@ -0,0 +1,219 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::{IntShape, IntShapes};
+    use i_float::f64_point::F64Point;
+    use i_overlay::core::error::OverlayError;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::i64::float::I64F64Overlay;
+    use i_overlay::i64::overlay::I64Overlay;
+    use i_overlay::i64::point::{I64Path, I64Point, I64Shapes};
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    fn to_i64(paths: &[Vec<IntPoint>]) -> Vec<I64Path> {
+        paths.iter().map(|path| path.iter().map(|p| I64Point::new(p.x as i64, p.y as i64)).collect()).collect()
+    }
+
+    fn to_int(shapes: I64Shapes) -> IntShapes {
+        shapes.into_iter().map(|shape| shape.into_iter().map(|path| {
+            path.into_iter().map(|p| IntPoint::new(p.x as i32, p.y as i32)).collect()
+        }).collect()).collect()
+    }
+
+    fn test_result(result: I64Shapes, bank: &[Vec<IntShape>]) -> bool {
+        let result = to_int(result);
+        bank.iter().any(|item| item.are_equal(&result))
+    }
+
+    fn square(x: i64, y: i64, size: i64) -> I64Path {
+        vec![
+            I64Point::new(x, y),
+            I64Point::new(x, y + size),
+            I64Point::new(x + size, y + size),
+            I64Point::new(x + size, y),
+        ]
+    }
+
+    #[test]
+    fn test_data() {
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = I64Overlay::with_paths(&to_i64(&test.subj_paths), &to_i64(&test.clip_paths))
+                .into_graph(fill_rule);
+
+            assert!(test_result(graph.extract_shapes(OverlayRule::Subject), &test.subject), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::Clip), &test.clip), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::Intersect), &test.intersect), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::Union), &test.union), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::Difference), &test.difference), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::InverseDifference), &test.inverse_difference), "test: {}", index);
+            assert!(test_result(graph.extract_shapes(OverlayRule::Xor), &test.xor), "test: {}", index);
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        // coordinates far out of i32 range
+        let s = 1 << 50;
+        let mut overlay = I64Overlay::new(8);
+        overlay.add_path(&square(s, s, 2 * s), ShapeType::Subject);
+        overlay.add_path(&square(2 * s, 2 * s, 2 * s), ShapeType::Clip);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let intersect = graph.extract_shapes(OverlayRule::Intersect);
+        assert_eq!(intersect, vec![vec![square(2 * s, 2 * s, s)]]);
+
+        let union = graph.extract_shapes(OverlayRule::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0][0].len(), 8);
+    }
+
+    #[test]
+    fn test_1() {
+        // a cross point which needs all 64 bits
+        let s = I64Point::MAX_COORD;
+        let subj = vec![I64Point::new(-s, -s / 2), I64Point::new(0, s), I64Point::new(s, -s / 2)];
+        let clip = vec![I64Point::new(-s, s / 2), I64Point::new(s, s / 2), I64Point::new(0, -s)];
+
+        let graph = I64Overlay::with_paths(&[subj], &[clip]).into_graph(FillRule::NonZero);
+        let intersect = graph.extract_shapes(OverlayRule::Intersect);
+
+        assert_eq!(intersect.len(), 1);
+        assert_eq!(intersect[0].len(), 1);
+        assert_eq!(intersect[0][0].len(), 6);
+        assert!(intersect[0][0].iter().any(|p| p.x.abs() > i32::MAX as i64));
+    }
+
+    #[test]
+    fn test_2() {
+        // holes are bound to the smallest shape around them
+        let s = 1 << 40;
+        let hole = |x, y, size| {
+            let mut path = square(x, y, size);
+            path.reverse();
+            path
+        };
+
+        let mut overlay = I64Overlay::new(20);
+        overlay.add_shape(&vec![square(0, 0, 10 * s), hole(2 * s, 2 * s, 6 * s)], ShapeType::Subject);
+        overlay.add_shape(&vec![square(3 * s, 3 * s, 4 * s), hole(4 * s, 4 * s, 2 * s)], ShapeType::Subject);
+        overlay.add_path(&square(20 * s, 0, s), ShapeType::Subject);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let mut shapes = graph.extract_shapes(OverlayRule::Subject);
+        shapes.sort_by(|a, b| a[0][0].cmp(&b[0][0]));
+
+        assert_eq!(shapes.len(), 3);
+        assert_eq!(shapes[0].len(), 2);
+        assert_eq!(shapes[0][1][0].x, 2 * s);
+        assert_eq!(shapes[1].len(), 2);
+        assert_eq!(shapes[1][1][0].x, 4 * s);
+        assert_eq!(shapes[2], vec![square(20 * s, 0, s)]);
+    }
+
+    #[test]
+    fn test_3() {
+        // a point out of range is reported instead of overflowing
+        let s = I64Point::MAX_COORD;
+        let mut overlay = I64Overlay::new(8);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Subject);
+        overlay.add_path(&square(s - 5, 0, 10), ShapeType::Clip);
+
+        assert_eq!(overlay.validate_range(), Err(OverlayError::I64CoordinateOverflow { x: s + 5, y: 10 }));
+
+        let result = overlay.try_into_graph(FillRule::NonZero);
+        assert_eq!(result.err(), Some(OverlayError::I64CoordinateOverflow { x: s + 5, y: 10 }));
+    }
+
+    #[test]
+    fn test_4() {
+        // the tree solvers give the same result as `Overlay` for a scaled grid
+        let s: i64 = 1 << 30;
+        let mut subj = Vec::new();
+        let mut clip = Vec::new();
+        for i in 0..40 {
+            for j in 0..40 {
+                subj.push(square(3 * i, 3 * j, 2));
+                clip.push(square(3 * i + 1, 3 * j + 1, 2));
+            }
+        }
+
+        let int_subj: Vec<Vec<IntPoint>> = subj.iter().map(|path| path.iter().map(|p| IntPoint::new(p.x as i32, p.y as i32)).collect()).collect();
+        let int_clip: Vec<Vec<IntPoint>> = clip.iter().map(|path| path.iter().map(|p| IntPoint::new(p.x as i32, p.y as i32)).collect()).collect();
+        let scale = |paths: Vec<I64Path>| -> Vec<I64Path> {
+            paths.into_iter().map(|path| path.into_iter().map(|p| I64Point::new(p.x * s, p.y * s)).collect()).collect()
+        };
+
+        let int_graph = Overlay::with_paths(&int_subj, &int_clip).into_graph_with_solver(FillRule::NonZero, Solver::TREE);
+        let graph = I64Overlay::with_paths(&scale(subj), &scale(clip)).into_graph_with_solver(FillRule::NonZero, Solver::TREE);
+
+        for rule in [OverlayRule::Union, OverlayRule::Intersect, OverlayRule::Xor] {
+            let expected: I64Shapes = int_graph.extract_shapes(rule).into_iter().map(|shape| {
+                shape.into_iter().map(|path| path.into_iter().map(|p| I64Point::new(p.x as i64 * s, p.y as i64 * s)).collect()).collect()
+            }).collect();
+
+            assert_eq!(graph.extract_shapes(rule), expected);
+        }
+    }
+
+    #[test]
+    fn test_5() {
+        // every hole is bound to a shape, none of them is dropped
+        let s = 1 << 40;
+        let mut hole = vec![
+            I64Point::new(2 * s, 2 * s),
+            I64Point::new(3 * s, 5 * s),
+            I64Point::new(4 * s, 2 * s),
+        ];
+        hole.reverse();
+
+        let mut overlay = I64Overlay::new(16);
+        overlay.add_path(&square(0, 0, 10 * s), ShapeType::Subject);
+        overlay.add_path(&hole, ShapeType::Subject);
+        overlay.add_path(&square(20 * s, 0, 10 * s), ShapeType::Subject);
+
+        let shapes = overlay.try_into_graph(FillRule::EvenOdd).unwrap().try_extract_shapes(OverlayRule::Subject).unwrap();
+
+        assert_eq!(shapes.len(), 2);
+        assert_eq!(shapes.iter().map(|shape| shape.len() - 1).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_6() {
+        // the f64 API keeps far more precision than `F64Overlay`
+        let square = |x: f64, y: f64, size: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ];
+
+        let subj = vec![square(0.0, 0.0, 1.0)];
+        let clip = vec![square(1e-12, 1e-12, 1.0)];
+
+        let shapes = I64F64Overlay::with_paths(subj, clip)
+            .into_graph(FillRule::NonZero)
+            .extract_shapes(OverlayRule::Difference);
+
+        assert_eq!(shapes.len(), 1);
+        assert_eq!(shapes[0][0].len(), 6);
+    }
+
+    #[test]
+    fn test_7() {
+        let subj = vec![vec![F64Point::new(0.0, 0.0), F64Point::new(f64::NAN, 1.0), F64Point::new(1.0, 0.0)]];
+
+        let result = I64F64Overlay::with_paths(subj, vec![]).try_into_graph(FillRule::NonZero);
+
+        assert!(matches!(result, Err(OverlayError::NonFiniteInput)));
+    }
+}
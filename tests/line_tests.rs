@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_overlay::core::context::OverlayContext;
+    use i_overlay::core::error::OverlayError;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::f64::overlay::F64Overlay;
+    use i_overlay::line::extract::LinePosition;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn path(points: &[[i32; 2]]) -> Vec<IntPoint> {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    fn sorted(paths: Vec<Vec<IntPoint>>) -> Vec<Vec<IntPoint>> {
+        let mut paths = paths;
+        paths.sort_by(|a, b| a[0].cmp(&b[0]));
+        paths
+    }
+
+    #[test]
+    fn test_0() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(4);
+            overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+            overlay.add_polyline(&path(&[[-10, 10], [30, 10]]));
+
+            let graph = overlay.into_graph_with_solver(FillRule::NonZero, solver);
+
+            let inside = graph.extract_lines(OverlayRule::Subject, LinePosition::Inside);
+            assert_eq!(inside, vec![path(&[[0, 10], [20, 10]])]);
+
+            let outside = sorted(graph.extract_lines(OverlayRule::Subject, LinePosition::Outside));
+            assert_eq!(outside, vec![path(&[[-10, 10], [0, 10]]), path(&[[20, 10], [30, 10]])]);
+
+            let boundary = graph.extract_lines(OverlayRule::Subject, LinePosition::Boundary);
+            assert!(boundary.is_empty());
+
+            // polylines do not change the shapes
+            assert_eq!(graph.extract_shapes(OverlayRule::Subject), vec![vec![square(0, 0, 20)]]);
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        // a polyline on the edge of a shape keeps its direction
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[30, 0], [-10, 0]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let boundary = graph.extract_lines(OverlayRule::Subject, LinePosition::Boundary);
+        assert_eq!(boundary, vec![path(&[[20, 0], [0, 0]])]);
+
+        let outside = sorted(graph.extract_lines(OverlayRule::Subject, LinePosition::Outside));
+        assert_eq!(outside, vec![path(&[[0, 0], [-10, 0]]), path(&[[30, 0], [20, 0]])]);
+
+        assert_eq!(graph.extract_shapes(OverlayRule::Subject), vec![vec![square(0, 0, 20)]]);
+    }
+
+    #[test]
+    fn test_2() {
+        // pieces are joined across the vertices of a polyline
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[5, -5], [5, 5], [15, 5], [15, -5]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let inside = graph.extract_lines(OverlayRule::Subject, LinePosition::Inside);
+        assert_eq!(inside, vec![path(&[[5, 0], [5, 5], [15, 5], [15, 0]])]);
+    }
+
+    #[test]
+    fn test_3() {
+        // the area is defined by the overlay rule
+        let mut overlay = Overlay::new(8);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_path(&square(10, -10, 20), ShapeType::Clip);
+        overlay.add_polyline(&path(&[[5, 5], [25, 5]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let difference = graph.extract_lines(OverlayRule::Difference, LinePosition::Inside);
+        assert_eq!(difference, vec![path(&[[5, 5], [10, 5]])]);
+
+        let intersect = graph.extract_lines(OverlayRule::Intersect, LinePosition::Inside);
+        assert_eq!(intersect, vec![path(&[[10, 5], [20, 5]])]);
+
+        let union = graph.extract_lines(OverlayRule::Union, LinePosition::Inside);
+        // the points where the polyline crosses the edges stay in the result
+        assert_eq!(union, vec![path(&[[5, 5], [10, 5], [20, 5], [25, 5]])]);
+    }
+
+    #[test]
+    fn test_4() {
+        // a closed chain repeats its first point, a polyline without shapes is outside
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[5, 5], [15, 5], [15, 15], [5, 15], [5, 5]]));
+        overlay.add_polyline(&path(&[[30, 0], [40, 0]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let inside = graph.extract_lines(OverlayRule::Subject, LinePosition::Inside);
+        assert_eq!(inside.len(), 1);
+        assert_eq!(inside[0].len(), 5);
+        assert_eq!(inside[0].first(), inside[0].last());
+
+        let outside = graph.extract_lines(OverlayRule::Subject, LinePosition::Outside);
+        assert_eq!(outside, vec![path(&[[30, 0], [40, 0]])]);
+    }
+
+    #[test]
+    fn test_5() {
+        let mut overlay = Overlay::new(0);
+        overlay.add_polyline(&path(&[[0, 0], [10, 0], [10, 10]]));
+        overlay.add_polyline(&path(&[[0, 0]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        assert!(graph.extract_shapes(OverlayRule::Union).is_empty());
+        assert!(graph.extract_lines(OverlayRule::Union, LinePosition::Inside).is_empty());
+        assert_eq!(graph.extract_lines(OverlayRule::Union, LinePosition::Outside), vec![path(&[[0, 0], [10, 0], [10, 10]])]);
+    }
+
+    #[test]
+    fn test_6() {
+        let mut overlay = F64Overlay::with_paths(vec![vec![
+            F64Point::new(0.0, 0.0),
+            F64Point::new(0.0, 1.0),
+            F64Point::new(1.0, 1.0),
+            F64Point::new(1.0, 0.0),
+        ]], vec![]);
+        overlay.add_polyline(vec![F64Point::new(-1.0, 0.5), F64Point::new(2.0, 0.5)]);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let inside = graph.extract_lines(OverlayRule::Subject, LinePosition::Inside);
+
+        assert_eq!(inside.len(), 1);
+        assert_eq!(inside[0].len(), 2);
+        assert!((inside[0][0].x - 0.0).abs() < 0.001 && (inside[0][0].y - 0.5).abs() < 0.001);
+        assert!((inside[0][1].x - 1.0).abs() < 0.001 && (inside[0][1].y - 0.5).abs() < 0.001);
+
+        let outside = graph.extract_lines(OverlayRule::Subject, LinePosition::Outside);
+        assert_eq!(outside.len(), 2);
+    }
+
+    #[test]
+    fn test_7() {
+        // overlapping polylines are merged into one piece
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[-10, 10], [30, 10]]));
+        overlay.add_polyline(&path(&[[-10, 10], [30, 10]]));
+        overlay.add_polyline(&path(&[[25, 10], [5, 10]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let inside = graph.extract_lines(OverlayRule::Subject, LinePosition::Inside);
+        assert_eq!(inside, vec![path(&[[0, 10], [5, 10], [20, 10]])]);
+    }
+
+    fn overlay_with_polyline() -> Overlay {
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[-10, 10], [30, 10]]));
+        overlay
+    }
+
+    #[test]
+    #[should_panic(expected = "polylines are not supported")]
+    fn test_8() {
+        overlay_with_polyline().into_coverage_graph();
+    }
+
+    #[test]
+    fn test_9() {
+        // the check does not depend on debug assertions, so polylines are never dropped silently
+        let mut context = OverlayContext::new(Solver::AUTO);
+        let result = overlay_with_polyline().build_graph(&mut context, FillRule::NonZero);
+        assert_eq!(result.err(), Some(OverlayError::UnsupportedPolylines));
+
+        let conversions: [fn(Overlay); 3] = [
+            |overlay| { overlay.into_incremental_graph(FillRule::NonZero); },
+            |overlay| { overlay.into_separate_vectors(FillRule::NonZero, Solver::AUTO); },
+            |overlay| { overlay.into_shape_vectors(FillRule::NonZero, OverlayRule::Subject, Solver::AUTO); },
+        ];
+        for conversion in conversions {
+            let overlay = overlay_with_polyline();
+            assert!(std::panic::catch_unwind(move || conversion(overlay)).is_err());
+        }
+    }
+}
@@ -0,0 +1,96 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_shape::f64::shape::F64Shape;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::metrics::{AreaMetrics, Metrics};
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::{rect, shapes_area};
+
+    fn square(x: f64, y: f64, size: f64) -> F64Shape {
+        vec![vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ]]
+    }
+
+    fn test_area(bank: &[Vec<IntShape>], area: f64) -> bool {
+        bank.iter().any(|shapes| shapes_area(shapes) == area)
+    }
+
+    #[test]
+    fn test_data() {
+        for (index, test) in Test::load_all() {
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+            let metrics = graph.metrics();
+
+            assert_eq!(metrics.subj_area, graph.area(OverlayRule::Subject), "test: {}", index);
+            assert_eq!(metrics.clip_area, graph.area(OverlayRule::Clip), "test: {}", index);
+            assert_eq!(metrics.intersect_area, graph.area(OverlayRule::Intersect), "test: {}", index);
+            assert_eq!(metrics.union_area, graph.area(OverlayRule::Union), "test: {}", index);
+            assert_eq!(metrics.symmetric_difference_area(), graph.area(OverlayRule::Xor), "test: {}", index);
+
+            assert!(test_area(&test.subject, metrics.subj_area), "test: {}", index);
+            assert!(test_area(&test.clip, metrics.clip_area), "test: {}", index);
+            assert!(test_area(&test.intersect, metrics.intersect_area), "test: {}", index);
+            assert!(test_area(&test.union, metrics.union_area), "test: {}", index);
+            assert!(test_area(&test.difference, metrics.subj_area - metrics.intersect_area), "test: {}", index);
+            assert!(test_area(&test.inverse_difference, metrics.clip_area - metrics.intersect_area), "test: {}", index);
+            assert!(test_area(&test.xor, metrics.symmetric_difference_area()), "test: {}", index);
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        let a: Vec<IntShape> = vec![vec![rect(0, 0, 10, 10)]];
+        let b: Vec<IntShape> = vec![vec![rect(5, 0, 10, 10)]];
+        let metrics = a.metrics(&b, FillRule::NonZero);
+        assert_eq!(metrics, AreaMetrics { subj_area: 100.0, clip_area: 100.0, intersect_area: 50.0, union_area: 150.0 });
+        assert_eq!(metrics.iou(), 1.0 / 3.0);
+        assert_eq!(metrics.dice(), 0.5);
+        assert_eq!(metrics.symmetric_difference_area(), 100.0);
+
+        // touching shapes do not intersect
+        let b: Vec<IntShape> = vec![vec![rect(10, 0, 10, 10)]];
+        let metrics = a.metrics(&b, FillRule::NonZero);
+        assert_eq!(metrics.intersect_area, 0.0);
+        assert_eq!(metrics.union_area, 200.0);
+        assert_eq!(metrics.iou(), 0.0);
+
+        // the same shape split into two parts with a common edge
+        let b: Vec<IntShape> = vec![vec![rect(0, 0, 5, 10)], vec![rect(5, 0, 5, 10)]];
+        let metrics = a.metrics(&b, FillRule::NonZero);
+        assert_eq!(metrics.iou(), 1.0);
+        assert_eq!(metrics.dice(), 1.0);
+        assert_eq!(metrics.symmetric_difference_area(), 0.0);
+
+        let empty: Vec<IntShape> = Vec::new();
+        assert_eq!(a.metrics(&empty, FillRule::NonZero).iou(), 0.0);
+        assert_eq!(empty.metrics(&empty, FillRule::NonZero).iou(), 1.0);
+    }
+
+    #[test]
+    fn test_1() {
+        let a = vec![square(0.0, 0.0, 1.0)];
+        let b = vec![square(0.5, 0.0, 1.0)];
+        let metrics = a.metrics(&b, FillRule::NonZero);
+        assert_eq!(metrics, AreaMetrics { subj_area: 1.0, clip_area: 1.0, intersect_area: 0.5, union_area: 1.5 });
+
+        let graph = F64Overlay::with_shapes(a.clone(), b.clone()).into_graph(FillRule::NonZero);
+        assert_eq!(graph.metrics(), metrics);
+
+        let empty: Vec<F64Shape> = Vec::new();
+        assert_eq!(empty.metrics(&empty, FillRule::NonZero), AreaMetrics::default());
+        assert_eq!(a.metrics(&empty, FillRule::NonZero).subj_area, 1.0);
+    }
+}
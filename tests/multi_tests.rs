@@ -0,0 +1,134 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::CircleCompare;
+
+    fn test_result(result: &Vec<IntShape>, bank: &[Vec<IntShape>]) -> bool {
+        bank.iter().any(|item| item.are_equal(result))
+    }
+
+    #[test]
+    fn test_data() {
+        let single_thread = Solver { multithreading: None, ..Solver::AUTO };
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            for solver in [Solver::LIST, Solver::TREE, Solver::AUTO, single_thread] {
+                let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths)
+                    .into_graph_with_solver(fill_rule, solver);
+
+                let rules = [
+                    OverlayRule::Union,
+                    OverlayRule::Subject,
+                    OverlayRule::Clip,
+                    OverlayRule::Intersect,
+                    OverlayRule::Difference,
+                    OverlayRule::InverseDifference,
+                    OverlayRule::Xor,
+                    OverlayRule::Union,
+                ];
+                let result = graph.extract_shapes_multi(&rules);
+
+                assert_eq!(result.len(), 8);
+                for (shapes, rule) in result.iter().zip(rules) {
+                    assert_eq!(shapes, &graph.extract_shapes(rule), "test: {}, rule: {:?}", index, rule);
+                }
+                assert!(test_result(&result[0], &test.union), "test: {}", index);
+                assert!(test_result(&result[1], &test.subject), "test: {}", index);
+                assert!(test_result(&result[2], &test.clip), "test: {}", index);
+                assert!(test_result(&result[3], &test.intersect), "test: {}", index);
+                assert!(test_result(&result[4], &test.difference), "test: {}", index);
+                assert!(test_result(&result[5], &test.inverse_difference), "test: {}", index);
+                assert!(test_result(&result[6], &test.xor), "test: {}", index);
+                assert_eq!(result[7], result[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let graph = Overlay::new(0).into_graph(FillRule::NonZero);
+        assert!(graph.extract_shapes_multi(&[]).is_empty());
+
+        let result = graph.extract_shapes_multi(&[OverlayRule::Union, OverlayRule::Xor]);
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|shapes| shapes.is_empty()));
+    }
+
+    #[test]
+    fn test_holes() {
+        let square = |x: i32, y: i32, size: i32| vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ];
+
+        // 4 outer boundaries with 100 holes each, some holes touch each other at a corner
+        let mut subj = Vec::new();
+        let mut clip = Vec::new();
+        for i in 0..4 {
+            let x0 = 50 * (i % 2);
+            let y0 = 50 * (i / 2);
+            subj.push(square(x0, y0, 45));
+            for j in 0..100 {
+                let x = x0 + 2 + 4 * (j % 10);
+                let y = y0 + 2 + 4 * (j / 10) + (j % 3 == 0) as i32;
+                clip.push(square(x, y, 2 + j % 2));
+            }
+        }
+
+        let graph = Overlay::with_paths(&subj, &clip).into_graph(FillRule::NonZero);
+        let rules = [OverlayRule::Difference, OverlayRule::Union, OverlayRule::Xor, OverlayRule::Intersect];
+        let result = graph.extract_shapes_multi(&rules);
+
+        for (shapes, rule) in result.iter().zip(rules) {
+            assert_eq!(shapes, &graph.extract_shapes(rule), "rule: {:?}", rule);
+        }
+        assert_eq!(result[0].len(), 4);
+    }
+
+    #[test]
+    fn test_f64() {
+        let subj = vec![
+            F64Point::new(0.0, 0.0),
+            F64Point::new(0.0, 1.0),
+            F64Point::new(1.0, 1.0),
+            F64Point::new(1.0, 0.0),
+        ];
+        let clip = vec![
+            F64Point::new(0.5, 0.0),
+            F64Point::new(0.5, 1.0),
+            F64Point::new(1.5, 1.0),
+            F64Point::new(1.5, 0.0),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![subj], vec![clip]).into_graph(FillRule::NonZero);
+        let rules = [OverlayRule::Union, OverlayRule::Intersect, OverlayRule::Xor];
+        let result = graph.extract_shapes_multi(&rules);
+
+        assert_eq!(result.len(), 3);
+        for (shapes, rule) in result.iter().zip(rules) {
+            let expected = graph.extract_shapes(rule);
+            assert_eq!(shapes.len(), expected.len());
+            for (shape, expected_shape) in shapes.iter().zip(expected.iter()) {
+                assert_eq!(shape.len(), expected_shape.len());
+                for (path, expected_path) in shape.iter().zip(expected_shape.iter()) {
+                    assert!(path.iter().zip(expected_path.iter()).all(|(a, b)| a.x == b.x && a.y == b.y));
+                }
+            }
+        }
+        assert_eq!(result[2].len(), 2);
+    }
+}
@@ -0,0 +1,168 @@
+mod data;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::ShapeType;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::origin::edge::{EdgeOrigin, OriginEdge, OriginShape};
+    use i_overlay::origin::overlay::OriginOverlay;
+    use crate::data::overlay::Test;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn subj(path: usize, edge: usize) -> EdgeOrigin {
+        EdgeOrigin { shape_type: ShapeType::Subject, path, edge }
+    }
+
+    fn clip(path: usize, edge: usize) -> EdgeOrigin {
+        EdgeOrigin { shape_type: ShapeType::Clip, path, edge }
+    }
+
+    fn area(shape: &OriginShape) -> i64 {
+        shape.iter()
+            .map(|path| path.iter().map(|edge| edge.a).collect::<IntPath>().unsafe_area())
+            .sum()
+    }
+
+    fn find(shapes: &[OriginShape], a: IntPoint, b: IntPoint) -> &OriginEdge {
+        shapes.iter().flatten().flatten()
+            .find(|edge| edge.a == a && edge.b == b || edge.a == b && edge.b == a)
+            .unwrap()
+    }
+
+    // intersection points are rounded to the grid, so a split edge may deviate by a couple of units
+    fn is_on_edge(p: IntPoint, p0: IntPoint, p1: IntPoint) -> bool {
+        let (dx, dy) = ((p1.x - p0.x) as f64, (p1.y - p0.y) as f64);
+        let (px, py) = ((p.x - p0.x) as f64, (p.y - p0.y) as f64);
+        let distance = (dx * py - dy * px).abs() / (dx * dx + dy * dy).sqrt();
+
+        distance <= 2.0
+            && p.x + 2 >= p0.x.min(p1.x) && p.x - 2 <= p0.x.max(p1.x)
+            && p.y + 2 >= p0.y.min(p1.y) && p.y - 2 <= p0.y.max(p1.y)
+    }
+
+    fn validate(shapes: &[OriginShape], subj_paths: &[IntPath], clip_paths: &[IntPath]) {
+        for edge in shapes.iter().flatten().flatten() {
+            assert!(!edge.origins.is_empty());
+            for origin in edge.origins.iter() {
+                let path = match origin.shape_type {
+                    ShapeType::Subject => &subj_paths[origin.path],
+                    ShapeType::Clip => &clip_paths[origin.path],
+                };
+                let p0 = path[origin.edge];
+                let p1 = path[(origin.edge + 1) % path.len()];
+                assert!(is_on_edge(edge.a, p0, p1) && is_on_edge(edge.b, p0, p1), "{:?} {:?} {:?} {:?}", edge, origin, p0, p1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        let subj_paths = vec![square(0, 0, 20)];
+        let clip_paths = vec![square(10, 10, 20)];
+        let graph = OriginOverlay::with_paths(&subj_paths, &clip_paths).into_graph(FillRule::NonZero);
+
+        let union = graph.extract_shape_origins(OverlayRule::Union);
+        validate(&union, &subj_paths, &clip_paths);
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(find(&union, IntPoint::new(0, 0), IntPoint::new(0, 20)).origins, vec![subj(0, 0)]);
+        assert_eq!(find(&union, IntPoint::new(10, 20), IntPoint::new(10, 30)).origins, vec![clip(0, 0)]);
+        assert_eq!(find(&union, IntPoint::new(20, 10), IntPoint::new(30, 10)).origins, vec![clip(0, 3)]);
+
+        let intersect = graph.extract_shape_origins(OverlayRule::Intersect);
+        validate(&intersect, &subj_paths, &clip_paths);
+
+        assert_eq!(find(&intersect, IntPoint::new(10, 10), IntPoint::new(10, 20)).origins, vec![clip(0, 0)]);
+        assert_eq!(find(&intersect, IntPoint::new(10, 20), IntPoint::new(20, 20)).origins, vec![subj(0, 1)]);
+    }
+
+    #[test]
+    fn test_1() {
+        // collinear points are kept, so edge indices refer to the original path
+        let subj_paths = vec![vec![
+            IntPoint::new(0, 0),
+            IntPoint::new(0, 10),
+            IntPoint::new(0, 20),
+            IntPoint::new(20, 20),
+            IntPoint::new(20, 0),
+        ]];
+        let clip_paths = vec![square(0, 0, 20), square(-10, 5, 5)];
+        let graph = OriginOverlay::with_paths(&subj_paths, &clip_paths).into_graph(FillRule::NonZero);
+
+        let union = graph.extract_shape_origins(OverlayRule::Union);
+        validate(&union, &subj_paths, &clip_paths);
+
+        assert_eq!(union.len(), 2);
+        assert_eq!(find(&union, IntPoint::new(0, 10), IntPoint::new(0, 20)).origins, vec![subj(0, 1), clip(0, 0)]);
+        assert_eq!(find(&union, IntPoint::new(20, 20), IntPoint::new(20, 0)).origins, vec![subj(0, 3), clip(0, 2)]);
+        assert_eq!(find(&union, IntPoint::new(-10, 5), IntPoint::new(-10, 10)).origins, vec![clip(1, 0)]);
+    }
+
+    #[test]
+    fn test_2() {
+        let graph = OriginOverlay::new(0).into_graph(FillRule::NonZero);
+        assert!(graph.extract_shape_origins(OverlayRule::Union).is_empty());
+    }
+
+    #[test]
+    fn test_3() {
+        // the same edge comes from four paths, origins stay sorted however the paths are added
+        let mut overlay = OriginOverlay::new(16);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Clip);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Subject);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Clip);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Subject);
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let union = graph.extract_shape_origins(OverlayRule::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(find(&union, IntPoint::new(0, 0), IntPoint::new(0, 10)).origins, vec![subj(0, 0), subj(1, 0), clip(0, 0), clip(1, 0)]);
+    }
+
+    #[test]
+    fn test_data() {
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+
+            for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+                let graph = OriginOverlay::with_paths(&test.subj_paths, &test.clip_paths)
+                    .into_graph_with_solver(fill_rule, solver);
+
+                let banks = [
+                    (OverlayRule::Subject, &test.subject),
+                    (OverlayRule::Clip, &test.clip),
+                    (OverlayRule::Intersect, &test.intersect),
+                    (OverlayRule::Union, &test.union),
+                    (OverlayRule::Difference, &test.difference),
+                    (OverlayRule::InverseDifference, &test.inverse_difference),
+                    (OverlayRule::Xor, &test.xor),
+                ];
+
+                for (rule, bank) in banks {
+                    let shapes = graph.extract_shape_origins(rule);
+                    validate(&shapes, &test.subj_paths, &test.clip_paths);
+
+                    let result: i64 = shapes.iter().map(area).sum();
+                    let is_expected = bank.iter().any(|shapes: &Vec<IntShape>| {
+                        shapes.iter().flatten().map(|path| path.unsafe_area()).sum::<i64>() == result
+                    });
+                    assert!(is_expected, "test: {}, rule: {:?}", index, rule);
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison, clippy::ptr_arg)]
+
 mod data;
 mod util;
 
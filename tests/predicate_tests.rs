@@ -0,0 +1,154 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_shape::int::path::IntPath;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_graph::OverlayGraph;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::predicate::{Dimension, Location, Predicate};
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::util::overlay::rect;
+
+    const PREDICATES: [Predicate; 9] = [
+        Predicate::Intersects,
+        Predicate::Disjoint,
+        Predicate::Touches,
+        Predicate::Within,
+        Predicate::Contains,
+        Predicate::CoveredBy,
+        Predicate::Covers,
+        Predicate::Equals,
+        Predicate::Overlaps,
+    ];
+
+    fn graph(subj: &[IntPath], clip: &[IntPath]) -> OverlayGraph {
+        Overlay::with_paths(subj, clip).into_graph(FillRule::NonZero)
+    }
+
+    fn assert_predicates(graph: &OverlayGraph, expected: &[Predicate]) {
+        for predicate in PREDICATES {
+            let is_expected = expected.contains(&predicate);
+            assert_eq!(graph.satisfies(predicate), is_expected, "predicate: {:?}", predicate);
+            assert_eq!(graph.relate().is(predicate), is_expected, "predicate: {:?}", predicate);
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        // far from each other
+        let graph = graph(&[rect(0, 0, 10, 10)], &[rect(20, 0, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "FF2FF1212");
+        assert_predicates(&graph, &[Predicate::Disjoint]);
+
+        // a common side
+        let graph = self::graph(&[rect(0, 0, 10, 10)], &[rect(10, 0, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "FF2F11212");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Touches]);
+
+        // a common corner
+        let graph = self::graph(&[rect(0, 0, 10, 10)], &[rect(10, 10, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "FF2F01212");
+        assert_eq!(graph.relate().get(Location::Boundary, Location::Boundary), Dimension::Point);
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Touches]);
+    }
+
+    #[test]
+    fn test_1() {
+        let graph = graph(&[rect(0, 0, 10, 10)], &[rect(5, 5, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "212101212");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Overlaps]);
+
+        // strictly inside
+        let graph = self::graph(&[rect(2, 2, 4, 4)], &[rect(0, 0, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "2FF1FF212");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Within, Predicate::CoveredBy]);
+
+        // inside with a common corner and two half sides
+        let graph = self::graph(&[rect(0, 0, 10, 10)], &[rect(0, 0, 5, 5)]);
+        assert_eq!(graph.relate().to_string(), "212F11FF2");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Contains, Predicate::Covers]);
+
+        let graph = self::graph(&[rect(0, 0, 10, 10)], &[rect(0, 0, 10, 10)]);
+        assert_eq!(graph.relate().to_string(), "2FFF1FFF2");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Within, Predicate::Contains, Predicate::CoveredBy, Predicate::Covers, Predicate::Equals]);
+    }
+
+    #[test]
+    fn test_2() {
+        // the clip is in the hole of the subject
+        let mut hole = rect(2, 2, 6, 6);
+        hole.reverse();
+        let graph = graph(&[rect(0, 0, 10, 10), hole.clone()], &[rect(3, 3, 2, 2)]);
+        assert_eq!(graph.relate().to_string(), "FF2FF1212");
+        assert_predicates(&graph, &[Predicate::Disjoint]);
+
+        // the clip fills the hole
+        let graph = self::graph(&[rect(0, 0, 10, 10), hole], &[rect(2, 2, 6, 6)]);
+        assert_eq!(graph.relate().to_string(), "FF2F112F2");
+        assert_predicates(&graph, &[Predicate::Intersects, Predicate::Touches]);
+
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = self::graph(&[rect(0, 0, 10, 10)], &empty);
+        assert_eq!(graph.relate().to_string(), "FF2FF1FF2");
+        assert!(graph.satisfies(Predicate::Disjoint));
+        assert!(!graph.satisfies(Predicate::Covers));
+    }
+
+    #[test]
+    fn test_3() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        for _ in 0..200 {
+            let mut random_rects = |count: usize| -> Vec<IntPath> {
+                (0..count).map(|_| {
+                    let x = 2 * rng.gen_range(0..16);
+                    let y = 2 * rng.gen_range(0..16);
+                    let w = 2 * rng.gen_range(1..8);
+                    let h = 2 * rng.gen_range(1..8);
+                    rect(x, y, w, h)
+                }).collect()
+            };
+
+            let subj = random_rects(3);
+            let clip = random_rects(3);
+            let graph = graph(&subj, &clip);
+            let matrix = graph.relate();
+
+            for predicate in PREDICATES {
+                assert_eq!(graph.satisfies(predicate), matrix.is(predicate), "predicate: {:?}, matrix: {}", predicate, matrix);
+            }
+
+            // compare with the result shapes
+            let is_intersect = !graph.extract_shapes(OverlayRule::Intersect).is_empty();
+            let is_subj_rest = !graph.extract_shapes(OverlayRule::Difference).is_empty();
+            let is_clip_rest = !graph.extract_shapes(OverlayRule::InverseDifference).is_empty();
+
+            assert_eq!(matrix.get(Location::Interior, Location::Interior) == Dimension::Area, is_intersect);
+            assert_eq!(matrix.get(Location::Interior, Location::Exterior) == Dimension::Area, is_subj_rest);
+            assert_eq!(matrix.get(Location::Exterior, Location::Interior) == Dimension::Area, is_clip_rest);
+            assert_eq!(matrix.is(Predicate::CoveredBy), !is_subj_rest);
+            assert_eq!(matrix.is(Predicate::Covers), !is_clip_rest);
+        }
+    }
+
+    #[test]
+    fn test_4() {
+        let square = |x: f64, y: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + 1.0),
+            F64Point::new(x + 1.0, y + 1.0),
+            F64Point::new(x + 1.0, y),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0)], vec![square(1.0, 0.5)]).into_graph(FillRule::NonZero);
+        assert_eq!(graph.relate().to_string(), "FF2F11212");
+        assert!(graph.satisfies(Predicate::Touches));
+
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0)], vec![square(0.5, 0.5)]).into_graph(FillRule::NonZero);
+        assert!(graph.satisfies(Predicate::Overlaps));
+        assert!(graph.relate().matches("T*T***T**"));
+    }
+}
@@ -0,0 +1,202 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_shape::int::shape::IntShape;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::query::PointFill;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::util::overlay::{rect, RULES};
+
+    fn shapes_contain(shapes: &[IntShape], p: IntPoint) -> bool {
+        shapes.iter().any(|shape| shape[0].contains(p) && !shape[1..].iter().any(|hole| hole.contains(p)))
+    }
+
+    #[test]
+    fn test_0() {
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(5, 5, 10, 10)]).into_graph(FillRule::NonZero);
+
+        assert_eq!(graph.fill_at(IntPoint::new(2, 2)), PointFill { subj: true, clip: false, is_boundary: false });
+        assert_eq!(graph.fill_at(IntPoint::new(7, 7)), PointFill { subj: true, clip: true, is_boundary: false });
+        assert_eq!(graph.fill_at(IntPoint::new(12, 12)), PointFill { subj: false, clip: true, is_boundary: false });
+        assert_eq!(graph.fill_at(IntPoint::new(20, 20)), PointFill { subj: false, clip: false, is_boundary: false });
+        assert_eq!(graph.fill_at(IntPoint::new(-1, 5)), PointFill { subj: false, clip: false, is_boundary: false });
+
+        // the right side of the subject inside the clip
+        assert_eq!(graph.fill_at(IntPoint::new(10, 7)), PointFill { subj: true, clip: true, is_boundary: true });
+        // a corner of the subject
+        assert_eq!(graph.fill_at(IntPoint::new(0, 0)), PointFill { subj: true, clip: false, is_boundary: true });
+
+        assert!(graph.contains(IntPoint::new(7, 7), OverlayRule::Intersect));
+        assert!(!graph.contains(IntPoint::new(7, 7), OverlayRule::Xor));
+        assert!(graph.contains(IntPoint::new(2, 2), OverlayRule::Difference));
+        assert!(!graph.contains(IntPoint::new(12, 12), OverlayRule::Difference));
+        assert!(graph.contains(IntPoint::new(12, 12), OverlayRule::Union));
+
+        // the point is on the boundary of the intersection, but not of the difference
+        assert!(graph.contains(IntPoint::new(10, 7), OverlayRule::Intersect));
+        assert!(graph.contains(IntPoint::new(10, 7), OverlayRule::Union));
+        assert!(!graph.contains(IntPoint::new(10, 7), OverlayRule::Difference));
+    }
+
+    #[test]
+    fn test_1() {
+        // a diamond with a square hole
+        let diamond = vec![
+            IntPoint::new(-10, 0),
+            IntPoint::new(0, 10),
+            IntPoint::new(10, 0),
+            IntPoint::new(0, -10),
+        ];
+        let mut hole = rect(-2, -2, 4, 4);
+        hole.reverse();
+
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&[diamond, hole], &empty).into_graph(FillRule::EvenOdd);
+
+        assert!(graph.contains(IntPoint::new(-5, 0), OverlayRule::Subject));
+        assert!(graph.contains(IntPoint::new(5, 4), OverlayRule::Subject));
+        assert!(graph.contains(IntPoint::new(5, 5), OverlayRule::Subject));
+        assert!(graph.fill_at(IntPoint::new(5, 5)).is_boundary);
+        assert!(!graph.contains(IntPoint::new(5, 6), OverlayRule::Subject));
+        assert!(!graph.contains(IntPoint::new(0, 0), OverlayRule::Subject));
+        assert!(graph.contains(IntPoint::new(2, 0), OverlayRule::Subject));
+        assert!(!graph.contains(IntPoint::new(0, 0), OverlayRule::Clip));
+
+        let empty_graph = Overlay::with_paths(&empty, &empty).into_graph(FillRule::EvenOdd);
+        assert_eq!(empty_graph.fill_at(IntPoint::new(0, 0)), PointFill { subj: false, clip: false, is_boundary: false });
+    }
+
+    #[test]
+    fn test_2() {
+        // random rects on an even grid, the queried points are odd, so they never lie on a boundary
+        let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+        for _ in 0..50 {
+            let mut random_rects = |count: usize| -> Vec<IntPath> {
+                (0..count).map(|_| {
+                    let x = 2 * rng.gen_range(0..20);
+                    let y = 2 * rng.gen_range(0..20);
+                    let w = 2 * rng.gen_range(1..10);
+                    let h = 2 * rng.gen_range(1..10);
+                    rect(x, y, w, h)
+                }).collect()
+            };
+
+            let subj = random_rects(8);
+            let clip = random_rects(8);
+
+            for fill_rule in [FillRule::EvenOdd, FillRule::NonZero, FillRule::Positive, FillRule::Negative] {
+                let graph = Overlay::with_paths(&subj, &clip).into_graph(fill_rule);
+                for rule in RULES {
+                    let shapes = graph.extract_shapes(rule);
+                    for x in (-1..60).step_by(4) {
+                        for y in (-1..60).step_by(4) {
+                            let p = IntPoint::new(x, y);
+                            assert_eq!(graph.contains(p, rule), shapes_contain(&shapes, p), "point: {:?}, rule: {:?}", p, rule);
+                        }
+                    }
+                }
+
+                for x in (1..60).step_by(6) {
+                    for y in (1..60).step_by(6) {
+                        let p = IntPoint::new(x, y);
+                        let fill = graph.fill_at(p);
+                        assert!(!fill.is_boundary);
+                        assert_eq!(fill.subj, graph.contains(p, OverlayRule::Subject));
+                        assert_eq!(fill.clip, graph.contains(p, OverlayRule::Clip));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_3() {
+        let square = |x: f64, y: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + 1.0),
+            F64Point::new(x + 1.0, y + 1.0),
+            F64Point::new(x + 1.0, y),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0)], vec![square(0.5, 0.5)]).into_graph(FillRule::NonZero);
+
+        assert_eq!(graph.fill_at(F64Point::new(0.25, 0.25)), PointFill { subj: true, clip: false, is_boundary: false });
+        assert_eq!(graph.fill_at(F64Point::new(0.75, 0.75)), PointFill { subj: true, clip: true, is_boundary: false });
+        assert_eq!(graph.fill_at(F64Point::new(1.0, 0.75)), PointFill { subj: true, clip: true, is_boundary: true });
+        assert!(graph.contains(F64Point::new(1.25, 1.25), OverlayRule::InverseDifference));
+        assert!(!graph.contains(F64Point::new(1.25, 1.25), OverlayRule::Intersect));
+        assert!(!graph.contains(F64Point::new(2.0, 2.0), OverlayRule::Union));
+
+        let points = [F64Point::new(0.25, 0.25), F64Point::new(1.0, 0.75), F64Point::new(1.25, 1.25)];
+        assert_eq!(graph.fill_at_points(&points), points.iter().map(|&p| graph.fill_at(p)).collect::<Vec<_>>());
+        assert_eq!(graph.contains_points(&points, OverlayRule::Intersect), vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_4() {
+        // the subject and the clip only touch at a corner, the corner is not in the closed intersection
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(10, 10, 10, 10)]).into_graph(FillRule::NonZero);
+        let corner = IntPoint::new(10, 10);
+
+        assert_eq!(graph.fill_at(corner), PointFill { subj: true, clip: true, is_boundary: true });
+        assert!(!graph.contains(corner, OverlayRule::Intersect));
+        assert!(graph.contains(corner, OverlayRule::Union));
+        assert_eq!(graph.contains_points(&[corner], OverlayRule::Intersect), vec![false]);
+        assert_eq!(graph.fill_at_points(&[corner]), vec![graph.fill_at(corner)]);
+    }
+
+    #[test]
+    fn test_5() {
+        // the sweep over many points gives the same answers as the single point queries, also on links and nodes
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        for _ in 0..30 {
+            let mut random_paths = |count: usize| -> Vec<IntPath> {
+                (0..count).map(|_| {
+                    let x = rng.gen_range(0..30);
+                    let y = rng.gen_range(0..30);
+                    let w = rng.gen_range(1..15);
+                    let h = rng.gen_range(1..15);
+                    if rng.gen_bool(0.5) {
+                        rect(x, y, w, h)
+                    } else {
+                        vec![
+                            IntPoint::new(x, y),
+                            IntPoint::new(x + w, y + h),
+                            IntPoint::new(x + 2 * w, y),
+                            IntPoint::new(x + w, y - h),
+                        ]
+                    }
+                }).collect()
+            };
+
+            let subj = random_paths(6);
+            let clip = random_paths(6);
+
+            let mut points: Vec<IntPoint> = (-2..50).flat_map(|x| (-16..46).map(move |y| IntPoint::new(x, y))).collect();
+            points.reverse();
+
+            for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+                let graph = Overlay::with_paths(&subj, &clip).into_graph(fill_rule);
+
+                let fills = graph.fill_at_points(&points);
+                for (&p, fill) in points.iter().zip(fills) {
+                    assert_eq!(fill, graph.fill_at(p), "point: {:?}", p);
+                }
+
+                for rule in RULES {
+                    let result = graph.contains_points(&points, rule);
+                    for (&p, is_inside) in points.iter().zip(result) {
+                        assert_eq!(is_inside, graph.contains(p, rule), "point: {:?}, rule: {:?}", p, rule);
+                    }
+                }
+            }
+        }
+    }
+}
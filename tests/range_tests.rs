@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_overlay::core::error::OverlayError;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::range::{MAX_COORD, MIN_COORD, RangeTransform};
+    use i_shape::int::path::PointPathExtension;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn rect(min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(min_x, min_y),
+            IntPoint::new(min_x, max_y),
+            IntPoint::new(max_x, max_y),
+            IntPoint::new(max_x, min_y),
+        ]
+    }
+
+    #[test]
+    fn test_0() {
+        let overlay = Overlay::with_paths(&[square(MIN_COORD, MIN_COORD, MAX_COORD)], &[square(0, 0, MAX_COORD)]);
+        assert_eq!(overlay.validate_range(), Ok(()));
+
+        let mut overlay = overlay;
+        assert_eq!(overlay.fit_range(), RangeTransform::IDENTITY);
+
+        let overlay = Overlay::with_paths(&[square(0, 0, MAX_COORD + 1)], &[]);
+        assert!(matches!(overlay.validate_range(), Err(OverlayError::CoordinateOverflow(p)) if p.x == MAX_COORD + 1 || p.y == MAX_COORD + 1));
+    }
+
+    #[test]
+    fn test_1() {
+        // the span is small, so the input is only moved
+        let x0 = 2_000_000_000;
+        let mut overlay = Overlay::with_paths(&[square(x0, 0, 10)], &[square(x0 + 5, 5, 10)]);
+        assert!(overlay.validate_range().is_err());
+
+        let transform = overlay.fit_range();
+        assert_eq!(transform.shift, 0);
+        assert_eq!(overlay.validate_range(), Ok(()));
+
+        let mut intersect = overlay.into_graph(FillRule::NonZero).extract_shapes(OverlayRule::Intersect);
+        transform.restore_shapes(&mut intersect);
+
+        assert_eq!(intersect, vec![vec![square(x0 + 5, 5, 5)]]);
+    }
+
+    #[test]
+    fn test_2() {
+        // the span is wider than the safe range, so the input is scaled down by 2
+        let q = 1 << 30;
+        let h = q / 2;
+        let subj = rect(i32::MIN, i32::MIN, -h, -h);
+        let clip = rect(-3 * h, -3 * h, 0, 0);
+
+        let mut overlay = Overlay::with_paths(&[subj], &[clip]);
+        assert!(overlay.clone().try_into_graph(FillRule::NonZero).is_err());
+
+        let transform = overlay.fit_range();
+        assert_eq!(transform, RangeTransform { offset: IntPoint::new(-q, -q), shift: 1 });
+
+        let graph = overlay.try_into_graph(FillRule::NonZero).unwrap();
+        let mut union = graph.try_extract_shapes(OverlayRule::Union).unwrap();
+        transform.restore_shapes(&mut union);
+
+        // all points are even, so they are restored exactly
+        let mut expected = vec![
+            IntPoint::new(i32::MIN, i32::MIN),
+            IntPoint::new(i32::MIN, -h),
+            IntPoint::new(-3 * h, -h),
+            IntPoint::new(-3 * h, 0),
+            IntPoint::new(0, 0),
+            IntPoint::new(0, -3 * h),
+            IntPoint::new(-h, -3 * h),
+            IntPoint::new(-h, i32::MIN),
+        ];
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].len(), 1);
+
+        let mut points = union[0][0].clone();
+        points.sort();
+        expected.sort();
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn test_3() {
+        // a tiny path collapses, the other paths are kept
+        let mut overlay = Overlay::new(12);
+        overlay.add_path(&square(1, 2, 1), ShapeType::Subject);
+        overlay.add_path(&rect(i32::MIN, -16, i32::MAX - 1, 16), ShapeType::Subject);
+        overlay.add_path(&square(-8, -8, 16), ShapeType::Clip);
+
+        let transform = overlay.fit_range();
+        assert_eq!(transform, RangeTransform { offset: IntPoint::new(-1, 0), shift: 2 });
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let intersect = graph.extract_shapes(OverlayRule::Intersect);
+
+        assert_eq!(intersect.len(), 1);
+        assert_eq!(intersect[0].len(), 1);
+        assert_eq!(intersect[0][0].unsafe_area(), 2 * 4 * 4);
+    }
+
+    #[test]
+    fn test_4() {
+        let transform = RangeTransform { offset: IntPoint::new(-2, 6), shift: 2 };
+        let p = transform.apply(IntPoint::new(14, -10));
+        assert_eq!(p, IntPoint::new(4, -4));
+        assert_eq!(transform.restore(p), IntPoint::new(14, -10));
+
+        let far = RangeTransform { offset: IntPoint::new(i32::MAX, 0), shift: 2 };
+        assert_eq!(far.restore(IntPoint::new(MAX_COORD, 0)).x, i32::MAX);
+    }
+}
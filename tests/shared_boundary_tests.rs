@@ -0,0 +1,87 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::util::overlay::rect;
+
+    fn path(points: &[[i32; 2]]) -> IntPath {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    #[test]
+    fn test_0() {
+        // two parcels with a common side
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(10, 0, 10, 10)]).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_shared_boundaries(), vec![path(&[[10, 10], [10, 0]])]);
+
+        // only a part of the side is common
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(10, 5, 10, 10)]).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_shared_boundaries(), vec![path(&[[10, 10], [10, 5]])]);
+
+        // crossing boundaries have only common points
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(5, 5, 10, 10)]).into_graph(FillRule::NonZero);
+        assert!(graph.extract_shared_boundaries().is_empty());
+    }
+
+    #[test]
+    fn test_1() {
+        // the clip wraps the top right corner of the subject
+        let clip = path(&[[0, 10], [0, 20], [20, 20], [20, 0], [10, 0], [10, 10]]);
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[clip]).into_graph(FillRule::EvenOdd);
+        assert_eq!(graph.extract_shared_boundaries(), vec![path(&[[0, 10], [10, 10], [10, 0]])]);
+
+        // the same squares give a closed clockwise chain
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[rect(0, 0, 10, 10)]).into_graph(FillRule::NonZero);
+        let boundaries = graph.extract_shared_boundaries();
+        assert_eq!(boundaries.len(), 1);
+        assert_eq!(boundaries[0].len(), 5);
+        assert_eq!(boundaries[0].first(), boundaries[0].last());
+        assert_eq!(boundaries[0][0..4], path(&[[0, 0], [0, 10], [10, 10], [10, 0]]));
+    }
+
+    #[test]
+    fn test_2() {
+        // a common side of two subject squares is inside the subject, so only the outer sides are shared
+        let subj = [rect(0, 0, 10, 10), rect(10, 0, 10, 10)];
+        let clip = [rect(0, 10, 20, 10)];
+        let graph = Overlay::with_paths(&subj, &clip).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_shared_boundaries(), vec![path(&[[0, 10], [10, 10], [20, 10]])]);
+
+        // the common edges with two clip parcels meet at one point and form a single polyline
+        let subj = [rect(0, 0, 10, 10)];
+        let clip = [rect(10, 0, 10, 10), rect(0, 10, 10, 10)];
+        let graph = Overlay::with_paths(&subj, &clip).into_graph(FillRule::NonZero);
+        assert_eq!(graph.extract_shared_boundaries(), vec![path(&[[0, 10], [10, 10], [10, 0]])]);
+
+        // four common edges meet at the center, so the chains are not joined there
+        let subj = [rect(0, 0, 10, 10), rect(10, 10, 10, 10)];
+        let clip = [rect(10, 0, 10, 10), rect(0, 10, 10, 10)];
+        let graph = Overlay::with_paths(&subj, &clip).into_graph(FillRule::NonZero);
+        let boundaries = graph.extract_shared_boundaries();
+        assert_eq!(boundaries.len(), 4);
+        assert!(boundaries.iter().all(|path| path.len() == 2 && path.contains(&IntPoint::new(10, 10))));
+    }
+
+    #[test]
+    fn test_3() {
+        let square = |x: f64| vec![
+            F64Point::new(x, 0.0),
+            F64Point::new(x, 1.0),
+            F64Point::new(x + 1.0, 1.0),
+            F64Point::new(x + 1.0, 0.0),
+        ];
+
+        let graph = F64Overlay::with_paths(vec![square(0.0)], vec![square(1.0)]).into_graph(FillRule::NonZero);
+        let boundaries = graph.extract_shared_boundaries();
+        assert_eq!(boundaries.len(), 1);
+        assert_eq!(boundaries[0].len(), 2);
+        assert_eq!((boundaries[0][0].x, boundaries[0][0].y), (1.0, 1.0));
+        assert_eq!((boundaries[0][1].x, boundaries[0][1].y), (1.0, 0.0));
+    }
+}
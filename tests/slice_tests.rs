@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::f64::overlay::F64Overlay;
+    use i_shape::int::path::PointPathExtension;
+    use i_shape::int::shape::IntShapes;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn path(points: &[[i32; 2]]) -> Vec<IntPoint> {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    fn double_area(shapes: &IntShapes) -> i64 {
+        shapes.iter().flatten().map(|path| path.unsafe_area()).sum()
+    }
+
+    #[test]
+    fn test_0() {
+        for solver in [Solver::LIST, Solver::TREE, Solver::AUTO] {
+            let mut overlay = Overlay::new(4);
+            overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+            overlay.add_polyline(&path(&[[-10, 10], [30, 10]]));
+
+            let graph = overlay.into_graph_with_solver(FillRule::NonZero, solver);
+            let slices = graph.extract_slices(OverlayRule::Subject);
+
+            assert_eq!(slices.left, vec![vec![path(&[[0, 10], [0, 20], [20, 20], [20, 10]])]]);
+            assert_eq!(slices.right, vec![vec![path(&[[0, 0], [0, 10], [20, 10], [20, 0]])]]);
+            assert!(slices.rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        // a polyline which does not cross the shape does not split it
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polyline(&path(&[[-10, 10], [10, 10], [10, 15]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let slices = graph.extract_slices(OverlayRule::Subject);
+
+        assert!(slices.left.is_empty());
+        assert!(slices.right.is_empty());
+        assert_eq!(slices.rest, vec![vec![square(0, 0, 20)]]);
+    }
+
+    #[test]
+    fn test_2() {
+        // the cut goes through the hole
+        let mut hole = square(10, 10, 10);
+        hole.reverse();
+
+        let mut overlay = Overlay::new(8);
+        overlay.add_shape(&vec![square(0, 0, 30), hole], ShapeType::Subject);
+        overlay.add_polyline(&path(&[[15, -10], [15, 40]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let slices = graph.extract_slices(OverlayRule::Subject);
+
+        assert_eq!(slices.left.len(), 1);
+        assert_eq!(slices.left[0].len(), 1);
+        assert_eq!(slices.right.len(), 1);
+        assert_eq!(slices.right[0].len(), 1);
+        assert!(slices.rest.is_empty());
+
+        assert_eq!(double_area(&slices.left), 800);
+        assert_eq!(double_area(&slices.right), 800);
+        assert!(slices.left[0][0].iter().all(|p| p.x <= 15));
+        assert!(slices.right[0][0].iter().all(|p| p.x >= 15));
+    }
+
+    #[test]
+    fn test_3() {
+        // two crossing cuts, the pieces between them touch both sides
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_polylines(&[
+            path(&[[-10, 10], [30, 10]]),
+            path(&[[10, -10], [10, 30]]),
+        ]);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let slices = graph.extract_slices(OverlayRule::Subject);
+
+        assert_eq!(slices.left, vec![vec![square(0, 10, 10)]]);
+        assert_eq!(slices.right, vec![vec![square(10, 0, 10)]]);
+        assert_eq!(slices.rest.len(), 2);
+        assert_eq!(double_area(&slices.rest), 400);
+    }
+
+    #[test]
+    fn test_4() {
+        // the cut edges of both sides are the same
+        let mut overlay = Overlay::new(4);
+        overlay.add_path(&square(0, 0, 20), ShapeType::Subject);
+        overlay.add_path(&square(10, 10, 20), ShapeType::Clip);
+        overlay.add_polyline(&path(&[[-5, 35], [12, 3], [35, -5]]));
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let slices = graph.extract_slices(OverlayRule::Union);
+
+        assert_eq!(slices.left.len(), 1);
+        assert_eq!(slices.right.len(), 1);
+        assert!(slices.rest.is_empty());
+        assert_eq!(double_area(&slices.left) + double_area(&slices.right), double_area(&graph.extract_shapes(OverlayRule::Union)));
+
+        let left = &slices.left[0][0];
+        let right = &slices.right[0][0];
+        let shared = left.iter().filter(|&p| right.iter().any(|q| q == p)).count();
+        assert!(shared >= 3);
+    }
+
+    #[test]
+    fn test_5() {
+        let mut overlay = F64Overlay::new();
+        overlay.add_path(vec![
+            F64Point::new(0.0, 0.0),
+            F64Point::new(0.0, 1.0),
+            F64Point::new(1.0, 1.0),
+            F64Point::new(1.0, 0.0),
+        ], ShapeType::Subject);
+        overlay.add_polyline(vec![F64Point::new(0.5, -1.0), F64Point::new(0.5, 2.0)]);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let slices = graph.extract_slices(OverlayRule::Subject);
+
+        assert_eq!(slices.left.len(), 1);
+        assert_eq!(slices.right.len(), 1);
+        assert!(slices.rest.is_empty());
+        assert!(slices.left[0][0].iter().all(|p| p.x <= 0.5 + 1e-6));
+        assert!(slices.right[0][0].iter().all(|p| p.x >= 0.5 - 1e-6));
+    }
+}
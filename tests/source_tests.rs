@@ -0,0 +1,151 @@
+mod data;
+
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::path::PointPathExtension;
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::{Overlay, ShapeType};
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::source::graph::ShapeSources;
+    use i_overlay::source::overlay::SourceOverlay;
+    use crate::data::overlay::Test;
+
+    fn square(x: i32, y: i32, size: i32) -> Vec<IntPoint> {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + size),
+            IntPoint::new(x + size, y + size),
+            IntPoint::new(x + size, y),
+        ]
+    }
+
+    fn area(shape: &IntShape) -> i64 {
+        shape.iter().map(|path| path.unsafe_area()).sum()
+    }
+
+    fn sources(subj: &[usize], clip: &[usize]) -> ShapeSources {
+        ShapeSources { subj: subj.to_vec(), clip: clip.to_vec() }
+    }
+
+    #[test]
+    fn test_0() {
+        let mut overlay = SourceOverlay::new(8);
+        overlay.add_path(&square(0, 0, 10), ShapeType::Subject);
+        overlay.add_path(&square(5, 0, 10), ShapeType::Clip);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+
+        let union = graph.extract_shapes_with_sources(OverlayRule::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(area(&union[0].0), 2 * 150);
+        assert_eq!(union[0].1, sources(&[0], &[0]));
+
+        let intersect = graph.extract_shapes_with_sources(OverlayRule::Intersect);
+        assert_eq!(intersect.len(), 1);
+        assert_eq!(intersect[0].1, sources(&[0], &[0]));
+
+        let difference = graph.extract_shapes_with_sources(OverlayRule::Difference);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0].1, sources(&[0], &[]));
+    }
+
+    #[test]
+    fn test_1() {
+        let mut overlay = SourceOverlay::new(12);
+        overlay.add_path(&square(100, 100, 10), ShapeType::Clip);
+        overlay.add_path(&square(0, 0, 30), ShapeType::Subject);
+        overlay.add_path(&square(10, 10, 10), ShapeType::Subject);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let subject = graph.extract_shapes_with_sources(OverlayRule::Subject);
+
+        // the inner square only doubles the winding, so it is a part of the outer one
+        assert_eq!(subject.len(), 1);
+        assert_eq!(area(&subject[0].0), 2 * 900);
+        assert_eq!(subject[0].1, sources(&[0, 1], &[]));
+
+        let union = graph.extract_shapes_with_sources(OverlayRule::Union);
+        assert_eq!(union.len(), 2);
+        let far = union.iter().find(|(shape, _)| shape[0][0].x >= 100).unwrap();
+        assert_eq!(far.1, sources(&[], &[0]));
+    }
+
+    #[test]
+    fn test_2() {
+        let overlay = SourceOverlay::new(0);
+        let graph = overlay.into_graph(FillRule::NonZero);
+        assert!(graph.extract_shapes_with_sources(OverlayRule::Union).is_empty());
+    }
+
+    #[test]
+    fn test_3() {
+        // a frame with a clip island inside its hole
+        let mut hole = square(10, 10, 10);
+        hole.reverse();
+        let frame = vec![square(0, 0, 30), hole];
+
+        let mut overlay = SourceOverlay::new(12);
+        overlay.add_shape(&frame, ShapeType::Subject);
+        overlay.add_path(&square(12, 12, 6), ShapeType::Clip);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let union = graph.extract_shapes_with_sources(OverlayRule::Union);
+        assert_eq!(union.len(), 2);
+
+        let frame_result = union.iter().find(|(shape, _)| shape.len() == 2).unwrap();
+        assert_eq!(area(&frame_result.0), 2 * 800);
+        assert_eq!(frame_result.1, sources(&[0], &[]));
+
+        // the hole is a part of the subject shape, so it does not cover the island
+        let island = union.iter().find(|(shape, _)| shape.len() == 1).unwrap();
+        assert_eq!(area(&island.0), 2 * 36);
+        assert_eq!(island.1, sources(&[], &[0]));
+
+        // added as paths, the outer contour and the hole are separate inputs and both cover the island alone
+        let mut overlay = SourceOverlay::new(12);
+        overlay.add_paths(&frame, ShapeType::Subject);
+        overlay.add_path(&square(12, 12, 6), ShapeType::Clip);
+
+        let graph = overlay.into_graph(FillRule::NonZero);
+        let union = graph.extract_shapes_with_sources(OverlayRule::Union);
+        let island = union.iter().find(|(shape, _)| shape.len() == 1).unwrap();
+        assert_eq!(island.1, sources(&[0, 1], &[0]));
+    }
+
+    #[test]
+    fn test_area() {
+        for index in 0..134 {
+            let test = Test::load(index);
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+
+            let graph = SourceOverlay::with_paths(&test.subj_paths, &test.clip_paths)
+                .into_graph_with_solver(fill_rule, Solver::AUTO);
+            let overlay_graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths)
+                .into_graph_with_solver(fill_rule, Solver::AUTO);
+
+            let banks = [
+                (OverlayRule::Subject, &test.subject),
+                (OverlayRule::Clip, &test.clip),
+                (OverlayRule::Intersect, &test.intersect),
+                (OverlayRule::Union, &test.union),
+                (OverlayRule::Difference, &test.difference),
+                (OverlayRule::InverseDifference, &test.inverse_difference),
+                (OverlayRule::Xor, &test.xor),
+            ];
+
+            for (rule, bank) in banks {
+                let result = graph.extract_shapes_with_sources(rule);
+                let shapes: Vec<IntShape> = result.iter().map(|(shape, _)| shape.clone()).collect();
+                assert_eq!(shapes, overlay_graph.extract_shapes(rule), "test: {}, rule: {:?}", index, rule);
+                assert!(result.iter().all(|(_, sources)| !sources.subj.is_empty() || !sources.clip.is_empty()), "test: {}, rule: {:?}", index, rule);
+
+                let result: i64 = shapes.iter().map(area).sum();
+                let is_expected = bank.iter().any(|shapes| shapes.iter().map(area).sum::<i64>() == result);
+                assert!(is_expected, "test: {}, rule: {:?}", index, rule);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use i_float::point::IntPoint;
+    use i_shape::int::path::IntPath;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::{MultithreadOptions, Solver, Strategy};
+
+    const RULES: [OverlayRule; 3] = [OverlayRule::Union, OverlayRule::Intersect, OverlayRule::Xor];
+
+    fn random_triangles(rng: &mut impl Rng, count: usize, size: i32, radius: i32) -> Vec<IntPath> {
+        (0..count).map(|_| {
+            let x = rng.gen_range(-size..size);
+            let y = rng.gen_range(-size..size);
+            (0..3).map(|_| IntPoint::new(x + rng.gen_range(-radius..radius), y + rng.gen_range(-radius..radius))).collect()
+        }).collect()
+    }
+
+    fn random_rects(rng: &mut impl Rng, count: usize, size: i32) -> Vec<IntPath> {
+        (0..count).map(|_| {
+            let x = 2 * rng.gen_range(-size..size);
+            let y = 2 * rng.gen_range(-size..size);
+            let w = 2 * rng.gen_range(1..50);
+            let h = 2 * rng.gen_range(1..50);
+            vec![
+                IntPoint::new(x, y),
+                IntPoint::new(x, y + h),
+                IntPoint::new(x + w, y + h),
+                IntPoint::new(x + w, y),
+            ]
+        }).collect()
+    }
+
+    // long strips with 45 degree edges, all cross points with even coordinates are integer
+    fn random_strips(rng: &mut impl Rng, count: usize, size: i32) -> Vec<IntPath> {
+        (0..count).map(|_| {
+            let x = 2 * rng.gen_range(-size..0);
+            let y = 2 * rng.gen_range(-size..size);
+            let l = 2 * rng.gen_range(size / 2..size);
+            let w = 2 * rng.gen_range(1..20);
+            let dy = if rng.gen_bool(0.5) { l } else { -l };
+            vec![
+                IntPoint::new(x, y),
+                IntPoint::new(x, y + w),
+                IntPoint::new(x + l, y + dy + w),
+                IntPoint::new(x + l, y + dy),
+            ]
+        }).collect()
+    }
+
+    fn compare(subj: &[IntPath], clip: &[IntPath]) {
+        let single = Solver { strategy: Strategy::Tree, multithreading: None, ..Solver::AUTO };
+        let multi = Solver { strategy: Strategy::Tree, multithreading: Some(MultithreadOptions::default()), ..Solver::AUTO };
+
+        let overlay = Overlay::with_paths(subj, clip);
+        let expected = overlay.clone().into_graph_with_solver(FillRule::NonZero, single);
+        let result = overlay.into_graph_with_solver(FillRule::NonZero, multi);
+
+        for rule in RULES {
+            assert_eq!(result.extract_shapes(rule), expected.extract_shapes(rule), "rule: {:?}", rule);
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        // short edges, every edge is a single fragment
+        let mut rng = StdRng::seed_from_u64(0);
+        let subj = random_triangles(&mut rng, 3000, 10_000, 100);
+        let clip = random_triangles(&mut rng, 3000, 10_000, 100);
+        compare(&subj, &clip);
+    }
+
+    #[test]
+    fn test_1() {
+        // a few long edges are broken into many fragments
+        let mut rng = StdRng::seed_from_u64(1);
+        let subj = random_rects(&mut rng, 5000, 5000);
+        let clip = random_strips(&mut rng, 50, 5000);
+        compare(&subj, &clip);
+    }
+}
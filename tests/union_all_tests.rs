@@ -0,0 +1,141 @@
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::shape::IntShape;
+    use rand::Rng;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::core::solver::Solver;
+    use i_overlay::core::union_all::UnionAll;
+    use i_overlay::f64::union_all::UnionAll as F64UnionAll;
+    use crate::util::overlay::{rect, CircleCompare};
+
+    fn random_shapes(rng: &mut impl Rng, count: usize, size: i32) -> Vec<IntShape> {
+        (0..count).map(|_| {
+            let x = rng.gen_range(-size..size);
+            let y = rng.gen_range(-size..size);
+            let w = rng.gen_range(1..20);
+            let h = rng.gen_range(1..20);
+            if w > 4 && h > 4 && rng.gen_bool(0.2) {
+                // a frame
+                let mut hole = rect(x + 2, y + 2, w - 4, h - 4);
+                hole.reverse();
+                vec![rect(x, y, w, h), hole]
+            } else {
+                vec![rect(x, y, w, h)]
+            }
+        }).collect()
+    }
+
+    fn single_union(shapes: &[IntShape]) -> Vec<IntShape> {
+        Overlay::with_shapes(shapes, &[]).into_graph(FillRule::NonZero).extract_shapes(OverlayRule::Subject)
+    }
+
+    #[test]
+    fn test_0() {
+        let empty: Vec<IntShape> = Vec::new();
+        assert!(empty.union_all().is_empty());
+
+        let shapes = vec![vec![rect(0, 0, 10, 10)], vec![rect(5, 5, 10, 10)]];
+        assert_eq!(shapes.union_all(), single_union(&shapes));
+    }
+
+    #[test]
+    fn test_1() {
+        let single_thread = Solver { multithreading: None, ..Solver::AUTO };
+        let mut rng = rand::thread_rng();
+        for size in [100, 1000, 10_000] {
+            let shapes = random_shapes(&mut rng, 3000, size);
+            let expected = single_union(&shapes);
+
+            for solver in [Solver::LIST, Solver::TREE, single_thread] {
+                let result = shapes.union_all_with_solver(solver);
+                assert!(result.are_equal(&expected), "size: {}", size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_2() {
+        // a grid of touching squares is merged into one shape
+        let shapes: Vec<IntShape> = (0..60).flat_map(|i| (0..60).map(move |j| vec![rect(10 * i, 10 * j, 10, 10)])).collect();
+        let result = shapes.union_all();
+        assert_eq!(result, vec![vec![rect(0, 0, 600, 600)]]);
+    }
+
+    #[test]
+    fn test_3() {
+        let shapes: Vec<Vec<Vec<F64Point>>> = (0..2000).map(|i| {
+            let x = (i % 50) as f64 * 1.5;
+            let y = (i / 50) as f64 * 1.5;
+            vec![vec![
+                F64Point::new(x, y),
+                F64Point::new(x, y + 1.0),
+                F64Point::new(x + 1.0, y + 1.0),
+                F64Point::new(x + 1.0, y),
+            ]]
+        }).collect();
+
+        let result = shapes.union_all();
+        assert_eq!(result.len(), 2000);
+        assert!(result.iter().all(|shape| shape.len() == 1 && shape[0].len() == 4));
+
+        let empty: Vec<Vec<Vec<F64Point>>> = Vec::new();
+        assert!(empty.union_all().is_empty());
+    }
+
+    #[test]
+    fn test_4() {
+        // counterclockwise shapes have a negative winding, so they cancel the clockwise shapes they overlap
+        let single_thread = Solver { multithreading: None, ..Solver::AUTO };
+        let mut rng = rand::thread_rng();
+        for size in [100, 1000] {
+            let mut shapes = random_shapes(&mut rng, 3000, size);
+            for shape in shapes.iter_mut() {
+                if rng.gen_bool(0.5) {
+                    for path in shape.iter_mut() {
+                        path.reverse();
+                    }
+                }
+            }
+
+            let expected = single_union(&shapes);
+
+            for solver in [Solver::LIST, Solver::TREE, single_thread] {
+                let result = shapes.union_all_with_solver(solver);
+                assert!(result.are_equal(&expected), "size: {}", size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_5() {
+        // the cross points of the slanted edges are not integer, so they are rounded
+        let triangles = |x0: i32, y0: i32, count: i32| -> Vec<IntShape> {
+            (0..count).map(|i| {
+                let x = x0 + 7 * (i % 20);
+                let y = y0 + 5 * (i / 20);
+                vec![vec![
+                    IntPoint::new(x, y),
+                    IntPoint::new(x + 3, y + 17),
+                    IntPoint::new(x + 19, y + 4),
+                ]]
+            }).collect()
+        };
+
+        // one large cluster and many small clusters apart from each other
+        let mut shapes = triangles(0, 0, 2000);
+        for i in 0..10 {
+            for j in 0..10 {
+                shapes.extend(triangles(-2000 + 200 * i, -2000 + 100 * j, 60));
+            }
+        }
+
+        let expected = single_union(&shapes);
+        assert!(shapes.union_all().are_equal(&expected));
+    }
+}
@@ -1,7 +1,34 @@
 #[cfg(test)]
+#[allow(dead_code)]
 pub mod overlay {
     use i_float::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
     use i_shape::int::shape::IntShape;
+    use i_overlay::core::overlay_rule::OverlayRule;
+
+    pub const RULES: [OverlayRule; 7] = [
+        OverlayRule::Subject,
+        OverlayRule::Clip,
+        OverlayRule::Intersect,
+        OverlayRule::Union,
+        OverlayRule::Difference,
+        OverlayRule::InverseDifference,
+        OverlayRule::Xor,
+    ];
+
+    pub fn rect(x: i32, y: i32, w: i32, h: i32) -> IntPath {
+        vec![
+            IntPoint::new(x, y),
+            IntPoint::new(x, y + h),
+            IntPoint::new(x + w, y + h),
+            IntPoint::new(x + w, y),
+        ]
+    }
+
+    pub fn shapes_area(shapes: &[IntShape]) -> f64 {
+        let area_x2: i64 = shapes.iter().flat_map(|shape| shape.iter()).map(|path| path.unsafe_area()).sum();
+        area_x2.abs() as f64 * 0.5
+    }
 
     pub trait CircleCompare {
         fn are_equal(&self, other: &Self) -> bool;
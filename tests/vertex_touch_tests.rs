@@ -0,0 +1,193 @@
+mod data;
+mod util;
+
+#[cfg(test)]
+mod tests {
+    use i_float::f64_point::F64Point;
+    use i_float::point::IntPoint;
+    use i_shape::int::path::{IntPath, PointPathExtension};
+    use i_shape::int::shape::IntShape;
+    use i_overlay::core::extract::VertexTouch;
+    use i_overlay::core::fill_rule::FillRule;
+    use i_overlay::core::overlay::Overlay;
+    use i_overlay::core::overlay_rule::OverlayRule;
+    use i_overlay::f64::overlay::F64Overlay;
+    use crate::data::overlay::Test;
+    use crate::util::overlay::rect;
+
+    fn path(points: &[[i32; 2]]) -> IntPath {
+        points.iter().map(|p| IntPoint::new(p[0], p[1])).collect()
+    }
+
+    fn is_strictly_simple(path: &IntPath) -> bool {
+        let mut points = path.clone();
+        points.sort_unstable();
+        points.windows(2).all(|w| w[0] != w[1])
+    }
+
+    fn area_x2(shapes: &[IntShape]) -> i64 {
+        shapes.iter().flat_map(|shape| shape.iter()).map(|path| path.unsafe_area()).sum()
+    }
+
+    #[test]
+    fn test_data() {
+        for (index, test) in Test::load_all() {
+            let fill_rule = test.fill_rule.unwrap_or(FillRule::EvenOdd);
+            let graph = Overlay::with_paths(&test.subj_paths, &test.clip_paths).into_graph(fill_rule);
+
+            for (rule, bank) in test.rule_banks() {
+                let merged = graph.extract_shapes(rule);
+                assert_eq!(graph.extract_shapes_with_touch(rule, VertexTouch::Merge), merged);
+
+                let split = graph.extract_shapes_with_touch(rule, VertexTouch::Split);
+                assert!(split.len() >= merged.len(), "test: {}, rule: {:?}", index, rule);
+                assert_eq!(area_x2(&split), area_x2(&merged), "test: {}, rule: {:?}", index, rule);
+                assert!(bank.iter().any(|shapes| area_x2(shapes) == area_x2(&split)), "test: {}, rule: {:?}", index, rule);
+
+                for shape in split.iter() {
+                    assert!(shape[0].unsafe_area() > 0, "test: {}, rule: {:?}", index, rule);
+                    assert!(shape[1..].iter().all(|hole| hole.unsafe_area() < 0), "test: {}, rule: {:?}", index, rule);
+                    assert!(shape.iter().all(is_strictly_simple), "test: {}, rule: {:?}", index, rule);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        // a hole touches the outer boundary at (0, 5)
+        let hole = path(&[[0, 5], [5, 8], [8, 5], [5, 2]]);
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &[hole]).into_graph(FillRule::NonZero);
+
+        let merged = graph.extract_shapes(OverlayRule::Difference);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].len(), 1);
+        assert_eq!(merged[0][0].len(), 9);
+
+        let split = graph.extract_shapes_with_touch(OverlayRule::Difference, VertexTouch::Split);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 2);
+        assert_eq!(split[0][0], path(&[[0, 0], [0, 5], [0, 10], [10, 10], [10, 0]]));
+        assert_eq!(split[0][1].len(), 4);
+        assert!(split[0][1].unsafe_area() < 0);
+        assert_ne!(split[0][1][0], IntPoint::new(0, 5));
+    }
+
+    #[test]
+    fn test_1() {
+        // two holes touch each other at (5, 5)
+        let holes = [rect(2, 2, 3, 3), rect(5, 5, 3, 3)];
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &holes).into_graph(FillRule::NonZero);
+
+        let merged = graph.extract_shapes(OverlayRule::Difference);
+        assert_eq!(merged[0].len(), 2);
+        assert_eq!(merged[0][1].len(), 8);
+
+        let split = graph.extract_shapes_with_touch(OverlayRule::Difference, VertexTouch::Split);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 3);
+        assert!(split[0][1..].iter().all(|hole| hole.len() == 4 && hole.unsafe_area() == -18));
+
+        // separate outer boundaries which touch are not changed
+        let empty: Vec<IntPath> = Vec::new();
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10), rect(10, 10, 10, 10)], &empty).into_graph(FillRule::NonZero);
+        let merged = graph.extract_shapes(OverlayRule::Subject);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(graph.extract_shapes_with_touch(OverlayRule::Subject, VertexTouch::Split), merged);
+    }
+
+    #[test]
+    fn test_2() {
+        let square = |x: f64, y: f64, size: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ];
+
+        // a hole touches the cut corner of the outer boundary at (1, 1)
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0, 4.0)], vec![square(0.0, 0.0, 1.0), square(1.0, 1.0, 1.0)]).into_graph(FillRule::NonZero);
+        let merged = graph.extract_shapes(OverlayRule::Difference);
+        assert_eq!(merged.len(), 1);
+
+        let split = graph.extract_shapes_with_touch(OverlayRule::Difference, VertexTouch::Split);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 2);
+        assert_eq!(split[0][1].len(), 4);
+
+        let split = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Split, 0.0);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 2);
+    }
+
+    #[test]
+    fn test_3() {
+        // two holes touch each other at (5, 5)
+        let holes = [rect(2, 2, 3, 3), rect(5, 5, 3, 3)];
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &holes).into_graph(FillRule::NonZero);
+
+        for vertex_touch in [VertexTouch::Merge, VertexTouch::Split] {
+            assert_eq!(graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, vertex_touch, 0), graph.extract_shapes_with_touch(OverlayRule::Difference, vertex_touch));
+        }
+
+        for min_area in [1, 9, 10, 18, 19, 100] {
+            let merged = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Merge, min_area);
+            assert_eq!(merged, graph.extract_shapes_min_area(OverlayRule::Difference, min_area));
+
+            let split = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Split, min_area);
+            for shape in split.iter() {
+                assert!(shape.iter().all(|path| path.unsafe_area().abs() >> 1 >= min_area), "min_area: {}", min_area);
+                assert!(shape.iter().all(is_strictly_simple), "min_area: {}", min_area);
+            }
+        }
+    }
+
+    #[test]
+    fn test_4() {
+        // a large hole and a small hole touch each other at (7, 7), so they are traced as one path with two loops
+        let holes = [rect(2, 2, 5, 5), rect(7, 7, 1, 1)];
+        let graph = Overlay::with_paths(&[rect(0, 0, 10, 10)], &holes).into_graph(FillRule::NonZero);
+
+        let merged = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Merge, 2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].len(), 2);
+        assert_eq!(merged[0][1].unsafe_area(), -52);
+
+        // only the small loop is removed
+        let split = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Split, 2);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 2);
+        assert_eq!(split[0][0].unsafe_area(), 200);
+        assert_eq!(split[0][1].len(), 4);
+        assert_eq!(split[0][1].unsafe_area(), -50);
+
+        // the merged hole is as large as both loops together, but every loop alone is smaller
+        let merged = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Merge, 26);
+        assert_eq!(merged[0].len(), 2);
+        let split = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Split, 26);
+        assert_eq!(split, vec![vec![rect(0, 0, 10, 10)]]);
+
+        for vertex_touch in [VertexTouch::Merge, VertexTouch::Split] {
+            assert!(graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, vertex_touch, 101).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_5() {
+        let square = |x: f64, y: f64, size: f64| vec![
+            F64Point::new(x, y),
+            F64Point::new(x, y + size),
+            F64Point::new(x + size, y + size),
+            F64Point::new(x + size, y),
+        ];
+
+        let holes = vec![square(2.0, 2.0, 5.0), square(7.0, 7.0, 1.0)];
+        let graph = F64Overlay::with_paths(vec![square(0.0, 0.0, 10.0)], holes).into_graph(FillRule::NonZero);
+
+        let split = graph.extract_shapes_with_touch_min_area(OverlayRule::Difference, VertexTouch::Split, 2.0);
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].len(), 2);
+        assert_eq!(split[0][1].len(), 4);
+        assert!(split[0][1].iter().all(|p| (2.0..=7.0).contains(&p.x) && (2.0..=7.0).contains(&p.y)));
+    }
+}